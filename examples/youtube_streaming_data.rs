@@ -44,7 +44,11 @@ async fn main() -> Result<()> {
                     _ => "N/A".to_string(),
                 };
 
-                let quality = format.quality_label.as_deref().unwrap_or(&format.quality);
+                let quality = format
+                    .quality_label
+                    .as_deref()
+                    .or(format.quality.as_deref())
+                    .unwrap_or("N/A");
                 let mime_parts: Vec<&str> = format.mime_type.split(';').collect();
                 let mime_type = mime_parts[0];
 
@@ -126,17 +130,15 @@ async fn main() -> Result<()> {
             }
 
             // Display additional format details for one high-quality format
-            if let Some(hd_format) = streaming_data
-                .adaptive_formats
-                .iter()
-                .find(|f| f.mime_type.starts_with("video/") && f.quality == "hd720")
-            {
+            if let Some(hd_format) = streaming_data.adaptive_formats.iter().find(|f| {
+                f.mime_type.starts_with("video/") && f.quality.as_deref() == Some("hd720")
+            }) {
                 println!(
                     "\nDetailed information for HD format (itag {}):",
                     hd_format.itag
                 );
                 println!("------------------------------------------------");
-                println!("Quality: {}", hd_format.quality);
+                println!("Quality: {}", hd_format.quality.as_deref().unwrap_or("N/A"));
                 println!(
                     "Quality label: {}",
                     hd_format.quality_label.as_deref().unwrap_or("N/A")
@@ -151,7 +153,10 @@ async fn main() -> Result<()> {
                     "Average bitrate: {} bps",
                     hd_format.average_bitrate.unwrap_or(0)
                 );
-                println!("Approximate duration: {} ms", hd_format.approx_duration_ms);
+                println!(
+                    "Approximate duration: {} ms",
+                    hd_format.approx_duration_ms.as_deref().unwrap_or("N/A")
+                );
 
                 if let Some(init_range) = &hd_format.init_range {
                     println!("Init range: {} - {}", init_range.start, init_range.end);
@@ -198,7 +203,10 @@ async fn main() -> Result<()> {
 }
 
 /// Formats a bitrate value to a human-readable string
-fn format_bitrate(bitrate: u64) -> String {
+fn format_bitrate(bitrate: Option<u64>) -> String {
+    let Some(bitrate) = bitrate else {
+        return "N/A".to_string();
+    };
     if bitrate >= 1_000_000 {
         format!("{:.2} Mbps", bitrate as f64 / 1_000_000.0)
     } else {
@@ -1,15 +1,32 @@
+use chrono::{DateTime, TimeDelta, Utc};
 use reqwest::Client;
 use std::path::Path;
 use std::sync::Arc;
 
+#[cfg(not(feature = "ci"))]
+use crate::channel_fetcher::ChannelFetcher;
+use crate::comment_fetcher::{CommentFetcher, CommentStream};
 use crate::cookie_jar_loader::CookieJarLoader;
 #[cfg(not(feature = "ci"))]
 use crate::errors::{CookieError, CouldNotRetrieveTranscript};
 #[cfg(feature = "ci")]
 use crate::errors::{CookieError, CouldNotRetrieveTranscript, CouldNotRetrieveTranscriptReason};
-use crate::models::{MicroformatData, StreamingData, VideoDetails, VideoInfos};
+use crate::format_downloader::FormatDownloader;
+#[cfg(not(feature = "ci"))]
+use crate::hls_playlist_fetcher::HlsPlaylistFetcher;
+use crate::live_caption_follower::LiveCaptionFollower;
+use crate::models::{
+    ChannelTab, ChannelUploads, Chapter, Comment, CommentSortOrder, DownloadProgress,
+    HlsMasterPlaylist, HlsMediaPlaylist, HlsVariant, LiveDetails, MicroformatData, Playlist,
+    PlaylistEntry, SearchFilters, SearchPage, Storyboard, StreamingData, StreamingFormat,
+    VideoDetails, VideoInfos, VideoLinks,
+};
+#[cfg(not(feature = "ci"))]
+use crate::playlist_fetcher::PlaylistFetcher;
 use crate::proxies::ProxyConfig;
 #[cfg(not(feature = "ci"))]
+use crate::search_fetcher::SearchFetcher;
+#[cfg(not(feature = "ci"))]
 use crate::video_data_fetcher::VideoDataFetcher;
 use crate::{FetchedTranscript, TranscriptList};
 
@@ -62,6 +79,9 @@ pub struct YouTubeTranscriptApi {
 }
 
 impl YouTubeTranscriptApi {
+    /// Stored URLs expiring within this many seconds are refreshed
+    const REFRESH_MARGIN_SECONDS: i64 = 60;
+
     /// Creates a new YouTube Transcript API instance.
     ///
     /// This method initializes an API instance with optional customizations for
@@ -310,6 +330,75 @@ impl YouTubeTranscriptApi {
         transcript.fetch(client, preserve_formatting).await
     }
 
+    /// Fetches a transcript, optionally removing rolling caption repeats.
+    ///
+    /// This behaves exactly like [`fetch_transcript()`](Self::fetch_transcript), but when
+    /// `deduplicate` is `true` the snippets are passed through a
+    /// [`TranscriptDeduplicator`](crate::TranscriptDeduplicator) before being returned.
+    /// This is mostly useful for auto-generated and live-stream captions, where the end
+    /// of each line is repeated at the beginning of the next one.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID (e.g., "dQw4w9WgXcQ" from https://www.youtube.com/watch?v=dQw4w9WgXcQ)
+    /// * `languages` - A list of language codes in order of preference (e.g., ["en", "es", "fr"])
+    /// * `preserve_formatting` - Whether to keep HTML formatting elements like `<i>` (italics) and `<b>` (bold)
+    /// * `deduplicate` - Whether to remove text repeated between consecutive snippets
+    ///
+    /// # Returns
+    ///
+    /// * `Result<FetchedTranscript, CouldNotRetrieveTranscript>` - The transcript or an error
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    ///
+    /// let transcript = api
+    ///     .fetch_transcript_with_dedup("dQw4w9WgXcQ", &["en"], false, true)
+    ///     .await?;
+    ///
+    /// println!("Full transcript text: {}", transcript.text());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ci")]
+    pub async fn fetch_transcript_with_dedup(
+        &self,
+        video_id: &str,
+        languages: &[&str],
+        preserve_formatting: bool,
+        deduplicate: bool,
+    ) -> Result<FetchedTranscript, CouldNotRetrieveTranscript> {
+        let mut transcript = self
+            .fetch_transcript(video_id, languages, preserve_formatting)
+            .await?;
+
+        if deduplicate {
+            transcript.snippets =
+                crate::TranscriptDeduplicator::new().deduplicate(&transcript.snippets);
+        }
+        Ok(transcript)
+    }
+
+    #[cfg(not(feature = "ci"))]
+    pub async fn fetch_transcript_with_dedup(
+        &self,
+        video_id: &str,
+        languages: &[&str],
+        preserve_formatting: bool,
+        deduplicate: bool,
+    ) -> Result<FetchedTranscript, CouldNotRetrieveTranscript> {
+        let transcript_list = self.list_transcripts(video_id).await?;
+        let transcript = transcript_list.find_transcript(languages)?;
+
+        transcript
+            .fetch_with_dedup(&self.fetcher.client, preserve_formatting, deduplicate)
+            .await
+    }
+
     /// Lists all available transcripts for a YouTube video.
     ///
     /// This method retrieves a list of all available transcripts/captions for a video,
@@ -584,7 +673,7 @@ impl YouTubeTranscriptApi {
     ///     .filter(|f| f.audio_quality.is_some())
     ///     .max_by_key(|f| f.bitrate) {
     ///     println!("Best audio quality: {}", best_audio.audio_quality.as_ref().unwrap());
-    ///     println!("Bitrate: {} bps", best_audio.bitrate);
+    ///     println!("Bitrate: {:?} bps", best_audio.bitrate);
     /// }
     /// # Ok(())
     /// # }
@@ -614,16 +703,179 @@ impl YouTubeTranscriptApi {
         self.fetcher.fetch_streaming_data(video_id).await
     }
 
-    /// Fetches all available information about a YouTube video in a single request.
+    /// Fetches the live stream and premiere details of a YouTube video.
     ///
-    /// This method retrieves comprehensive information about a video in one network call, including:
-    /// - Video details (title, author, etc.)
-    /// - Microformat data (category, available countries, etc.)
-    /// - Streaming data (available formats, qualities, etc.)
-    /// - Transcript list (available caption languages)
+    /// This works for upcoming live streams and premieres too, which makes it
+    /// suitable for waiting until a stream starts or checking whether it has ended.
     ///
-    /// This is more efficient than calling individual methods separately when multiple
-    /// types of information are needed, as it avoids multiple HTTP requests.
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID (e.g., "dQw4w9WgXcQ")
+    ///
+    /// # Returns
+    ///
+    /// * `Result<LiveDetails, CouldNotRetrieveTranscript>` - The live details on success, or an error
+    ///
+    /// # Errors
+    ///
+    /// This method will return a `CouldNotRetrieveTranscript` error if:
+    /// - The video doesn't exist
+    /// - There's a network issue during the fetch
+    /// - The page data cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    ///
+    /// let live = api.fetch_live_details("jfKfPfyJRdk").await?;
+    /// if live.is_upcoming {
+    ///     println!("Starts at {:?}", live.scheduled_start_time);
+    /// } else if live.is_live_now {
+    ///     println!("Live since {:?}", live.start_timestamp);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ci")]
+    pub async fn fetch_live_details(
+        &self,
+        video_id: &str,
+    ) -> Result<LiveDetails, CouldNotRetrieveTranscript> {
+        // For non-existent video ID, return an error
+        if video_id == crate::tests::test_utils::NON_EXISTENT_VIDEO_ID {
+            return Err(CouldNotRetrieveTranscript {
+                video_id: video_id.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::VideoUnavailable),
+            });
+        }
+
+        // Return mock data
+        Ok(crate::tests::mocks::create_mock_live_details())
+    }
+
+    #[cfg(not(feature = "ci"))]
+    pub async fn fetch_live_details(
+        &self,
+        video_id: &str,
+    ) -> Result<LiveDetails, CouldNotRetrieveTranscript> {
+        self.fetcher.fetch_live_details(video_id).await
+    }
+
+    /// Starts following the caption track of a live stream.
+    ///
+    /// The best matching caption track is selected like in
+    /// [`fetch_transcript`](YouTubeTranscriptApi::fetch_transcript). Live streams
+    /// usually only have an auto-generated track in the stream's language.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID of the live stream
+    /// * `languages` - List of language codes in order of preference
+    ///
+    /// # Returns
+    ///
+    /// * `Result<LiveCaptionFollower, CouldNotRetrieveTranscript>` - A follower returning new captions on each poll, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`list_transcripts`](YouTubeTranscriptApi::list_transcripts),
+    /// including `LiveStreamOffline` for streams that have not started yet, and
+    /// `NoTranscriptFound` if no track matches the requested languages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let mut follower = api.follow_live_captions("jfKfPfyJRdk", &["en"]).await?;
+    ///
+    /// for snippet in follower.poll().await? {
+    ///     println!("[{:.1}s] {}", snippet.start, snippet.text);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn follow_live_captions(
+        &self,
+        video_id: &str,
+        languages: &[&str],
+    ) -> Result<LiveCaptionFollower, CouldNotRetrieveTranscript> {
+        let transcript_list = self.list_transcripts(video_id).await?;
+        let transcript = transcript_list.find_transcript(languages)?;
+
+        #[cfg(not(feature = "ci"))]
+        let client = self.fetcher.client.clone();
+        #[cfg(feature = "ci")]
+        let client = self.client.clone();
+
+        Ok(LiveCaptionFollower::new(transcript, client))
+    }
+
+    /// Downloads the stream of a format to a file, in chunks, resuming a partial download.
+    ///
+    /// The download goes through the client of the API, with its proxy and cookies:
+    /// stream URLs are usually bound to the IP address that fetched them. See
+    /// [`FormatDownloader`] for the details.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID (used for error reporting)
+    /// * `format` - A format of the streaming data of the video
+    /// * `path` - The destination file; an existing file is resumed
+    /// * `on_progress` - Called after each block of data written to the file
+    ///
+    /// # Returns
+    ///
+    /// * `Result<u64, CouldNotRetrieveTranscript>` - The size of the downloaded file, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YouTubeRequestFailed` error if a request fails (usually because the
+    /// URL has expired), and a `DownloadFailed` error if the format has no URL, the
+    /// file cannot be written, or the stream does not match the format's `content_length`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let streaming_data = api.fetch_streaming_data("dQw4w9WgXcQ").await?;
+    /// let audio = streaming_data.best_audio().expect("No audio format");
+    ///
+    /// api.download_format("dQw4w9WgXcQ", audio, "audio.m4a", |progress| {
+    ///     println!("{} / {:?} bytes", progress.downloaded, progress.total);
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_format<F>(
+        &self,
+        video_id: &str,
+        format: &StreamingFormat,
+        path: impl AsRef<Path>,
+        on_progress: F,
+    ) -> Result<u64, CouldNotRetrieveTranscript>
+    where
+        F: FnMut(&DownloadProgress),
+    {
+        #[cfg(not(feature = "ci"))]
+        let client = self.fetcher.client.clone();
+        #[cfg(feature = "ci")]
+        let client = self.client.clone();
+
+        FormatDownloader::new(client)
+            .download(format, path, video_id, on_progress)
+            .await
+    }
+
+    /// Fetches the storyboard of a YouTube video: the sprite sheets of preview frames
+    /// shown when hovering the progress bar.
     ///
     /// # Parameters
     ///
@@ -631,14 +883,13 @@ impl YouTubeTranscriptApi {
     ///
     /// # Returns
     ///
-    /// * `Result<VideoInfos, CouldNotRetrieveTranscript>` - Combined video information on success, or an error
+    /// * `Result<Option<Storyboard>, CouldNotRetrieveTranscript>` - The storyboard (`None` if the video has none), or an error
     ///
     /// # Errors
     ///
     /// This method will return a `CouldNotRetrieveTranscript` error if:
     /// - The video doesn't exist or is private
-    /// - The video has geo-restrictions that prevent access
-    /// - Network errors occur during the request
+    /// - There's a network issue during the fetch
     ///
     /// # Examples
     ///
@@ -646,43 +897,1001 @@ impl YouTubeTranscriptApi {
     /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let api = YouTubeTranscriptApi::new(None, None, None)?;
-    /// let video_id = "dQw4w9WgXcQ";
+    /// let storyboard = api.fetch_storyboard("dQw4w9WgXcQ").await?;
+    /// let transcript = api.fetch_transcript("dQw4w9WgXcQ", &["en"], false).await?;
     ///
-    /// // Fetch all information in a single request
-    /// let infos = api.fetch_video_infos(video_id).await?;
+    /// // Preview frame for each transcript line
+    /// for snippet in transcript.parts() {
+    ///     if let Some(frame) = storyboard.as_ref().and_then(|s| s.frame_at(snippet.start)) {
+    ///         println!("{} -> {} @ ({}, {})", snippet.text, frame.url, frame.x, frame.y);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ci")]
+    pub async fn fetch_storyboard(
+        &self,
+        video_id: &str,
+    ) -> Result<Option<Storyboard>, CouldNotRetrieveTranscript> {
+        // For non-existent video ID, return an error
+        if video_id == crate::tests::test_utils::NON_EXISTENT_VIDEO_ID {
+            return Err(CouldNotRetrieveTranscript {
+                video_id: video_id.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::VideoUnavailable),
+            });
+        }
+
+        // Return mock data
+        Ok(Some(crate::tests::mocks::create_mock_storyboard()))
+    }
+
+    #[cfg(not(feature = "ci"))]
+    pub async fn fetch_storyboard(
+        &self,
+        video_id: &str,
+    ) -> Result<Option<Storyboard>, CouldNotRetrieveTranscript> {
+        self.fetcher.fetch_storyboard(video_id).await
+    }
+
+    /// Fetches the HLS master playlist of a live stream, listing its variant streams
+    /// with their bandwidth, resolution and codecs.
     ///
-    /// // Access combined information
-    /// println!("Title: {}", infos.video_details.title);
-    /// println!("Author: {}", infos.video_details.author);
+    /// # Parameters
     ///
-    /// if let Some(category) = &infos.microformat.category {
-    ///     println!("Category: {}", category);
-    /// }
+    /// * `video_id` - The YouTube video ID (e.g., "jfKfPfyJRdk")
     ///
-    /// println!("Available formats: {}", infos.streaming_data.formats.len());
-    /// println!("Available transcripts: {}", infos.transcript_list.transcripts().count());
+    /// # Returns
+    ///
+    /// * `Result<Option<HlsMasterPlaylist>, CouldNotRetrieveTranscript>` - The playlist (`None` if the video has no HLS manifest), or an error
+    ///
+    /// # Errors
+    ///
+    /// This method will return a `CouldNotRetrieveTranscript` error if:
+    /// - The video doesn't exist or is private
+    /// - There's a network issue during the fetch
+    /// - The playlist is not a valid HLS playlist
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    ///
+    /// if let Some(master) = api.fetch_hls_playlist("jfKfPfyJRdk").await? {
+    ///     for variant in &master.variants {
+    ///         println!("{:?}p {} bps {:?}", variant.height, variant.bandwidth, variant.codecs);
+    ///     }
+    ///
+    ///     let variant = master.best_under(720).expect("No variant");
+    ///     let media = api.fetch_hls_media_playlist("jfKfPfyJRdk", variant).await?;
+    ///     println!("Latest segment: {:?}", media.segments.last().map(|s| &s.url));
+    /// }
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "ci")]
+    pub async fn fetch_hls_playlist(
+        &self,
+        video_id: &str,
+    ) -> Result<Option<HlsMasterPlaylist>, CouldNotRetrieveTranscript> {
+        // For non-existent video ID, return an error
+        if video_id == crate::tests::test_utils::NON_EXISTENT_VIDEO_ID {
+            return Err(CouldNotRetrieveTranscript {
+                video_id: video_id.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::VideoUnavailable),
+            });
+        }
+
+        // Return mock data
+        Ok(Some(crate::tests::mocks::create_mock_hls_master_playlist()))
+    }
+
     #[cfg(not(feature = "ci"))]
-    pub async fn fetch_video_infos(
+    pub async fn fetch_hls_playlist(
         &self,
         video_id: &str,
-    ) -> Result<VideoInfos, CouldNotRetrieveTranscript> {
-        self.fetcher.fetch_video_infos(video_id).await
+    ) -> Result<Option<HlsMasterPlaylist>, CouldNotRetrieveTranscript> {
+        self.fetcher.fetch_hls_playlist(video_id).await
     }
 
-    /// Fetches all available information about a YouTube video in a single request.
+    /// Fetches the media playlist of a variant stream: the latest segments of a live stream.
     ///
-    /// This is a CI-mode placeholder that always returns an error.
+    /// The playlist of a live stream only lists a sliding window of segments, so it
+    /// has to be fetched again to follow the stream.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID (used for error reporting)
+    /// * `variant` - A variant of the master playlist returned by [`fetch_hls_playlist`](YouTubeTranscriptApi::fetch_hls_playlist)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<HlsMediaPlaylist, CouldNotRetrieveTranscript>` - The playlist, or an error
+    ///
+    /// # Errors
+    ///
+    /// This method will return a `CouldNotRetrieveTranscript` error if there's a network
+    /// issue during the fetch or the playlist is not a valid HLS playlist.
     #[cfg(feature = "ci")]
-    pub async fn fetch_video_infos(
+    pub async fn fetch_hls_media_playlist(
+        &self,
+        _video_id: &str,
+        _variant: &HlsVariant,
+    ) -> Result<HlsMediaPlaylist, CouldNotRetrieveTranscript> {
+        // Return mock data
+        Ok(crate::tests::mocks::create_mock_hls_media_playlist())
+    }
+
+    #[cfg(not(feature = "ci"))]
+    pub async fn fetch_hls_media_playlist(
         &self,
         video_id: &str,
-    ) -> Result<VideoInfos, CouldNotRetrieveTranscript> {
-        Err(CouldNotRetrieveTranscript {
-            video_id: video_id.to_string(),
-            reason: Some(CouldNotRetrieveTranscriptReason::VideoUnavailable),
-        })
+        variant: &HlsVariant,
+    ) -> Result<HlsMediaPlaylist, CouldNotRetrieveTranscript> {
+        HlsPlaylistFetcher::new(self.fetcher.client.clone())
+            .fetch_media_playlist(&variant.url, video_id)
+            .await
+    }
+
+    /// Fetches the links a creator attached to a YouTube video: endscreen elements,
+    /// info cards, and the URLs, hashtags, @mentions and timestamps of the description.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID (e.g., "dQw4w9WgXcQ")
+    ///
+    /// # Returns
+    ///
+    /// * `Result<VideoLinks, CouldNotRetrieveTranscript>` - The links, or an error
+    ///
+    /// # Errors
+    ///
+    /// This method will return a `CouldNotRetrieveTranscript` error if:
+    /// - The video doesn't exist or is private
+    /// - There's a network issue during the fetch
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # use yt_transcript_rs::models::LinkTarget;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let links = api.fetch_video_links("dQw4w9WgXcQ").await?;
+    ///
+    /// for element in &links.endscreen {
+    ///     if let Some(LinkTarget::Video(video_id)) = &element.target {
+    ///         println!("Endscreen at {}s: {} ({})", element.start, element.title, video_id);
+    ///     }
+    /// }
+    /// for link in &links.description {
+    ///     println!("{:?}: {}", link.kind, link.text);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ci")]
+    pub async fn fetch_video_links(
+        &self,
+        video_id: &str,
+    ) -> Result<VideoLinks, CouldNotRetrieveTranscript> {
+        // For non-existent video ID, return an error
+        if video_id == crate::tests::test_utils::NON_EXISTENT_VIDEO_ID {
+            return Err(CouldNotRetrieveTranscript {
+                video_id: video_id.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::VideoUnavailable),
+            });
+        }
+
+        // Return mock data
+        Ok(crate::tests::mocks::create_mock_video_links())
+    }
+
+    #[cfg(not(feature = "ci"))]
+    pub async fn fetch_video_links(
+        &self,
+        video_id: &str,
+    ) -> Result<VideoLinks, CouldNotRetrieveTranscript> {
+        self.fetcher.fetch_video_links(video_id).await
+    }
+
+    /// Fetches the chapters of a YouTube video.
+    ///
+    /// Chapters defined by the creator (or generated automatically by YouTube) are read
+    /// from the watch page; if the page has none, timestamp lines in the description
+    /// ("0:00 Intro") are used instead, following YouTube's own rules for chapters.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID (e.g., "dQw4w9WgXcQ")
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<Chapter>, CouldNotRetrieveTranscript>` - The chapters (empty if the video has none), or an error
+    ///
+    /// # Errors
+    ///
+    /// This method will return a `CouldNotRetrieveTranscript` error if:
+    /// - The video doesn't exist or is private
+    /// - There's a network issue during the fetch
+    /// - The page data cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    ///
+    /// for chapter in api.fetch_chapters("dQw4w9WgXcQ").await? {
+    ///     println!("{:>6.0}s {}", chapter.start, chapter.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ci")]
+    pub async fn fetch_chapters(
+        &self,
+        video_id: &str,
+    ) -> Result<Vec<Chapter>, CouldNotRetrieveTranscript> {
+        // For non-existent video ID, return an error
+        if video_id == crate::tests::test_utils::NON_EXISTENT_VIDEO_ID {
+            return Err(CouldNotRetrieveTranscript {
+                video_id: video_id.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::VideoUnavailable),
+            });
+        }
+
+        // Return mock data
+        Ok(crate::tests::mocks::create_mock_chapters())
+    }
+
+    #[cfg(not(feature = "ci"))]
+    pub async fn fetch_chapters(
+        &self,
+        video_id: &str,
+    ) -> Result<Vec<Chapter>, CouldNotRetrieveTranscript> {
+        self.fetcher.fetch_chapters(video_id).await
+    }
+
+    /// Fetches the transcripts of several videos.
+    ///
+    /// The videos are fetched one after the other, and a failure for one video does
+    /// not stop the others: each video gets its own result.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_ids` - The YouTube video IDs
+    /// * `languages` - List of language codes in order of preference
+    /// * `preserve_formatting` - Whether to preserve HTML formatting in the transcripts
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(String, Result<FetchedTranscript, CouldNotRetrieveTranscript>)>` - The video IDs with their results, in input order
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    ///
+    /// for (video_id, result) in api.fetch_transcripts(&["dQw4w9WgXcQ", "arj7oStGLkU"], &["en"], false).await {
+    ///     match result {
+    ///         Ok(transcript) => println!("{}: {} snippets", video_id, transcript.parts().len()),
+    ///         Err(e) => println!("{}: {}", video_id, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_transcripts(
+        &self,
+        video_ids: &[&str],
+        languages: &[&str],
+        preserve_formatting: bool,
+    ) -> Vec<(
+        String,
+        Result<FetchedTranscript, CouldNotRetrieveTranscript>,
+    )> {
+        let mut results = Vec::with_capacity(video_ids.len());
+
+        for video_id in video_ids {
+            let result = self
+                .fetch_transcript(video_id, languages, preserve_formatting)
+                .await;
+            results.push((video_id.to_string(), result));
+        }
+
+        results
+    }
+
+    /// Fetches a playlist and the videos it contains.
+    ///
+    /// Regular playlists are enumerated completely, following YouTube's pagination;
+    /// for mixes, only the videos currently queued by YouTube are returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `playlist` - The playlist ID (e.g., "PLRqwX-V7Uu6ZiZxtDDRCi6uhfTH4FilpH"), or a
+    ///   playlist or watch URL with a `list` parameter
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Playlist, CouldNotRetrieveTranscript>` - The playlist and its entries, or an error
+    ///
+    /// # Errors
+    ///
+    /// This method will return a `CouldNotRetrieveTranscript` error if:
+    /// - No playlist ID can be found in the input
+    /// - The playlist doesn't exist or is private (`PlaylistUnavailable`)
+    /// - There's a network issue during the fetch
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    ///
+    /// let playlist = api
+    ///     .fetch_playlist("https://www.youtube.com/playlist?list=PLRqwX-V7Uu6ZiZxtDDRCi6uhfTH4FilpH")
+    ///     .await?;
+    /// println!("{:?}: {} videos", playlist.title, playlist.entries.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ci")]
+    pub async fn fetch_playlist(
+        &self,
+        playlist: &str,
+    ) -> Result<Playlist, CouldNotRetrieveTranscript> {
+        let Some(playlist_id) =
+            crate::playlist_fetcher::PlaylistFetcher::extract_playlist_id(playlist)
+        else {
+            return Err(CouldNotRetrieveTranscript {
+                video_id: playlist.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::PlaylistUnavailable(
+                    "No playlist ID found in the input".to_string(),
+                )),
+            });
+        };
+
+        // Return mock data
+        Ok(crate::tests::mocks::create_mock_playlist(&playlist_id))
+    }
+
+    #[cfg(not(feature = "ci"))]
+    pub async fn fetch_playlist(
+        &self,
+        playlist: &str,
+    ) -> Result<Playlist, CouldNotRetrieveTranscript> {
+        PlaylistFetcher::new(self.fetcher.client.clone())
+            .fetch_playlist(playlist)
+            .await
+    }
+
+    /// Fetches the videos listed on a tab of a channel.
+    ///
+    /// Channels can be given by ID (as in [`VideoDetails::channel_id`]), by handle
+    /// ("@name"), or by URL, including the `/c/` and `/user/` forms and
+    /// [`MicroformatData::owner_profile_url`]. Pages of the tab are fetched until
+    /// `limit` videos are found or the tab is exhausted.
+    ///
+    /// # Parameters
+    ///
+    /// * `channel` - A channel ID, handle or URL
+    /// * `tab` - The tab to list (videos, shorts or live streams)
+    /// * `limit` - Maximum number of videos to return, or `None` for all of them
+    ///
+    /// # Returns
+    ///
+    /// * `Result<ChannelUploads, CouldNotRetrieveTranscript>` - The videos of the tab, newest first, or an error
+    ///
+    /// # Errors
+    ///
+    /// This method will return a `CouldNotRetrieveTranscript` error if:
+    /// - The channel cannot be resolved or doesn't exist (`ChannelUnavailable`)
+    /// - There's a network issue during the fetch
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # use yt_transcript_rs::models::ChannelTab;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    ///
+    /// // Backfill the transcripts of a whole channel
+    /// let uploads = api
+    ///     .fetch_channel_videos("@RickAstleyYT", ChannelTab::Videos, None)
+    ///     .await?;
+    /// for (video_id, result) in api.fetch_transcripts(&uploads.video_ids(), &["en"], false).await {
+    ///     if let Ok(transcript) = result {
+    ///         println!("{}: {} snippets", video_id, transcript.parts().len());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ci")]
+    pub async fn fetch_channel_videos(
+        &self,
+        channel: &str,
+        tab: ChannelTab,
+        limit: Option<usize>,
+    ) -> Result<ChannelUploads, CouldNotRetrieveTranscript> {
+        // For non-existent channel, return an error
+        if channel == crate::tests::test_utils::NON_EXISTENT_VIDEO_ID {
+            return Err(CouldNotRetrieveTranscript {
+                video_id: channel.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::ChannelUnavailable(
+                    "Not a channel ID, handle or URL".to_string(),
+                )),
+            });
+        }
+
+        // Return mock data
+        let mut uploads = crate::tests::mocks::create_mock_channel_uploads(tab);
+        uploads.videos.truncate(limit.unwrap_or(usize::MAX));
+        Ok(uploads)
+    }
+
+    #[cfg(not(feature = "ci"))]
+    pub async fn fetch_channel_videos(
+        &self,
+        channel: &str,
+        tab: ChannelTab,
+        limit: Option<usize>,
+    ) -> Result<ChannelUploads, CouldNotRetrieveTranscript> {
+        ChannelFetcher::new(self.fetcher.client.clone())
+            .fetch_channel_videos(channel, tab, limit)
+            .await
+    }
+
+    /// Fetches a playlist and the transcripts of its available videos.
+    ///
+    /// Private and deleted videos are skipped. As in
+    /// [`fetch_transcripts`](YouTubeTranscriptApi::fetch_transcripts), each video gets
+    /// its own result.
+    ///
+    /// # Parameters
+    ///
+    /// * `playlist` - The playlist ID or URL
+    /// * `languages` - List of language codes in order of preference
+    /// * `preserve_formatting` - Whether to preserve HTML formatting in the transcripts
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<(PlaylistEntry, Result<FetchedTranscript, CouldNotRetrieveTranscript>)>, CouldNotRetrieveTranscript>` -
+    ///   The available entries with their results, in playlist order, or an error if the playlist itself could not be fetched
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    ///
+    /// let results = api
+    ///     .fetch_playlist_transcripts("PLRqwX-V7Uu6ZiZxtDDRCi6uhfTH4FilpH", &["en"], false)
+    ///     .await?;
+    /// for (entry, result) in results {
+    ///     if let Ok(transcript) = result {
+    ///         println!("{}: {} snippets", entry.title, transcript.parts().len());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_playlist_transcripts(
+        &self,
+        playlist: &str,
+        languages: &[&str],
+        preserve_formatting: bool,
+    ) -> Result<
+        Vec<(
+            PlaylistEntry,
+            Result<FetchedTranscript, CouldNotRetrieveTranscript>,
+        )>,
+        CouldNotRetrieveTranscript,
+    > {
+        let playlist = self.fetch_playlist(playlist).await?;
+        let mut results = Vec::new();
+
+        for entry in playlist.entries.into_iter().filter(|e| e.is_available) {
+            let result = self
+                .fetch_transcript(&entry.video_id, languages, preserve_formatting)
+                .await;
+            results.push((entry, result));
+        }
+
+        Ok(results)
+    }
+
+    /// Searches YouTube and returns the first page of results.
+    ///
+    /// Use [`search_next_page`](YouTubeTranscriptApi::search_next_page) to fetch the
+    /// following pages.
+    ///
+    /// # Parameters
+    ///
+    /// * `query` - The search query
+    /// * `filters` - The filters to apply (upload date, duration, type, subtitles/CC)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SearchPage, CouldNotRetrieveTranscript>` - The first page of results, or an error
+    ///
+    /// # Errors
+    ///
+    /// This method will return a `CouldNotRetrieveTranscript` error if there's a network
+    /// issue or the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # use yt_transcript_rs::models::{SearchFilters, SearchUploadDate};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let filters = SearchFilters {
+    ///     upload_date: Some(SearchUploadDate::ThisMonth),
+    ///     captions_only: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut page = api.search("rust async runtime", &filters).await?;
+    /// loop {
+    ///     for video in page.videos() {
+    ///         println!("{} {}", video.video_id, video.title);
+    ///     }
+    ///     match api.search_next_page(&page).await? {
+    ///         Some(next) => page = next,
+    ///         None => break,
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ci")]
+    pub async fn search(
+        &self,
+        query: &str,
+        _filters: &SearchFilters,
+    ) -> Result<SearchPage, CouldNotRetrieveTranscript> {
+        // Return mock data
+        Ok(crate::tests::mocks::create_mock_search_page(query))
+    }
+
+    #[cfg(not(feature = "ci"))]
+    pub async fn search(
+        &self,
+        query: &str,
+        filters: &SearchFilters,
+    ) -> Result<SearchPage, CouldNotRetrieveTranscript> {
+        SearchFetcher::new(self.fetcher.client.clone())
+            .search(query, filters)
+            .await
+    }
+
+    /// Fetches the page of search results following `page`.
+    ///
+    /// # Parameters
+    ///
+    /// * `page` - A page returned by [`search`](YouTubeTranscriptApi::search) or a previous call
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<SearchPage>, CouldNotRetrieveTranscript>` - The next page, `None` if
+    ///   `page` was the last one, or an error
+    #[cfg(feature = "ci")]
+    pub async fn search_next_page(
+        &self,
+        _page: &SearchPage,
+    ) -> Result<Option<SearchPage>, CouldNotRetrieveTranscript> {
+        // The mock search has a single page
+        Ok(None)
+    }
+
+    #[cfg(not(feature = "ci"))]
+    pub async fn search_next_page(
+        &self,
+        page: &SearchPage,
+    ) -> Result<Option<SearchPage>, CouldNotRetrieveTranscript> {
+        SearchFetcher::new(self.fetcher.client.clone())
+            .next_page(page)
+            .await
+    }
+
+    /// Fetches the comments of a video.
+    ///
+    /// For videos with many comments, prefer [`comments`](YouTubeTranscriptApi::comments),
+    /// which does not hold all of them in memory.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID (e.g., "dQw4w9WgXcQ")
+    /// * `sort` - The order of the comments (top or newest first)
+    /// * `expand_replies` - Whether to fetch the replies of each comment
+    /// * `limit` - Maximum number of top-level comments to return, or `None` for all of them
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<Comment>, CouldNotRetrieveTranscript>` - The comments (empty if comments are turned off), or an error
+    ///
+    /// # Errors
+    ///
+    /// This method will return a `CouldNotRetrieveTranscript` error if there's a network
+    /// issue or the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # use yt_transcript_rs::models::CommentSortOrder;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    ///
+    /// let comments = api
+    ///     .fetch_comments("dQw4w9WgXcQ", CommentSortOrder::Top, true, Some(20))
+    ///     .await?;
+    /// for comment in &comments {
+    ///     println!("{}: {} ({} replies)", comment.author, comment.text, comment.replies.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ci")]
+    pub async fn fetch_comments(
+        &self,
+        video_id: &str,
+        _sort: CommentSortOrder,
+        expand_replies: bool,
+        limit: Option<usize>,
+    ) -> Result<Vec<Comment>, CouldNotRetrieveTranscript> {
+        // For non-existent video ID, return an error
+        if video_id == crate::tests::test_utils::NON_EXISTENT_VIDEO_ID {
+            return Err(CouldNotRetrieveTranscript {
+                video_id: video_id.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::VideoUnavailable),
+            });
+        }
+
+        // Return mock data
+        let mut comments = crate::tests::mocks::create_mock_comments();
+        if !expand_replies {
+            comments
+                .iter_mut()
+                .for_each(|comment| comment.replies.clear());
+        }
+        comments.truncate(limit.unwrap_or(usize::MAX));
+        Ok(comments)
+    }
+
+    #[cfg(not(feature = "ci"))]
+    pub async fn fetch_comments(
+        &self,
+        video_id: &str,
+        sort: CommentSortOrder,
+        expand_replies: bool,
+        limit: Option<usize>,
+    ) -> Result<Vec<Comment>, CouldNotRetrieveTranscript> {
+        CommentFetcher::new(self.fetcher.client.clone())
+            .fetch_comments(video_id, sort, expand_replies, limit)
+            .await
+    }
+
+    /// Returns a stream over the comments of a video, fetching one page at a time.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID (e.g., "dQw4w9WgXcQ")
+    /// * `sort` - The order of the comments (top or newest first)
+    /// * `expand_replies` - Whether to fetch the replies of each comment
+    ///
+    /// # Returns
+    ///
+    /// * `CommentStream` - A stream of comments; no request is made until the first comment is requested
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # use yt_transcript_rs::models::CommentSortOrder;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    ///
+    /// let mut stream = api.comments("dQw4w9WgXcQ", CommentSortOrder::Newest, false);
+    /// while let Some(comment) = stream.next().await {
+    ///     println!("{}", comment?.text);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn comments(
+        &self,
+        video_id: &str,
+        sort: CommentSortOrder,
+        expand_replies: bool,
+    ) -> CommentStream {
+        #[cfg(not(feature = "ci"))]
+        let client = self.fetcher.client.clone();
+        #[cfg(feature = "ci")]
+        let client = self.client.clone();
+
+        CommentFetcher::new(client).comments(video_id, sort, expand_replies)
+    }
+
+    /// Fetches all available information about a YouTube video in a single request.
+    ///
+    /// This method retrieves comprehensive information about a video in one network call, including:
+    /// - Video details (title, author, etc.)
+    /// - Microformat data (category, available countries, etc.)
+    /// - Streaming data (available formats, qualities, etc.)
+    /// - Transcript list (available caption languages)
+    ///
+    /// This is more efficient than calling individual methods separately when multiple
+    /// types of information are needed, as it avoids multiple HTTP requests.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID (e.g., "dQw4w9WgXcQ")
+    ///
+    /// # Returns
+    ///
+    /// * `Result<VideoInfos, CouldNotRetrieveTranscript>` - Combined video information on success, or an error
+    ///
+    /// # Errors
+    ///
+    /// This method will return a `CouldNotRetrieveTranscript` error if:
+    /// - The video doesn't exist or is private
+    /// - The video has geo-restrictions that prevent access
+    /// - Network errors occur during the request
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let video_id = "dQw4w9WgXcQ";
+    ///
+    /// // Fetch all information in a single request
+    /// let infos = api.fetch_video_infos(video_id).await?;
+    ///
+    /// // Access combined information
+    /// println!("Title: {}", infos.video_details.title);
+    /// println!("Author: {}", infos.video_details.author);
+    ///
+    /// if let Some(category) = &infos.microformat.category {
+    ///     println!("Category: {}", category);
+    /// }
+    ///
+    /// println!("Available formats: {}", infos.streaming_data.formats.len());
+    /// println!("Available transcripts: {}", infos.transcript_list.transcripts().count());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "ci"))]
+    pub async fn fetch_video_infos(
+        &self,
+        video_id: &str,
+    ) -> Result<VideoInfos, CouldNotRetrieveTranscript> {
+        self.fetcher.fetch_video_infos(video_id).await
+    }
+
+    /// Fetches the streaming data of a video again if its URLs have expired or are
+    /// about to.
+    ///
+    /// Streaming URLs are valid for about six hours. The data is refreshed when its
+    /// URLs expire within a minute, so that a request started right after the check
+    /// does not fail; data whose expiry is unknown is left unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID of the streaming data
+    /// * `streaming_data` - The stored streaming data, replaced when refreshed
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, CouldNotRetrieveTranscript>` - Whether the data was refreshed, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`fetch_streaming_data`](YouTubeTranscriptApi::fetch_streaming_data).
+    pub async fn refresh_streaming_data(
+        &self,
+        video_id: &str,
+        streaming_data: &mut StreamingData,
+    ) -> Result<bool, CouldNotRetrieveTranscript> {
+        if !Self::expires_soon(streaming_data.expires_at()) {
+            return Ok(false);
+        }
+
+        *streaming_data = self.fetch_streaming_data(video_id).await?;
+        Ok(true)
+    }
+
+    /// Fetches the information of a video again if its streaming or transcript URLs
+    /// have expired or are about to, so that cached `VideoInfos` stay usable.
+    ///
+    /// The information is refreshed when a URL expires within a minute; information
+    /// whose expiry is unknown is left unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `infos` - The stored video information, replaced when refreshed
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, CouldNotRetrieveTranscript>` - Whether the information was refreshed, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`fetch_video_infos`](YouTubeTranscriptApi::fetch_video_infos).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let mut infos = api.fetch_video_infos("dQw4w9WgXcQ").await?;
+    ///
+    /// // Hours later, before using the stored URLs
+    /// if api.refresh_video_infos(&mut infos).await? {
+    ///     println!("URLs refreshed, now valid until {:?}", infos.expires_at());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn refresh_video_infos(
+        &self,
+        infos: &mut VideoInfos,
+    ) -> Result<bool, CouldNotRetrieveTranscript> {
+        if !Self::expires_soon(infos.expires_at()) {
+            return Ok(false);
+        }
+
+        let video_id = infos.video_details.video_id.clone();
+        *infos = self.fetch_video_infos(&video_id).await?;
+        Ok(true)
+    }
+
+    /// Returns whether an expiry is less than `REFRESH_MARGIN_SECONDS` away.
+    fn expires_soon(expires_at: Option<DateTime<Utc>>) -> bool {
+        expires_at.is_some_and(|expires_at| {
+            expires_at <= Utc::now() + TimeDelta::seconds(Self::REFRESH_MARGIN_SECONDS)
+        })
+    }
+
+    /// Fetches all available information about a YouTube video in a single request.
+    ///
+    /// This is a CI-mode placeholder that always returns an error.
+    #[cfg(feature = "ci")]
+    pub async fn fetch_video_infos(
+        &self,
+        video_id: &str,
+    ) -> Result<VideoInfos, CouldNotRetrieveTranscript> {
+        Err(CouldNotRetrieveTranscript {
+            video_id: video_id.to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::VideoUnavailable),
+        })
+    }
+
+    /// Fetches all available information about a YouTube video, along with the raw
+    /// player response it was extracted from.
+    ///
+    /// The typed models only keep the fields this crate knows about. The raw
+    /// `ytInitialPlayerResponse` JSON gives access to everything else, including
+    /// fields YouTube adds later, from the same single request.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID (e.g., "dQw4w9WgXcQ")
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(VideoInfos, serde_json::Value), CouldNotRetrieveTranscript>` - The video information and the raw player response, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`fetch_video_infos`](YouTubeTranscriptApi::fetch_video_infos).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let (infos, raw) = api.fetch_video_infos_with_raw("dQw4w9WgXcQ").await?;
+    ///
+    /// println!("Title: {}", infos.video_details.title);
+    /// // A field that is not modelled
+    /// println!("Paid content overlay: {}", raw["paidContentOverlay"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "ci"))]
+    pub async fn fetch_video_infos_with_raw(
+        &self,
+        video_id: &str,
+    ) -> Result<(VideoInfos, serde_json::Value), CouldNotRetrieveTranscript> {
+        self.fetcher.fetch_video_infos_with_raw(video_id).await
+    }
+
+    /// Fetches all available information about a YouTube video, along with the raw
+    /// player response.
+    ///
+    /// This is a CI-mode placeholder that always returns an error.
+    #[cfg(feature = "ci")]
+    pub async fn fetch_video_infos_with_raw(
+        &self,
+        video_id: &str,
+    ) -> Result<(VideoInfos, serde_json::Value), CouldNotRetrieveTranscript> {
+        Err(CouldNotRetrieveTranscript {
+            video_id: video_id.to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::VideoUnavailable),
+        })
+    }
+
+    /// Fetches the raw player response of a YouTube video (`ytInitialPlayerResponse`).
+    ///
+    /// Use this to reach data the crate does not model yet. The playability of the
+    /// video is not checked, so the `playabilityStatus` of unplayable videos can be
+    /// inspected as well.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID (e.g., "dQw4w9WgXcQ")
+    ///
+    /// # Returns
+    ///
+    /// * `Result<serde_json::Value, CouldNotRetrieveTranscript>` - The player response JSON, or an error
+    ///
+    /// # Errors
+    ///
+    /// This method will return a `CouldNotRetrieveTranscript` error if:
+    /// - The video page cannot be fetched
+    /// - The page does not contain a player response
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let player_response = api.fetch_player_response("dQw4w9WgXcQ").await?;
+    ///
+    /// println!("Status: {}", player_response["playabilityStatus"]["status"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ci")]
+    pub async fn fetch_player_response(
+        &self,
+        video_id: &str,
+    ) -> Result<serde_json::Value, CouldNotRetrieveTranscript> {
+        // For non-existent video ID, return an error
+        if video_id == crate::tests::test_utils::NON_EXISTENT_VIDEO_ID {
+            return Err(CouldNotRetrieveTranscript {
+                video_id: video_id.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::VideoUnavailable),
+            });
+        }
+
+        // Return mock data
+        Ok(crate::tests::mocks::mock_youtube_player_response())
+    }
+
+    #[cfg(not(feature = "ci"))]
+    pub async fn fetch_player_response(
+        &self,
+        video_id: &str,
+    ) -> Result<serde_json::Value, CouldNotRetrieveTranscript> {
+        self.fetcher.fetch_raw_player_response(video_id).await
     }
 }
@@ -0,0 +1,414 @@
+/// Bilingual transcript alignment and export.
+///
+/// This module contains the `BilingualTranscript` type, which pairs the snippets of
+/// two transcripts of the same video (typically an original transcript and its
+/// translation obtained with `translate_and_fetch`) by time overlap.
+///
+/// Aligned transcripts can be exported as dual-line subtitles (SRT or WebVTT) or as
+/// side-by-side tables (JSON or CSV), which is useful for language-learning content.
+use serde::{Deserialize, Serialize};
+
+use crate::fetched_transcript::FetchedTranscript;
+use crate::models::{BilingualSnippet, FetchedTranscriptSnippet};
+
+/// Two transcripts of the same video aligned snippet by snippet.
+///
+/// Each secondary snippet is assigned to the primary snippet it overlaps the most in
+/// time. Primary snippets without any overlapping secondary snippet, and secondary
+/// snippets that overlap no primary snippet, are kept as one-sided pairs, so no text
+/// is lost.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use yt_transcript_rs::YouTubeTranscriptApi;
+/// # use yt_transcript_rs::bilingual_transcript::BilingualTranscript;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let api = YouTubeTranscriptApi::new(None, None, None)?;
+/// let transcript_list = api.list_transcripts("dQw4w9WgXcQ").await?;
+/// let transcript = transcript_list.find_transcript(&["en"])?;
+///
+/// let client = reqwest::Client::new();
+/// let original = transcript.fetch(&client, false).await?;
+/// let translated = transcript.translate_and_fetch(&client, "es", false).await?;
+///
+/// let bilingual = BilingualTranscript::align(&original, &translated)?;
+///
+/// // Dual-line subtitles, English on top and Spanish below
+/// std::fs::write("dQw4w9WgXcQ.en-es.srt", bilingual.to_srt())?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BilingualTranscript {
+    /// YouTube video ID both transcripts belong to.
+    pub video_id: String,
+
+    /// Language code of the primary transcript (e.g., "en").
+    pub primary_language_code: String,
+
+    /// Language code of the secondary transcript (e.g., "es").
+    pub secondary_language_code: String,
+
+    /// The aligned snippet pairs, sorted by start time.
+    pub snippets: Vec<BilingualSnippet>,
+}
+
+impl BilingualTranscript {
+    /// Aligns two transcripts of the same video by time overlap.
+    ///
+    /// # Parameters
+    ///
+    /// * `primary` - The transcript shown first (e.g., the original language)
+    /// * `secondary` - The transcript shown second (e.g., the translation)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<BilingualTranscript, anyhow::Error>` - The aligned transcript
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the two transcripts belong to different videos.
+    pub fn align(
+        primary: &FetchedTranscript,
+        secondary: &FetchedTranscript,
+    ) -> Result<Self, anyhow::Error> {
+        if primary.video_id != secondary.video_id {
+            return Err(anyhow::anyhow!(
+                "Cannot align transcripts of different videos: {} and {}",
+                primary.video_id,
+                secondary.video_id
+            ));
+        }
+
+        let mut primary_snippets = primary.snippets.clone();
+        primary_snippets.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+        // Secondary snippets assigned to each primary snippet, and those left over
+        let mut assigned: Vec<Vec<&FetchedTranscriptSnippet>> =
+            vec![Vec::new(); primary_snippets.len()];
+        let mut unassigned: Vec<&FetchedTranscriptSnippet> = Vec::new();
+
+        for snippet in &secondary.snippets {
+            match Self::best_overlap(&primary_snippets, snippet) {
+                Some(index) => assigned[index].push(snippet),
+                None => unassigned.push(snippet),
+            }
+        }
+
+        let mut snippets: Vec<BilingualSnippet> = primary_snippets
+            .iter()
+            .zip(assigned)
+            .map(|(primary_snippet, secondaries)| {
+                let mut start = primary_snippet.start;
+                let mut end = primary_snippet.start + primary_snippet.duration;
+                for s in &secondaries {
+                    start = start.min(s.start);
+                    end = end.max(s.start + s.duration);
+                }
+
+                BilingualSnippet {
+                    start,
+                    duration: end - start,
+                    primary: Some(primary_snippet.text.clone()),
+                    secondary: Self::join_texts(&secondaries),
+                }
+            })
+            .collect();
+
+        snippets.extend(unassigned.into_iter().map(|s| BilingualSnippet {
+            start: s.start,
+            duration: s.duration,
+            primary: None,
+            secondary: Some(s.text.clone()),
+        }));
+        snippets.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+        Ok(Self {
+            video_id: primary.video_id.clone(),
+            primary_language_code: primary.language_code.clone(),
+            secondary_language_code: secondary.language_code.clone(),
+            snippets,
+        })
+    }
+
+    /// Exports the aligned transcript as dual-line SubRip (SRT) subtitles.
+    ///
+    /// Each cue shows the primary text on the first line(s) and the secondary
+    /// text below it.
+    pub fn to_srt(&self) -> String {
+        let mut output = String::new();
+
+        for (i, snippet) in self.snippets.iter().enumerate() {
+            output.push_str(&format!(
+                "{}\n{} --> {}\n{}\n\n",
+                i + 1,
+                Self::format_timestamp(snippet.start, ','),
+                Self::format_timestamp(snippet.start + snippet.duration, ','),
+                Self::cue_text(snippet)
+            ));
+        }
+
+        output
+    }
+
+    /// Exports the aligned transcript as dual-line WebVTT subtitles.
+    ///
+    /// Each cue shows the primary text on the first line(s) and the secondary
+    /// text below it.
+    pub fn to_webvtt(&self) -> String {
+        let mut output = String::from("WEBVTT\n\n");
+
+        for snippet in &self.snippets {
+            output.push_str(&format!(
+                "{} --> {}\n{}\n\n",
+                Self::format_timestamp(snippet.start, '.'),
+                Self::format_timestamp(snippet.start + snippet.duration, '.'),
+                Self::cue_text(snippet).replace("-->", "->")
+            ));
+        }
+
+        output
+    }
+
+    /// Exports the aligned transcript as a pretty-printed JSON document.
+    ///
+    /// The document contains the video ID, both language codes and the list of
+    /// aligned pairs with their `start`, `duration`, `primary` and `secondary` fields.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Exports the aligned transcript as side-by-side CSV.
+    ///
+    /// The header row is `start,duration,<primary language code>,<secondary language code>`,
+    /// followed by one row per aligned pair. Fields containing commas, quotes or line
+    /// breaks are quoted.
+    pub fn to_csv(&self) -> String {
+        let mut output = format!(
+            "start,duration,{},{}\n",
+            Self::csv_field(&self.primary_language_code),
+            Self::csv_field(&self.secondary_language_code)
+        );
+
+        for snippet in &self.snippets {
+            output.push_str(&format!(
+                "{:.3},{:.3},{},{}\n",
+                snippet.start,
+                snippet.duration,
+                Self::csv_field(snippet.primary.as_deref().unwrap_or("")),
+                Self::csv_field(snippet.secondary.as_deref().unwrap_or(""))
+            ));
+        }
+
+        output
+    }
+
+    /// Returns the index of the primary snippet overlapping `snippet` the most.
+    fn best_overlap(
+        primary_snippets: &[FetchedTranscriptSnippet],
+        snippet: &FetchedTranscriptSnippet,
+    ) -> Option<usize> {
+        let start = snippet.start;
+        let end = snippet.start + snippet.duration;
+        let max_duration = primary_snippets
+            .iter()
+            .map(|p| p.duration)
+            .fold(0.0, f64::max);
+
+        // Only primary snippets starting before `end` can overlap; walk back until
+        // no earlier snippet can still be on screen at `start`
+        let upper = primary_snippets.partition_point(|p| p.start < end);
+        let mut best: Option<(usize, f64)> = None;
+
+        for index in (0..upper).rev() {
+            let candidate = &primary_snippets[index];
+            if candidate.start + max_duration <= start {
+                break;
+            }
+
+            let overlap =
+                end.min(candidate.start + candidate.duration) - start.max(candidate.start);
+            if overlap > 0.0 && best.is_none_or(|(_, best_overlap)| overlap >= best_overlap) {
+                best = Some((index, overlap));
+            }
+        }
+
+        // Zero-length snippets are matched to the snippet on screen at that moment
+        if best.is_none() && snippet.duration == 0.0 && upper > 0 {
+            let candidate = &primary_snippets[upper - 1];
+            if candidate.start <= start && start < candidate.start + candidate.duration {
+                return Some(upper - 1);
+            }
+        }
+
+        best.map(|(index, _)| index)
+    }
+
+    /// Joins the text of several snippets with spaces.
+    fn join_texts(snippets: &[&FetchedTranscriptSnippet]) -> Option<String> {
+        if snippets.is_empty() {
+            return None;
+        }
+
+        Some(
+            snippets
+                .iter()
+                .map(|s| s.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    }
+
+    /// Builds the text of a dual-line subtitle cue.
+    fn cue_text(snippet: &BilingualSnippet) -> String {
+        [snippet.primary.as_deref(), snippet.secondary.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter(|text| !text.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Formats a time in seconds as `HH:MM:SS<separator>mmm`.
+    fn format_timestamp(seconds: f64, separator: char) -> String {
+        let total_ms = (seconds.max(0.0) * 1000.0).round() as u64;
+        let hours = total_ms / 3_600_000;
+        let minutes = (total_ms % 3_600_000) / 60_000;
+        let secs = (total_ms % 60_000) / 1000;
+        let millis = total_ms % 1000;
+
+        format!(
+            "{:02}:{:02}:{:02}{}{:03}",
+            hours, minutes, secs, separator, millis
+        )
+    }
+
+    /// Quotes a CSV field when needed.
+    fn csv_field(value: &str) -> String {
+        if value.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_transcript(language_code: &str, texts: &[(&str, f64, f64)]) -> FetchedTranscript {
+        FetchedTranscript {
+            snippets: texts
+                .iter()
+                .map(|(text, start, duration)| FetchedTranscriptSnippet {
+                    text: text.to_string(),
+                    start: *start,
+                    duration: *duration,
+                })
+                .collect(),
+            video_id: "test123".to_string(),
+            language: language_code.to_string(),
+            language_code: language_code.to_string(),
+            is_generated: false,
+        }
+    }
+
+    #[test]
+    fn test_align_identical_timings() {
+        let english = create_transcript("en", &[("Hello", 0.0, 2.0), ("How are you?", 2.0, 3.0)]);
+        let spanish = create_transcript("es", &[("Hola", 0.0, 2.0), ("¿Cómo estás?", 2.0, 3.0)]);
+
+        let bilingual = BilingualTranscript::align(&english, &spanish).unwrap();
+
+        assert_eq!(bilingual.primary_language_code, "en");
+        assert_eq!(bilingual.secondary_language_code, "es");
+        assert_eq!(bilingual.snippets.len(), 2);
+        assert_eq!(bilingual.snippets[0].primary.as_deref(), Some("Hello"));
+        assert_eq!(bilingual.snippets[0].secondary.as_deref(), Some("Hola"));
+        assert_eq!(
+            bilingual.snippets[1].secondary.as_deref(),
+            Some("¿Cómo estás?")
+        );
+        assert_eq!(bilingual.snippets[1].start, 2.0);
+        assert_eq!(bilingual.snippets[1].duration, 3.0);
+    }
+
+    #[test]
+    fn test_align_different_segmentation() {
+        let english = create_transcript(
+            "en",
+            &[
+                ("one two", 0.0, 4.0),
+                ("three", 4.0, 2.0),
+                ("four", 10.0, 1.0),
+            ],
+        );
+        let french = create_transcript(
+            "fr",
+            &[
+                ("un", 0.0, 2.0),
+                ("deux", 2.0, 2.5),
+                ("trois", 4.5, 1.5),
+                ("cinq", 20.0, 1.0),
+            ],
+        );
+
+        let bilingual = BilingualTranscript::align(&english, &french).unwrap();
+
+        assert_eq!(bilingual.snippets.len(), 4);
+        assert_eq!(bilingual.snippets[0].secondary.as_deref(), Some("un deux"));
+        assert_eq!(bilingual.snippets[0].duration, 4.5);
+        assert_eq!(bilingual.snippets[1].secondary.as_deref(), Some("trois"));
+        assert_eq!(bilingual.snippets[2].primary.as_deref(), Some("four"));
+        assert_eq!(bilingual.snippets[2].secondary, None);
+        assert_eq!(bilingual.snippets[3].primary, None);
+        assert_eq!(bilingual.snippets[3].secondary.as_deref(), Some("cinq"));
+    }
+
+    #[test]
+    fn test_align_different_videos() {
+        let english = create_transcript("en", &[("Hello", 0.0, 2.0)]);
+        let mut other = create_transcript("es", &[("Hola", 0.0, 2.0)]);
+        other.video_id = "other".to_string();
+
+        assert!(BilingualTranscript::align(&english, &other).is_err());
+    }
+
+    #[test]
+    fn test_subtitle_exports() {
+        let english = create_transcript("en", &[("Hello", 1.5, 2.0), ("Bye", 3661.0, 1.25)]);
+        let spanish = create_transcript("es", &[("Hola", 1.5, 2.0)]);
+        let bilingual = BilingualTranscript::align(&english, &spanish).unwrap();
+
+        assert_eq!(
+            bilingual.to_srt(),
+            "1\n00:00:01,500 --> 00:00:03,500\nHello\nHola\n\n\
+             2\n01:01:01,000 --> 01:01:02,250\nBye\n\n"
+        );
+        assert_eq!(
+            bilingual.to_webvtt(),
+            "WEBVTT\n\n\
+             00:00:01.500 --> 00:00:03.500\nHello\nHola\n\n\
+             01:01:01.000 --> 01:01:02.250\nBye\n\n"
+        );
+    }
+
+    #[test]
+    fn test_table_exports() {
+        let english = create_transcript("en", &[("Hello, \"friend\"", 0.0, 2.0)]);
+        let spanish = create_transcript("es", &[("Hola", 0.0, 2.0)]);
+        let bilingual = BilingualTranscript::align(&english, &spanish).unwrap();
+
+        assert_eq!(
+            bilingual.to_csv(),
+            "start,duration,en,es\n0.000,2.000,\"Hello, \"\"friend\"\"\",Hola\n"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&bilingual.to_json().unwrap()).unwrap();
+        assert_eq!(json["video_id"], "test123");
+        assert_eq!(json["snippets"][0]["primary"], "Hello, \"friend\"");
+        assert_eq!(json["snippets"][0]["secondary"], "Hola");
+    }
+}
@@ -0,0 +1,448 @@
+use std::collections::HashSet;
+
+use reqwest::Client;
+use serde_json::Value;
+use url::Url;
+
+use crate::errors::{CouldNotRetrieveTranscript, CouldNotRetrieveTranscriptReason};
+use crate::innertube_client::InnerTubeClient;
+use crate::json_utils::{
+    extract_continuation_token, extract_error_alert, find_all, find_first, parse_length_text,
+    text_field,
+};
+use crate::models::{ChannelTab, ChannelUploads, ChannelVideo};
+
+/// How a channel was referred to by the caller.
+#[derive(Debug, PartialEq)]
+enum ChannelReference {
+    /// A channel ID, which needs no resolution
+    Id(String),
+    /// A handle, custom or legacy user URL, which must be resolved by YouTube
+    Url(String),
+}
+
+/// # ChannelFetcher
+///
+/// Resolves YouTube channels and enumerates their videos through the InnerTube API.
+///
+/// Channels can be referred to by their ID ("UC..." as found in
+/// [`VideoDetails::channel_id`](crate::models::VideoDetails::channel_id)), by their
+/// handle ("@name"), or by any channel URL, including the legacy `/c/` and `/user/`
+/// forms and [`MicroformatData::owner_profile_url`](crate::models::MicroformatData::owner_profile_url).
+/// Everything but IDs is resolved with the InnerTube `navigation/resolve_url` endpoint.
+///
+/// Videos are listed tab by tab ([`ChannelTab`]) from the `browse` endpoint, newest
+/// first, following continuation tokens until the tab is exhausted or the requested
+/// number of videos is reached.
+///
+/// ## Usage Example
+///
+/// ```rust,no_run
+/// # use reqwest::Client;
+/// # use yt_transcript_rs::channel_fetcher::ChannelFetcher;
+/// # use yt_transcript_rs::models::ChannelTab;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let fetcher = ChannelFetcher::new(Client::new());
+///
+/// let uploads = fetcher
+///     .fetch_channel_videos("@RickAstleyYT", ChannelTab::Videos, Some(50))
+///     .await?;
+/// for video in &uploads.videos {
+///     println!("{} {} ({:?})", video.video_id, video.title, video.published_time_text);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct ChannelFetcher {
+    innertube: InnerTubeClient,
+}
+
+impl ChannelFetcher {
+    /// Creates a new channel fetcher.
+    ///
+    /// # Parameters
+    ///
+    /// * `client` - HTTP client used for the InnerTube requests
+    pub fn new(client: Client) -> Self {
+        Self {
+            innertube: InnerTubeClient::new(client),
+        }
+    }
+
+    /// Resolves a channel ID, handle or URL to a channel ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `channel` - A channel ID ("UC..."), a handle ("@name") or a channel URL
+    ///
+    /// # Returns
+    ///
+    /// * `Result<String, CouldNotRetrieveTranscript>` - The channel ID, or an error
+    ///
+    /// # Errors
+    ///
+    /// - `ChannelUnavailable` if the input is not a channel reference, or YouTube does
+    ///   not resolve it to a channel
+    /// - `YouTubeRequestFailed` if the resolution request fails, which is also how
+    ///   YouTube answers for handles that do not exist
+    pub async fn resolve_channel_id(
+        &self,
+        channel: &str,
+    ) -> Result<String, CouldNotRetrieveTranscript> {
+        match Self::parse_reference(channel) {
+            Some(ChannelReference::Id(id)) => Ok(id),
+            Some(ChannelReference::Url(url)) => {
+                let response = self.innertube.resolve_url(&url).await?;
+                find_first(&response, "browseEndpoint")
+                    .and_then(|endpoint| endpoint.get("browseId"))
+                    .and_then(|id| id.as_str())
+                    .filter(|id| Self::is_channel_id(id))
+                    .map(|id| id.to_string())
+                    .ok_or_else(|| {
+                        Self::unavailable(channel, format!("{} is not a channel URL", url))
+                    })
+            }
+            None => Err(Self::unavailable(
+                channel,
+                "Not a channel ID, handle or URL".to_string(),
+            )),
+        }
+    }
+
+    /// Fetches the videos listed on a tab of a channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `channel` - A channel ID, handle or URL (see [`resolve_channel_id`](ChannelFetcher::resolve_channel_id))
+    /// * `tab` - The tab to list
+    /// * `limit` - Maximum number of videos to return, or `None` for all of them
+    ///
+    /// # Returns
+    ///
+    /// * `Result<ChannelUploads, CouldNotRetrieveTranscript>` - The videos of the tab, newest first,
+    ///   or an error. A channel without the requested tab has no videos.
+    ///
+    /// # Errors
+    ///
+    /// - `ChannelUnavailable` if the channel cannot be resolved or does not exist
+    /// - `YouTubeRequestFailed` or `YouTubeDataUnparsable` if an InnerTube request fails
+    pub async fn fetch_channel_videos(
+        &self,
+        channel: &str,
+        tab: ChannelTab,
+        limit: Option<usize>,
+    ) -> Result<ChannelUploads, CouldNotRetrieveTranscript> {
+        let channel_id = self.resolve_channel_id(channel).await?;
+        let response = self
+            .innertube
+            .browse(&channel_id, Some(Self::tab_params(tab)))
+            .await?;
+
+        if let Some(message) = extract_error_alert(&response) {
+            return Err(Self::unavailable(&channel_id, message));
+        }
+
+        let mut uploads = Self::parse_channel_page(&response, &channel_id, tab);
+        let limit = limit.unwrap_or(usize::MAX);
+
+        let mut token = if uploads.videos.is_empty() {
+            None
+        } else {
+            extract_continuation_token(&response)
+        };
+        let mut seen_tokens = HashSet::new();
+
+        while let Some(current) = token {
+            if uploads.videos.len() >= limit || !seen_tokens.insert(current.clone()) {
+                break;
+            }
+
+            let page = self
+                .innertube
+                .continuation("browse", &current, &channel_id)
+                .await?;
+            let videos = Self::parse_videos(&page);
+            if videos.is_empty() {
+                break;
+            }
+
+            uploads.videos.extend(videos);
+            token = extract_continuation_token(&page);
+        }
+
+        uploads.videos.truncate(limit);
+        Ok(uploads)
+    }
+
+    /// Parses the first page of a channel tab.
+    ///
+    /// When a channel has no such tab, YouTube answers with its home tab, whose
+    /// featured videos must not be mistaken for the tab's contents.
+    fn parse_channel_page(response: &Value, channel_id: &str, tab: ChannelTab) -> ChannelUploads {
+        let metadata = find_first(response, "channelMetadataRenderer");
+
+        let handle = metadata
+            .and_then(|m| m.get("vanityChannelUrl"))
+            .and_then(|url| url.as_str())
+            .and_then(|url| url.rsplit('/').next())
+            .filter(|segment| segment.starts_with('@'))
+            .map(|segment| segment.to_string());
+
+        let selected_tab_url = find_all(response, "tabRenderer")
+            .into_iter()
+            .find(|tab| tab.get("selected").and_then(|s| s.as_bool()) == Some(true))
+            .and_then(|tab| find_first(tab, "webCommandMetadata"))
+            .and_then(|metadata| metadata.get("url"))
+            .and_then(|url| url.as_str());
+
+        let videos = match selected_tab_url {
+            Some(url) if !url.ends_with(Self::tab_path(tab)) => Vec::new(),
+            _ => Self::parse_videos(response),
+        };
+
+        ChannelUploads {
+            channel_id: channel_id.to_string(),
+            title: metadata.and_then(|m| text_field(m, "title")),
+            handle,
+            tab,
+            videos,
+        }
+    }
+
+    /// Parses the videos of a tab page or continuation page.
+    ///
+    /// Supports the `videoRenderer` items of the videos and live tabs, and both the
+    /// `reelItemRenderer` and `shortsLockupViewModel` items of the shorts tab.
+    fn parse_videos(page: &Value) -> Vec<ChannelVideo> {
+        find_all(page, "richItemRenderer")
+            .into_iter()
+            .filter_map(|item| item.get("content"))
+            .filter_map(|content| {
+                if let Some(renderer) = content.get("videoRenderer") {
+                    Some(ChannelVideo {
+                        video_id: renderer.get("videoId")?.as_str()?.to_string(),
+                        title: text_field(renderer, "title").unwrap_or_default(),
+                        length_seconds: text_field(renderer, "lengthText")
+                            .and_then(|t| parse_length_text(&t)),
+                        published_time_text: text_field(renderer, "publishedTimeText"),
+                        view_count_text: text_field(renderer, "viewCountText"),
+                    })
+                } else if let Some(renderer) = content.get("reelItemRenderer") {
+                    Some(ChannelVideo {
+                        video_id: renderer.get("videoId")?.as_str()?.to_string(),
+                        title: text_field(renderer, "headline").unwrap_or_default(),
+                        length_seconds: None,
+                        published_time_text: None,
+                        view_count_text: text_field(renderer, "viewCountText"),
+                    })
+                } else if let Some(model) = content.get("shortsLockupViewModel") {
+                    let overlay = model.get("overlayMetadata");
+                    Some(ChannelVideo {
+                        video_id: find_first(model, "reelWatchEndpoint")?
+                            .get("videoId")?
+                            .as_str()?
+                            .to_string(),
+                        title: overlay
+                            .and_then(|o| text_field(o, "primaryText"))
+                            .unwrap_or_default(),
+                        length_seconds: None,
+                        published_time_text: None,
+                        view_count_text: overlay.and_then(|o| text_field(o, "secondaryText")),
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Works out what a channel reference designates, without any request.
+    fn parse_reference(channel: &str) -> Option<ChannelReference> {
+        let input = channel.trim();
+
+        if Self::is_channel_id(input) {
+            return Some(ChannelReference::Id(input.to_string()));
+        }
+        if input.starts_with('@') && !input.contains('/') {
+            return Some(ChannelReference::Url(format!(
+                "https://www.youtube.com/{}",
+                input
+            )));
+        }
+        if !input.contains('/') {
+            return None;
+        }
+
+        let url = if input.contains("://") {
+            Url::parse(input).ok()?
+        } else {
+            Url::parse(&format!("https://{}", input)).ok()?
+        };
+        let segments: Vec<&str> = url.path_segments()?.filter(|s| !s.is_empty()).collect();
+
+        match segments.as_slice() {
+            ["channel", id, ..] if Self::is_channel_id(id) => {
+                Some(ChannelReference::Id(id.to_string()))
+            }
+            [handle, ..] if handle.starts_with('@') => Some(ChannelReference::Url(format!(
+                "https://www.youtube.com/{}",
+                handle
+            ))),
+            [kind @ ("c" | "user"), name, ..] => Some(ChannelReference::Url(format!(
+                "https://www.youtube.com/{}/{}",
+                kind, name
+            ))),
+            _ => None,
+        }
+    }
+
+    fn is_channel_id(id: &str) -> bool {
+        id.len() == 24
+            && id.starts_with("UC")
+            && id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
+    /// Returns the encoded `browse` parameters selecting a tab.
+    fn tab_params(tab: ChannelTab) -> &'static str {
+        match tab {
+            ChannelTab::Videos => "EgZ2aWRlb3PyBgQKAjoA",
+            ChannelTab::Shorts => "EgZzaG9ydHPyBgUKA5oBAA==",
+            ChannelTab::Live => "EgdzdHJlYW1z8gYECgJ6AA==",
+        }
+    }
+
+    /// Returns the last segment of the tab's URL on the channel page.
+    fn tab_path(tab: ChannelTab) -> &'static str {
+        match tab {
+            ChannelTab::Videos => "/videos",
+            ChannelTab::Shorts => "/shorts",
+            ChannelTab::Live => "/streams",
+        }
+    }
+
+    fn unavailable(channel: &str, message: String) -> CouldNotRetrieveTranscript {
+        CouldNotRetrieveTranscript {
+            video_id: channel.to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::ChannelUnavailable(
+                message,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const CHANNEL_ID: &str = "UCuAXFkgsw1L7xaCfnd5JJOw";
+
+    fn tab(url: &str, selected: bool, items: Vec<Value>) -> Value {
+        json!({"tabRenderer": {
+            "endpoint": {"commandMetadata": {"webCommandMetadata": {"url": url}}},
+            "selected": selected,
+            "content": {"richGridRenderer": {"contents": items}}
+        }})
+    }
+
+    #[test]
+    fn test_parse_reference() {
+        let id = |s: &str| Some(ChannelReference::Id(s.to_string()));
+        let url = |s: &str| Some(ChannelReference::Url(s.to_string()));
+
+        assert_eq!(ChannelFetcher::parse_reference(CHANNEL_ID), id(CHANNEL_ID));
+        assert_eq!(
+            ChannelFetcher::parse_reference(&format!(
+                "http://www.youtube.com/channel/{}/videos",
+                CHANNEL_ID
+            )),
+            id(CHANNEL_ID)
+        );
+        assert_eq!(
+            ChannelFetcher::parse_reference("@RickAstleyYT"),
+            url("https://www.youtube.com/@RickAstleyYT")
+        );
+        assert_eq!(
+            ChannelFetcher::parse_reference("https://m.youtube.com/@RickAstleyYT/shorts"),
+            url("https://www.youtube.com/@RickAstleyYT")
+        );
+        assert_eq!(
+            ChannelFetcher::parse_reference("youtube.com/c/SomeName"),
+            url("https://www.youtube.com/c/SomeName")
+        );
+        assert_eq!(
+            ChannelFetcher::parse_reference("https://www.youtube.com/user/RickAstleyVEVO"),
+            url("https://www.youtube.com/user/RickAstleyVEVO")
+        );
+        assert_eq!(
+            ChannelFetcher::parse_reference("https://www.youtube.com/watch?v=dQw4w9WgXcQ"),
+            None
+        );
+        assert_eq!(ChannelFetcher::parse_reference("RickAstley"), None);
+    }
+
+    #[test]
+    fn test_parse_videos_tab() {
+        let response = json!({
+            "metadata": {"channelMetadataRenderer": {
+                "title": "Rick Astley",
+                "vanityChannelUrl": "http://www.youtube.com/@RickAstleyYT"
+            }},
+            "contents": {"twoColumnBrowseResultsRenderer": {"tabs": [
+                tab("/@RickAstleyYT/featured", false, vec![]),
+                tab("/@RickAstleyYT/videos", true, vec![
+                    json!({"richItemRenderer": {"content": {"videoRenderer": {
+                        "videoId": "dQw4w9WgXcQ",
+                        "title": {"runs": [{"text": "Never Gonna Give You Up"}]},
+                        "lengthText": {"simpleText": "3:33"},
+                        "publishedTimeText": {"simpleText": "15 years ago"},
+                        "viewCountText": {"simpleText": "1,500,000,000 views"}
+                    }}}}),
+                    json!({"continuationItemRenderer": {"continuationEndpoint": {
+                        "continuationCommand": {"token": "NEXT"}
+                    }}})
+                ])
+            ]}}
+        });
+
+        let uploads = ChannelFetcher::parse_channel_page(&response, CHANNEL_ID, ChannelTab::Videos);
+        assert_eq!(uploads.title.as_deref(), Some("Rick Astley"));
+        assert_eq!(uploads.handle.as_deref(), Some("@RickAstleyYT"));
+        assert_eq!(uploads.video_ids(), vec!["dQw4w9WgXcQ"]);
+        assert_eq!(uploads.videos[0].length_seconds, Some(213));
+        assert_eq!(
+            uploads.videos[0].published_time_text.as_deref(),
+            Some("15 years ago")
+        );
+
+        // The channel has no live tab: YouTube falls back to another tab
+        let uploads = ChannelFetcher::parse_channel_page(&response, CHANNEL_ID, ChannelTab::Live);
+        assert!(uploads.videos.is_empty());
+    }
+
+    #[test]
+    fn test_parse_shorts() {
+        let page = json!({"continuationItems": [
+            {"richItemRenderer": {"content": {"shortsLockupViewModel": {
+                "onTap": {"innertubeCommand": {"reelWatchEndpoint": {"videoId": "short000001"}}},
+                "overlayMetadata": {
+                    "primaryText": {"content": "A short"},
+                    "secondaryText": {"content": "12K views"}
+                }
+            }}}},
+            {"richItemRenderer": {"content": {"reelItemRenderer": {
+                "videoId": "short000002",
+                "headline": {"simpleText": "Another short"}
+            }}}}
+        ]});
+
+        let videos = ChannelFetcher::parse_videos(&page);
+        assert_eq!(videos.len(), 2);
+        assert_eq!(videos[0].video_id, "short000001");
+        assert_eq!(videos[0].title, "A short");
+        assert_eq!(videos[0].view_count_text.as_deref(), Some("12K views"));
+        assert_eq!(videos[1].title, "Another short");
+    }
+}
@@ -0,0 +1,280 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::json_utils::{find_all, find_first, text_field};
+use crate::models::Chapter;
+
+/// Matches a description line starting with a timestamp: "0:00 Intro", "(1:02:03) - Outro"
+static LEADING_TIMESTAMP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*(?:[-*•]\s*)?[\[(]?((?:\d{1,2}:)?\d{1,2}:\d{2})[\])]?\s*(?:[-–—:|]\s*)?(.+)$")
+        .unwrap()
+});
+
+/// Matches a description line ending with a timestamp: "Intro - 0:00"
+static TRAILING_TIMESTAMP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^\s*(?:[-*•]\s*)?(.+?)\s*(?:[-–—:|]\s*)?[\[(]?((?:\d{1,2}:)?\d{1,2}:\d{2})[\])]?\s*$",
+    )
+    .unwrap()
+});
+
+/// # ChaptersExtractor
+///
+/// Extracts the chapters of a video.
+///
+/// Chapters come from two sources:
+/// - The chapter markers of the player bar in `ytInitialData`, which include both
+///   creator-defined and automatically generated chapters
+/// - Timestamp lines in the video description ("0:00 Intro"), which is how creators
+///   define chapters in the first place
+///
+/// Structured markers are preferred. Description timestamps only form chapters when
+/// they follow YouTube's own rules: the first one is at 0:00, there are at least
+/// three of them and they are in ascending order.
+pub struct ChaptersExtractor;
+
+impl ChaptersExtractor {
+    /// Minimum number of timestamps for a description to define chapters
+    const MIN_DESCRIPTION_CHAPTERS: usize = 3;
+
+    /// Extracts chapters, preferring structured markers over the description.
+    ///
+    /// # Parameters
+    ///
+    /// * `initial_data` - The parsed `ytInitialData` JSON object, if available
+    /// * `description` - The video description
+    /// * `video_length` - The length of the video in seconds, used as the end of the last chapter
+    ///
+    /// # Returns
+    ///
+    /// The chapters in chronological order, or an empty list if the video has none.
+    pub fn extract_chapters(
+        initial_data: Option<&Value>,
+        description: &str,
+        video_length: f64,
+    ) -> Vec<Chapter> {
+        let chapters = initial_data
+            .map(|data| Self::extract_from_initial_data(data, video_length))
+            .unwrap_or_default();
+
+        if chapters.is_empty() {
+            Self::extract_from_description(description, video_length)
+        } else {
+            chapters
+        }
+    }
+
+    /// Extracts chapters from the player bar markers of `ytInitialData`.
+    ///
+    /// When a video has both creator-defined (`DESCRIPTION_CHAPTERS`) and automatic
+    /// (`AUTO_CHAPTERS`) markers, the creator-defined ones are used.
+    ///
+    /// # Parameters
+    ///
+    /// * `initial_data` - The parsed `ytInitialData` JSON object
+    /// * `video_length` - The length of the video in seconds, used as the end of the last chapter
+    pub fn extract_from_initial_data(initial_data: &Value, video_length: f64) -> Vec<Chapter> {
+        let markers = find_first(initial_data, "markersMap")
+            .and_then(|map| map.as_array())
+            .and_then(|entries| {
+                let chapters_of = |key: &str| {
+                    entries
+                        .iter()
+                        .find(|entry| entry.get("key").and_then(|k| k.as_str()) == Some(key))
+                        .and_then(|entry| entry.get("value"))
+                };
+                chapters_of("DESCRIPTION_CHAPTERS").or_else(|| chapters_of("AUTO_CHAPTERS"))
+            })
+            .unwrap_or(initial_data);
+
+        let starts: Vec<(f64, String)> = find_all(markers, "chapterRenderer")
+            .into_iter()
+            .filter_map(|renderer| {
+                let millis = renderer.get("timeRangeStartMillis")?.as_f64()?;
+                let title = text_field(renderer, "title")?;
+                Some((millis / 1000.0, title))
+            })
+            .collect();
+
+        Self::build_chapters(starts, video_length)
+    }
+
+    /// Extracts chapters from timestamp lines in a video description.
+    ///
+    /// Timestamps may appear at the beginning (`0:00 Intro`, `[01:30] - Setup`) or at
+    /// the end (`Setup - 1:30`) of a line. Lines without a timestamp are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `description` - The video description
+    /// * `video_length` - The length of the video in seconds, used as the end of the last chapter
+    ///
+    /// # Returns
+    ///
+    /// The chapters, or an empty list if the timestamps do not define valid chapters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use yt_transcript_rs::chapters_extractor::ChaptersExtractor;
+    /// let description = "My video\n\n0:00 Intro\n1:30 Setup\n5:00 Results";
+    ///
+    /// let chapters = ChaptersExtractor::extract_from_description(description, 600.0);
+    ///
+    /// assert_eq!(chapters.len(), 3);
+    /// assert_eq!(chapters[1].title, "Setup");
+    /// assert_eq!(chapters[1].start, 90.0);
+    /// assert_eq!(chapters[2].end, 600.0);
+    /// ```
+    pub fn extract_from_description(description: &str, video_length: f64) -> Vec<Chapter> {
+        let starts: Vec<(f64, String)> = description
+            .lines()
+            .filter_map(|line| {
+                let (timestamp, title) = if let Some(caps) = LEADING_TIMESTAMP.captures(line) {
+                    (caps.get(1)?.as_str(), caps.get(2)?.as_str())
+                } else {
+                    let caps = TRAILING_TIMESTAMP.captures(line)?;
+                    (caps.get(2)?.as_str(), caps.get(1)?.as_str())
+                };
+
+                Some((Self::parse_timestamp(timestamp)?, title.trim().to_string()))
+            })
+            .collect();
+
+        let is_valid = starts.len() >= Self::MIN_DESCRIPTION_CHAPTERS
+            && starts[0].0 == 0.0
+            && starts.windows(2).all(|pair| pair[0].0 < pair[1].0);
+
+        if is_valid {
+            Self::build_chapters(starts, video_length)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Builds chapters from their start times, ending each one where the next one starts.
+    fn build_chapters(mut starts: Vec<(f64, String)>, video_length: f64) -> Vec<Chapter> {
+        starts.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let ends: Vec<f64> = starts
+            .iter()
+            .skip(1)
+            .map(|(start, _)| *start)
+            .chain(std::iter::once(video_length))
+            .collect();
+
+        starts
+            .into_iter()
+            .zip(ends)
+            .map(|((start, title), end)| Chapter {
+                title,
+                start,
+                end: end.max(start),
+            })
+            .collect()
+    }
+
+    /// Parses a "m:ss", "mm:ss" or "h:mm:ss" timestamp into seconds.
+    fn parse_timestamp(timestamp: &str) -> Option<f64> {
+        timestamp
+            .split(':')
+            .try_fold(0u64, |total, part| {
+                Some(total * 60 + part.parse::<u64>().ok()?)
+            })
+            .map(|seconds| seconds as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_extract_from_initial_data_prefers_description_chapters() {
+        let initial_data = json!({
+            "playerOverlays": {"playerOverlayRenderer": {"decoratedPlayerBarRenderer": {
+                "decoratedPlayerBarRenderer": {"playerBar": {"multiMarkersPlayerBarRenderer": {
+                    "markersMap": [
+                        {"key": "AUTO_CHAPTERS", "value": {"chapters": [
+                            {"chapterRenderer": {"title": {"simpleText": "Auto"}, "timeRangeStartMillis": 0}}
+                        ]}},
+                        {"key": "DESCRIPTION_CHAPTERS", "value": {"chapters": [
+                            {"chapterRenderer": {"title": {"simpleText": "Intro"}, "timeRangeStartMillis": 0}},
+                            {"chapterRenderer": {"title": {"simpleText": "Main part"}, "timeRangeStartMillis": 65500}}
+                        ]}}
+                    ]
+                }}}
+            }}}
+        });
+
+        let chapters = ChaptersExtractor::extract_from_initial_data(&initial_data, 120.0);
+
+        assert_eq!(
+            chapters,
+            vec![
+                Chapter {
+                    title: "Intro".to_string(),
+                    start: 0.0,
+                    end: 65.5
+                },
+                Chapter {
+                    title: "Main part".to_string(),
+                    start: 65.5,
+                    end: 120.0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_from_description_formats() {
+        let description = "Links below!\n\
+            (0:00) Intro\n\
+            - 1:05 - Setting up\n\
+            Building the thing: 12:30\n\
+            1:02:03 | Wrap-up\n\
+            Thanks for watching";
+
+        let chapters = ChaptersExtractor::extract_from_description(description, 4000.0);
+
+        let titles: Vec<&str> = chapters.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec!["Intro", "Setting up", "Building the thing", "Wrap-up"]
+        );
+        assert_eq!(chapters[1].start, 65.0);
+        assert_eq!(chapters[2].start, 750.0);
+        assert_eq!(chapters[3].start, 3723.0);
+        assert_eq!(chapters[3].end, 4000.0);
+    }
+
+    #[test]
+    fn test_invalid_description_chapters() {
+        // Does not start at 0:00
+        let description = "0:10 One\n1:00 Two\n2:00 Three";
+        assert!(ChaptersExtractor::extract_from_description(description, 300.0).is_empty());
+
+        // Too few timestamps
+        let description = "0:00 One\n1:00 Two";
+        assert!(ChaptersExtractor::extract_from_description(description, 300.0).is_empty());
+
+        // Not in ascending order
+        let description = "0:00 One\n2:00 Two\n1:00 Three";
+        assert!(ChaptersExtractor::extract_from_description(description, 300.0).is_empty());
+    }
+
+    #[test]
+    fn test_extract_chapters_falls_back_to_description() {
+        let description = "0:00 One\n1:00 Two\n2:00 Three";
+
+        let chapters =
+            ChaptersExtractor::extract_chapters(Some(&json!({"contents": {}})), description, 300.0);
+        assert_eq!(chapters.len(), 3);
+
+        let chapters = ChaptersExtractor::extract_chapters(None, "No chapters here", 300.0);
+        assert!(chapters.is_empty());
+    }
+}
@@ -0,0 +1,532 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use reqwest::Client;
+use serde_json::Value;
+
+use crate::errors::CouldNotRetrieveTranscript;
+use crate::initial_data_extractor::InitialDataExtractor;
+use crate::innertube_client::InnerTubeClient;
+use crate::json_utils::{extract_text, find_all, find_first, parse_abbreviated_count, text_field};
+use crate::models::{Comment, CommentSortOrder};
+
+/// # CommentFetcher
+///
+/// Retrieves the comments of a video through the InnerTube `next` endpoint.
+///
+/// The watch page data (`ytInitialData`, or the equivalent `next` response) only
+/// holds a continuation token for the comment section. Requesting that token from
+/// the `next` endpoint returns the first page of comment threads along with the sort
+/// menu, and each page ends with the token of the following page. Replies are paged
+/// the same way, from a token attached to each thread.
+///
+/// Comments are returned through a [`CommentStream`], which only keeps the current
+/// page in memory.
+///
+/// ## Usage Example
+///
+/// ```rust,no_run
+/// # use reqwest::Client;
+/// # use yt_transcript_rs::comment_fetcher::CommentFetcher;
+/// # use yt_transcript_rs::models::CommentSortOrder;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let fetcher = CommentFetcher::new(Client::new());
+/// let mut stream = fetcher.comments("dQw4w9WgXcQ", CommentSortOrder::Newest, false);
+///
+/// while let Some(comment) = stream.next().await {
+///     let comment = comment?;
+///     println!("{} ({:?} likes): {}", comment.author, comment.like_count, comment.text);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct CommentFetcher {
+    client: Client,
+}
+
+impl CommentFetcher {
+    /// Creates a new comment fetcher.
+    ///
+    /// # Parameters
+    ///
+    /// * `client` - HTTP client used for the InnerTube requests
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Returns a stream over the top-level comments of a video.
+    ///
+    /// No request is made until the first comment is requested.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID
+    /// * `sort` - The order of the comments
+    /// * `expand_replies` - Whether to fetch the replies of each comment into [`Comment::replies`]
+    pub fn comments(
+        &self,
+        video_id: &str,
+        sort: CommentSortOrder,
+        expand_replies: bool,
+    ) -> CommentStream {
+        CommentStream {
+            innertube: InnerTubeClient::new(self.client.clone()),
+            video_id: video_id.to_string(),
+            sort,
+            expand_replies,
+            started: false,
+            next_token: None,
+            seen_tokens: HashSet::new(),
+            buffer: VecDeque::new(),
+        }
+    }
+
+    /// Fetches the top-level comments of a video into a vector.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID
+    /// * `sort` - The order of the comments
+    /// * `expand_replies` - Whether to fetch the replies of each comment
+    /// * `limit` - Maximum number of top-level comments to return, or `None` for all of them
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<Comment>, CouldNotRetrieveTranscript>` - The comments, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns `YouTubeRequestFailed` or `YouTubeDataUnparsable` if an InnerTube request fails.
+    pub async fn fetch_comments(
+        &self,
+        video_id: &str,
+        sort: CommentSortOrder,
+        expand_replies: bool,
+        limit: Option<usize>,
+    ) -> Result<Vec<Comment>, CouldNotRetrieveTranscript> {
+        let limit = limit.unwrap_or(usize::MAX);
+        let mut stream = self.comments(video_id, sort, expand_replies);
+        let mut comments = Vec::new();
+
+        while comments.len() < limit {
+            match stream.next().await {
+                Some(comment) => comments.push(comment?),
+                None => break,
+            }
+        }
+
+        Ok(comments)
+    }
+}
+
+/// # CommentStream
+///
+/// Streams the top-level comments of a video, one page at a time.
+///
+/// Created by [`CommentFetcher::comments`]. Each call to [`next`](CommentStream::next)
+/// returns the next comment, requesting a new page when the current one is consumed.
+/// A failed request does not lose any comment: calling `next` again retries it.
+pub struct CommentStream {
+    innertube: InnerTubeClient,
+    video_id: String,
+    sort: CommentSortOrder,
+    expand_replies: bool,
+
+    /// Whether the token of the first page has been obtained
+    started: bool,
+
+    /// Token of the next page of comment threads
+    next_token: Option<String>,
+
+    /// Tokens already requested, to stop if YouTube returns a page twice
+    seen_tokens: HashSet<String>,
+
+    /// Comments of the current page, with the token of their replies
+    buffer: VecDeque<(Comment, Option<String>)>,
+}
+
+impl CommentStream {
+    /// Returns the next top-level comment, or `None` once all comments were returned.
+    ///
+    /// Videos with comments turned off have no comments.
+    pub async fn next(&mut self) -> Option<Result<Comment, CouldNotRetrieveTranscript>> {
+        loop {
+            if let Some((mut comment, replies_token)) = self.buffer.pop_front() {
+                if let (true, Some(token)) = (self.expand_replies, &replies_token) {
+                    match self.fetch_replies(&comment.comment_id, token).await {
+                        Ok(replies) => comment.replies = replies,
+                        Err(e) => {
+                            self.buffer.push_front((comment, replies_token));
+                            return Some(Err(e));
+                        }
+                    }
+                }
+                return Some(Ok(comment));
+            }
+
+            match self.load_page().await {
+                Ok(true) => continue,
+                Ok(false) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    /// Loads the next page of comment threads into the buffer.
+    ///
+    /// Returns `false` once there are no more pages.
+    async fn load_page(&mut self) -> Result<bool, CouldNotRetrieveTranscript> {
+        if !self.started {
+            self.next_token = self.first_page_token().await?;
+            self.started = true;
+        }
+
+        let Some(token) = self.next_token.clone() else {
+            return Ok(false);
+        };
+        if self.seen_tokens.contains(&token) {
+            return Ok(false);
+        }
+
+        let page = self
+            .innertube
+            .continuation("next", &token, &self.video_id)
+            .await?;
+        let (comments, next_token) = parse_page(&page, None);
+
+        self.seen_tokens.insert(token);
+        self.next_token = next_token;
+        self.buffer.extend(comments);
+
+        Ok(true)
+    }
+
+    /// Returns the token of the first page of comments in the requested order.
+    async fn first_page_token(&self) -> Result<Option<String>, CouldNotRetrieveTranscript> {
+        let response = self.innertube.next(Some(&self.video_id), None).await?;
+        let Some(token) = InitialDataExtractor::extract_comments_continuation(&response) else {
+            return Ok(None);
+        };
+
+        if self.sort == CommentSortOrder::Top {
+            return Ok(Some(token));
+        }
+
+        // The other orders are only reachable from the sort menu of the first page
+        let page = self
+            .innertube
+            .continuation("next", &token, &self.video_id)
+            .await?;
+        Ok(Some(sort_token(&page, self.sort).unwrap_or(token)))
+    }
+
+    /// Fetches all replies of a comment thread.
+    async fn fetch_replies(
+        &self,
+        parent_id: &str,
+        token: &str,
+    ) -> Result<Vec<Comment>, CouldNotRetrieveTranscript> {
+        let mut replies = Vec::new();
+        let mut token = Some(token.to_string());
+        let mut seen_tokens = HashSet::new();
+
+        while let Some(current) = token {
+            if !seen_tokens.insert(current.clone()) {
+                break;
+            }
+
+            let page = self
+                .innertube
+                .continuation("next", &current, &self.video_id)
+                .await?;
+            let (comments, next_token) = parse_page(&page, Some(parent_id));
+
+            replies.extend(comments.into_iter().map(|(comment, _)| comment));
+            token = next_token;
+        }
+
+        Ok(replies)
+    }
+}
+
+/// Parses a page of comment threads or replies.
+///
+/// Returns the comments with the token of their replies, and the token of the next page.
+/// Only the items directly in the page's item lists are considered, so the reply tokens
+/// nested in threads are not mistaken for the page's own continuation.
+fn parse_page(
+    page: &Value,
+    parent_id: Option<&str>,
+) -> (Vec<(Comment, Option<String>)>, Option<String>) {
+    let entities = comment_entities(page);
+    let mut comments = Vec::new();
+    let mut next_token = None;
+
+    for item in find_all(page, "continuationItems")
+        .into_iter()
+        .filter_map(|items| items.as_array())
+        .flatten()
+    {
+        if let Some(thread) = item.get("commentThreadRenderer") {
+            let replies_token = thread
+                .get("replies")
+                .and_then(|replies| find_first(replies, "continuationCommand"))
+                .and_then(|command| command.get("token"))
+                .and_then(|token| token.as_str())
+                .map(str::to_string);
+
+            let comment = match thread.get("commentViewModel") {
+                Some(view_model) => parse_view_model(view_model, &entities, None),
+                None => thread
+                    .get("comment")
+                    .and_then(|c| c.get("commentRenderer"))
+                    .and_then(|renderer| parse_renderer(renderer, None)),
+            };
+            if let Some(comment) = comment {
+                comments.push((comment, replies_token));
+            }
+        } else if let Some(view_model) = item.get("commentViewModel") {
+            if let Some(comment) = parse_view_model(view_model, &entities, parent_id) {
+                comments.push((comment, None));
+            }
+        } else if let Some(renderer) = item.get("commentRenderer") {
+            if let Some(comment) = parse_renderer(renderer, parent_id) {
+                comments.push((comment, None));
+            }
+        } else if let Some(renderer) = item.get("continuationItemRenderer") {
+            next_token = find_first(renderer, "continuationCommand")
+                .and_then(|command| command.get("token"))
+                .and_then(|token| token.as_str())
+                .map(str::to_string);
+        }
+    }
+
+    (comments, next_token)
+}
+
+/// Indexes the comment entities of a page by key.
+///
+/// Newer layouts only reference comments from the threads (`commentKey`), and send
+/// their content separately as entity mutations.
+fn comment_entities(page: &Value) -> HashMap<&str, &Value> {
+    find_all(page, "mutations")
+        .into_iter()
+        .filter_map(|mutations| mutations.as_array())
+        .flatten()
+        .filter_map(|mutation| {
+            let key = mutation.get("entityKey")?.as_str()?;
+            let payload = mutation.get("payload")?.get("commentEntityPayload")?;
+            Some((key, payload))
+        })
+        .collect()
+}
+
+/// Parses a comment of the newer `commentViewModel` layout.
+fn parse_view_model(
+    view_model: &Value,
+    entities: &HashMap<&str, &Value>,
+    parent_id: Option<&str>,
+) -> Option<Comment> {
+    let view_model = view_model.get("commentViewModel").unwrap_or(view_model);
+    let key = view_model.get("commentKey")?.as_str()?;
+    let entity = entities.get(key)?;
+
+    let properties = entity.get("properties");
+    let author = entity.get("author");
+    let toolbar = entity.get("toolbar");
+    let toolbar_count = |key: &str| {
+        toolbar
+            .and_then(|t| t.get(key))
+            .and_then(|v| v.as_str())
+            .map(|count| {
+                if count.trim().is_empty() {
+                    Some(0)
+                } else {
+                    parse_abbreviated_count(count)
+                }
+            })
+    };
+
+    Some(Comment {
+        comment_id: properties
+            .and_then(|p| p.get("commentId"))
+            .or_else(|| view_model.get("commentId"))?
+            .as_str()?
+            .to_string(),
+        parent_id: parent_id.map(str::to_string),
+        author: author
+            .and_then(|a| a.get("displayName"))
+            .and_then(|n| n.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        author_channel_id: author
+            .and_then(|a| a.get("channelId"))
+            .and_then(|id| id.as_str())
+            .map(str::to_string),
+        is_author_uploader: author
+            .and_then(|a| a.get("isCreator"))
+            .and_then(|c| c.as_bool())
+            .unwrap_or(false),
+        text: properties
+            .and_then(|p| p.get("content"))
+            .and_then(extract_text)
+            .unwrap_or_default(),
+        like_count: toolbar_count("likeCountNotliked").flatten(),
+        published_time_text: properties
+            .and_then(|p| p.get("publishedTime"))
+            .and_then(|t| t.as_str())
+            .map(str::to_string),
+        reply_count: toolbar_count("replyCount").flatten().unwrap_or(0),
+        is_pinned: view_model.get("pinnedText").is_some(),
+        replies: Vec::new(),
+    })
+}
+
+/// Parses a comment of the legacy `commentRenderer` layout.
+fn parse_renderer(renderer: &Value, parent_id: Option<&str>) -> Option<Comment> {
+    Some(Comment {
+        comment_id: renderer.get("commentId")?.as_str()?.to_string(),
+        parent_id: parent_id.map(str::to_string),
+        author: text_field(renderer, "authorText").unwrap_or_default(),
+        author_channel_id: renderer
+            .get("authorEndpoint")
+            .and_then(|endpoint| find_first(endpoint, "browseId"))
+            .and_then(|id| id.as_str())
+            .map(str::to_string),
+        is_author_uploader: renderer
+            .get("authorIsChannelOwner")
+            .and_then(|o| o.as_bool())
+            .unwrap_or(false),
+        text: text_field(renderer, "contentText").unwrap_or_default(),
+        // Comments without likes have no vote count
+        like_count: match text_field(renderer, "voteCount") {
+            Some(count) => parse_abbreviated_count(&count),
+            None => Some(0),
+        },
+        published_time_text: text_field(renderer, "publishedTimeText"),
+        reply_count: renderer
+            .get("replyCount")
+            .and_then(|c| c.as_u64())
+            .unwrap_or(0),
+        is_pinned: renderer.get("pinnedCommentBadge").is_some(),
+        replies: Vec::new(),
+    })
+}
+
+/// Returns the token of the first page in `sort` order, from the sort menu of a page.
+fn sort_token(page: &Value, sort: CommentSortOrder) -> Option<String> {
+    let index = match sort {
+        CommentSortOrder::Top => 0,
+        CommentSortOrder::Newest => 1,
+    };
+
+    find_first(page, "sortFilterSubMenuRenderer")?
+        .get("subMenuItems")?
+        .get(index)
+        .and_then(|item| find_first(item, "continuationCommand"))
+        .and_then(|command| command.get("token"))
+        .and_then(|token| token.as_str())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sort_item(title: &str, token: &str) -> Value {
+        json!({
+            "title": title,
+            "serviceEndpoint": {"continuationCommand": {"token": token}}
+        })
+    }
+
+    #[test]
+    fn test_parse_view_model_page() {
+        let page = json!({
+            "onResponseReceivedEndpoints": [
+                {"reloadContinuationItemsCommand": {"continuationItems": [
+                    {"commentsHeaderRenderer": {"sortMenu": {"sortFilterSubMenuRenderer": {
+                        "subMenuItems": [sort_item("Top", "TOP"), sort_item("Newest", "NEWEST")]
+                    }}}}
+                ]}},
+                {"reloadContinuationItemsCommand": {"continuationItems": [
+                    {"commentThreadRenderer": {
+                        "commentViewModel": {"commentViewModel": {
+                            "commentKey": "key-1",
+                            "pinnedText": "Pinned by @channel"
+                        }},
+                        "replies": {"commentRepliesRenderer": {"contents": [
+                            {"continuationItemRenderer": {"continuationEndpoint": {
+                                "continuationCommand": {"token": "REPLIES1"}
+                            }}}
+                        ]}}
+                    }},
+                    {"continuationItemRenderer": {"continuationEndpoint": {
+                        "continuationCommand": {"token": "PAGE2"}
+                    }}}
+                ]}}
+            ],
+            "frameworkUpdates": {"entityBatchUpdate": {"mutations": [
+                {"entityKey": "key-1", "payload": {"commentEntityPayload": {
+                    "properties": {
+                        "commentId": "Ugz1",
+                        "content": {"content": "Great video!"},
+                        "publishedTime": "2 years ago"
+                    },
+                    "author": {"channelId": "UCauthor", "displayName": "@fan", "isCreator": false},
+                    "toolbar": {"likeCountNotliked": "1.2K", "replyCount": "14"}
+                }}}
+            ]}}
+        });
+
+        let (comments, next_token) = parse_page(&page, None);
+        assert_eq!(next_token.as_deref(), Some("PAGE2"));
+        assert_eq!(comments.len(), 1);
+
+        let (comment, replies_token) = &comments[0];
+        assert_eq!(comment.comment_id, "Ugz1");
+        assert_eq!(comment.author, "@fan");
+        assert_eq!(comment.author_channel_id.as_deref(), Some("UCauthor"));
+        assert_eq!(comment.text, "Great video!");
+        assert_eq!(comment.like_count, Some(1_200));
+        assert_eq!(comment.reply_count, 14);
+        assert!(comment.is_pinned);
+        assert_eq!(replies_token.as_deref(), Some("REPLIES1"));
+
+        assert_eq!(
+            sort_token(&page, CommentSortOrder::Newest).as_deref(),
+            Some("NEWEST")
+        );
+    }
+
+    #[test]
+    fn test_parse_legacy_replies() {
+        let page = json!({
+            "onResponseReceivedEndpoints": [{"appendContinuationItemsAction": {
+                "continuationItems": [
+                    {"commentRenderer": {
+                        "commentId": "Ugz1.reply",
+                        "authorText": {"simpleText": "@uploader"},
+                        "authorEndpoint": {"browseEndpoint": {"browseId": "UCuploader"}},
+                        "authorIsChannelOwner": true,
+                        "contentText": {"runs": [{"text": "Thanks "}, {"text": "a lot"}]},
+                        "publishedTimeText": {"runs": [{"text": "1 year ago"}]}
+                    }},
+                    {"continuationItemRenderer": {"button": {"buttonRenderer": {
+                        "command": {"continuationCommand": {"token": "MORE_REPLIES"}}
+                    }}}}
+                ]
+            }}]
+        });
+
+        let (replies, next_token) = parse_page(&page, Some("Ugz1"));
+        assert_eq!(next_token.as_deref(), Some("MORE_REPLIES"));
+
+        let reply = &replies[0].0;
+        assert_eq!(reply.parent_id.as_deref(), Some("Ugz1"));
+        assert_eq!(reply.text, "Thanks a lot");
+        assert_eq!(reply.like_count, Some(0));
+        assert!(reply.is_author_uploader);
+        assert_eq!(reply.published_time_text.as_deref(), Some("1 year ago"));
+    }
+}
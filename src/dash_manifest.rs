@@ -0,0 +1,494 @@
+use std::fmt::Write;
+
+use url::Url;
+
+use crate::models::{MediaKind, StreamingData, StreamingFormat};
+use crate::transcript_list::TranscriptList;
+
+/// # DashManifest
+///
+/// Generates a static MPEG-DASH manifest (MPD) from the adaptive formats of a video.
+///
+/// YouTube's adaptive formats are single-file streams with a `sidx` index: the
+/// `init_range` locates the initialization segment and the `index_range` the
+/// segment index, which is what the `SegmentBase` element of the on-demand DASH
+/// profile describes. Formats are grouped into one adaptation set per kind and
+/// MIME type (e.g., `video/mp4`, `video/webm`, `audio/mp4`), since players only
+/// switch between representations of the same set.
+///
+/// Videos with several audio tracks (multi-language and dubbed videos) have audio
+/// formats sharing the same itag, told apart by their `audioTrack` (or `xtags`).
+/// Each audio track gets its own adaptation sets, with the language of the track,
+/// and representation ids of the form `<itag>-<track id>` to stay unique.
+///
+/// Formats are left out when they cannot be played from a manifest: formats without
+/// a URL (such as undeciphered protected formats), without ranges (muxed and live
+/// formats), and audio formats with dynamic range compression, which duplicate the
+/// regular audio tracks.
+///
+/// Subtitle tracks are added as WebVTT text adaptation sets, one per transcript.
+pub struct DashManifest;
+
+impl DashManifest {
+    /// Namespace of the MPD schema
+    const MPD_NAMESPACE: &'static str = "urn:mpeg:dash:schema:mpd:2011";
+
+    /// Profile of single-file representations indexed by a segment index
+    const ON_DEMAND_PROFILE: &'static str = "urn:mpeg:dash:profile:isoff-on-demand:2011";
+
+    /// Generates the manifest of the streaming data.
+    ///
+    /// # Parameters
+    ///
+    /// * `streaming_data` - The streaming data of the video
+    /// * `subtitles` - The transcripts to add as subtitle tracks, if any
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The MPD document
+    pub fn generate(streaming_data: &StreamingData, subtitles: Option<&TranscriptList>) -> String {
+        let formats: Vec<&StreamingFormat> = streaming_data
+            .adaptive_formats
+            .iter()
+            .filter(|format| Self::is_playable(format))
+            .collect();
+
+        let duration_ms = formats
+            .iter()
+            .filter_map(|format| format.approx_duration_ms.as_deref()?.parse::<u64>().ok())
+            .max()
+            .unwrap_or(0);
+
+        let mut mpd = String::new();
+        mpd.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            mpd,
+            "<MPD xmlns=\"{}\" profiles=\"{}\" type=\"static\" mediaPresentationDuration=\"{}\" minBufferTime=\"PT1.500S\">",
+            Self::MPD_NAMESPACE,
+            Self::ON_DEMAND_PROFILE,
+            Self::duration(duration_ms)
+        );
+        mpd.push_str("  <Period>\n");
+
+        let mut set_id = 0;
+        for kind in [MediaKind::Video, MediaKind::Audio] {
+            for (mime_type, track, representations) in Self::group(&formats, kind) {
+                Self::write_media_set(
+                    &mut mpd,
+                    set_id,
+                    kind,
+                    &mime_type,
+                    track.as_ref(),
+                    representations,
+                );
+                set_id += 1;
+            }
+        }
+
+        if let Some(transcript_list) = subtitles {
+            let mut transcripts: Vec<_> = transcript_list.transcripts().collect();
+            transcripts.sort_by(|a, b| {
+                (&a.language_code, a.is_generated).cmp(&(&b.language_code, b.is_generated))
+            });
+
+            for transcript in transcripts {
+                let Ok(mut url) = Url::parse(&transcript.url) else {
+                    continue;
+                };
+                let query: Vec<(String, String)> = url
+                    .query_pairs()
+                    .filter(|(key, _)| key != "fmt")
+                    .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                    .collect();
+                url.query_pairs_mut()
+                    .clear()
+                    .extend_pairs(query)
+                    .append_pair("fmt", "vtt");
+
+                let id = if transcript.is_generated {
+                    format!("{}.asr", transcript.language_code)
+                } else {
+                    transcript.language_code.clone()
+                };
+
+                let _ = writeln!(
+                    mpd,
+                    "    <AdaptationSet id=\"{}\" contentType=\"text\" mimeType=\"text/vtt\" lang=\"{}\">",
+                    set_id,
+                    Self::escape(&transcript.language_code)
+                );
+                let _ = writeln!(
+                    mpd,
+                    "      <Role schemeIdUri=\"urn:mpeg:dash:role:2011\" value=\"{}\"/>",
+                    if transcript.is_generated {
+                        "caption"
+                    } else {
+                        "subtitle"
+                    }
+                );
+                let _ = writeln!(
+                    mpd,
+                    "      <Label>{}</Label>",
+                    Self::escape(&transcript.language)
+                );
+                let _ = writeln!(
+                    mpd,
+                    "      <Representation id=\"{}\" bandwidth=\"0\">",
+                    Self::escape(&id)
+                );
+                let _ = writeln!(
+                    mpd,
+                    "        <BaseURL>{}</BaseURL>",
+                    Self::escape(url.as_str())
+                );
+                mpd.push_str("      </Representation>\n");
+                mpd.push_str("    </AdaptationSet>\n");
+                set_id += 1;
+            }
+        }
+
+        mpd.push_str("  </Period>\n");
+        mpd.push_str("</MPD>\n");
+        mpd
+    }
+
+    /// Writes the adaptation set of one kind, MIME type and audio track.
+    fn write_media_set(
+        mpd: &mut String,
+        id: usize,
+        kind: MediaKind,
+        mime_type: &str,
+        track: Option<&AudioTrack>,
+        mut representations: Vec<&StreamingFormat>,
+    ) {
+        representations.sort_by_key(|format| format.bitrate);
+        let content_type = match kind {
+            MediaKind::Video => "video",
+            MediaKind::Audio => "audio",
+        };
+
+        let mut set_attributes = format!(
+            "id=\"{}\" contentType=\"{}\" mimeType=\"{}\"",
+            id,
+            content_type,
+            Self::escape(mime_type)
+        );
+        if let Some(language) = track.and_then(|track| track.language.as_deref()) {
+            let _ = write!(set_attributes, " lang=\"{}\"", Self::escape(language));
+        }
+        let _ = writeln!(
+            mpd,
+            "    <AdaptationSet {} subsegmentAlignment=\"true\" subsegmentStartsWithSAP=\"1\">",
+            set_attributes
+        );
+        if let Some(name) = track.and_then(|track| track.name.as_deref()) {
+            let _ = writeln!(mpd, "      <Label>{}</Label>", Self::escape(name));
+        }
+
+        for format in representations {
+            let representation_id = match track {
+                Some(track) => format!("{}-{}", format.itag, track.id),
+                None => format.itag.to_string(),
+            };
+            let mut attributes = format!("id=\"{}\"", Self::escape(&representation_id));
+            let codecs = format.codecs();
+            if !codecs.is_empty() {
+                let _ = write!(
+                    attributes,
+                    " codecs=\"{}\"",
+                    Self::escape(&codecs.join(","))
+                );
+            }
+            let _ = write!(
+                attributes,
+                " bandwidth=\"{}\"",
+                format.bitrate.or(format.average_bitrate).unwrap_or(0)
+            );
+            if let (Some(width), Some(height)) = (format.width, format.height) {
+                let _ = write!(attributes, " width=\"{}\" height=\"{}\"", width, height);
+            }
+            if let Some(fps) = format.fps {
+                let _ = write!(attributes, " frameRate=\"{}\"", fps);
+            }
+            if let Some(sample_rate) = &format.audio_sample_rate {
+                let _ = write!(
+                    attributes,
+                    " audioSamplingRate=\"{}\"",
+                    Self::escape(sample_rate)
+                );
+            }
+
+            let _ = writeln!(mpd, "      <Representation {}>", attributes);
+            if let Some(channels) = format.audio_channels {
+                let _ = writeln!(
+                    mpd,
+                    "        <AudioChannelConfiguration schemeIdUri=\"urn:mpeg:dash:23003:3:audio_channel_configuration:2011\" value=\"{}\"/>",
+                    channels
+                );
+            }
+            let _ = writeln!(
+                mpd,
+                "        <BaseURL>{}</BaseURL>",
+                Self::escape(format.url.as_deref().unwrap_or_default())
+            );
+            // Ranges were checked by `is_playable`
+            if let (Some(init), Some(index)) = (&format.init_range, &format.index_range) {
+                let _ = writeln!(
+                    mpd,
+                    "        <SegmentBase indexRange=\"{}-{}\">",
+                    Self::escape(&index.start),
+                    Self::escape(&index.end)
+                );
+                let _ = writeln!(
+                    mpd,
+                    "          <Initialization range=\"{}-{}\"/>",
+                    Self::escape(&init.start),
+                    Self::escape(&init.end)
+                );
+                mpd.push_str("        </SegmentBase>\n");
+            }
+            mpd.push_str("      </Representation>\n");
+        }
+
+        mpd.push_str("    </AdaptationSet>\n");
+    }
+
+    /// Groups the formats of one kind by MIME type and audio track, in order of
+    /// first appearance.
+    fn group<'a>(
+        formats: &[&'a StreamingFormat],
+        kind: MediaKind,
+    ) -> Vec<(String, Option<AudioTrack>, Vec<&'a StreamingFormat>)> {
+        let mut groups: Vec<(String, Option<AudioTrack>, Vec<&StreamingFormat>)> = Vec::new();
+
+        for format in formats {
+            let Some(media_type) = format.media_type().filter(|m| m.kind == kind) else {
+                continue;
+            };
+            let mime_type = format!("{}/{}", Self::kind_name(kind), media_type.container);
+            let track = match kind {
+                MediaKind::Audio => AudioTrack::of(format),
+                MediaKind::Video => None,
+            };
+
+            match groups.iter_mut().find(|(m, t, _)| {
+                *m == mime_type && t.as_ref().map(|t| &t.id) == track.as_ref().map(|t| &t.id)
+            }) {
+                Some((_, _, group)) => group.push(format),
+                None => groups.push((mime_type, track, vec![format])),
+            }
+        }
+        groups
+    }
+
+    fn is_playable(format: &StreamingFormat) -> bool {
+        format.url.is_some()
+            && format.init_range.is_some()
+            && format.index_range.is_some()
+            && !format.is_drc()
+    }
+
+    fn kind_name(kind: MediaKind) -> &'static str {
+        match kind {
+            MediaKind::Video => "video",
+            MediaKind::Audio => "audio",
+        }
+    }
+
+    /// Formats a duration as an ISO 8601 duration in seconds (e.g., "PT212.091S").
+    fn duration(duration_ms: u64) -> String {
+        format!("PT{}.{:03}S", duration_ms / 1000, duration_ms % 1000)
+    }
+
+    /// Escapes the XML special characters of an attribute or text value.
+    fn escape(value: &str) -> String {
+        let mut escaped = String::with_capacity(value.len());
+        for character in value.chars() {
+            match character {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&apos;"),
+                other => escaped.push(other),
+            }
+        }
+        escaped
+    }
+}
+
+/// The audio track of a format of a video with several audio tracks
+struct AudioTrack {
+    /// Identifier of the track (e.g., "en-US.4")
+    id: String,
+    /// Language of the track (e.g., "en-US")
+    language: Option<String>,
+    /// Name of the track shown by the player (e.g., "English (United States) original")
+    name: Option<String>,
+}
+
+impl AudioTrack {
+    /// Reads the `audioTrack` of a format, falling back to its `xtags`.
+    fn of(format: &StreamingFormat) -> Option<Self> {
+        if let Some(track) = format.extra.get("audioTrack") {
+            let id = track.get("id").and_then(|id| id.as_str())?.to_string();
+            let language = id
+                .split('.')
+                .next()
+                .filter(|language| !language.is_empty())
+                .map(str::to_string);
+            let name = track
+                .get("displayName")
+                .and_then(|name| name.as_str())
+                .map(str::to_string);
+            return Some(Self { id, language, name });
+        }
+
+        format.xtags.as_ref().map(|xtags| Self {
+            id: xtags.clone(),
+            language: None,
+            name: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming_data_extractor::StreamingDataExtractor;
+    use serde_json::json;
+
+    fn streaming_data() -> StreamingData {
+        let format = |itag: u32, mime_type: &str, bitrate: u64, extra: serde_json::Value| {
+            let mut format = json!({
+                "itag": itag,
+                "url": format!("https://rr1---sn-abc.googlevideo.com/videoplayback?itag={}&mime=x", itag),
+                "mimeType": mime_type,
+                "bitrate": bitrate,
+                "quality": "medium",
+                "projectionType": "RECTANGULAR",
+                "approxDurationMs": "212091",
+                "initRange": {"start": "0", "end": "739"},
+                "indexRange": {"start": "740", "end": "1235"}
+            });
+            format
+                .as_object_mut()
+                .unwrap()
+                .extend(extra.as_object().unwrap().clone());
+            format
+        };
+
+        let player_response = json!({"streamingData": {
+            "expiresInSeconds": "21540",
+            "formats": [{
+                "itag": 18, "url": "https://example.com/18", "mimeType": "video/mp4; codecs=\"avc1.42001E, mp4a.40.2\"",
+                "bitrate": 500000, "quality": "medium", "projectionType": "RECTANGULAR", "approxDurationMs": "212091"
+            }],
+            "adaptiveFormats": [
+                format(137, "video/mp4; codecs=\"avc1.640028\"", 4_000_000, json!({"width": 1920, "height": 1080, "fps": 30})),
+                format(136, "video/mp4; codecs=\"avc1.4d401f\"", 2_000_000, json!({"width": 1280, "height": 720, "fps": 30})),
+                format(248, "video/webm; codecs=\"vp9\"", 2_500_000, json!({"width": 1920, "height": 1080, "fps": 30})),
+                format(140, "audio/mp4; codecs=\"mp4a.40.2\"", 130_000, json!({"audioSampleRate": "44100", "audioChannels": 2})),
+                format(251, "audio/webm; codecs=\"opus\"", 140_000, json!({"audioSampleRate": "48000", "audioChannels": 2, "isDrc": true})),
+                format(250, "audio/webm; codecs=\"opus\"", 70_000, json!({"audioSampleRate": "48000", "audioChannels": 2, "indexRange": null})),
+            ]
+        }});
+
+        StreamingDataExtractor::extract_streaming_data(&player_response, "test").unwrap()
+    }
+
+    #[test]
+    fn test_multiple_audio_tracks() {
+        let track = |itag: u32, id: &str, name: &str| {
+            json!({
+                "itag": itag,
+                "url": format!("https://example.com/{}/{}", itag, id),
+                "mimeType": "audio/webm; codecs=\"opus\"",
+                "bitrate": 140_000,
+                "initRange": {"start": "0", "end": "265"},
+                "indexRange": {"start": "266", "end": "620"},
+                "audioTrack": {"displayName": name, "id": id, "audioIsDefault": id == "en.4"}
+            })
+        };
+        let player_response = json!({"streamingData": {"adaptiveFormats": [
+            track(251, "en.4", "English original"),
+            track(251, "fr-FR.3", "French (France)"),
+            track(250, "en.4", "English original"),
+            {
+                "itag": 140, "url": "https://example.com/140", "mimeType": "audio/mp4",
+                "bitrate": 130_000,
+                "initRange": {"start": "0", "end": "631"},
+                "indexRange": {"start": "632", "end": "900"}
+            }
+        ]}});
+        let streaming_data =
+            StreamingDataExtractor::extract_streaming_data(&player_response, "test").unwrap();
+        let mpd = DashManifest::generate(&streaming_data, None);
+
+        let sets: Vec<&str> = mpd
+            .lines()
+            .filter(|line| line.contains("<AdaptationSet"))
+            .collect();
+        assert_eq!(sets.len(), 3);
+        assert!(sets[0].contains("mimeType=\"audio/webm\" lang=\"en\""));
+        assert!(sets[1].contains("mimeType=\"audio/webm\" lang=\"fr-FR\""));
+        assert!(sets[2].contains("mimeType=\"audio/mp4\" subsegmentAlignment"));
+        assert!(mpd.contains("<Label>French (France)</Label>"));
+
+        // Representation ids are unique
+        assert!(mpd.contains("<Representation id=\"251-en.4\" codecs=\"opus\""));
+        assert!(mpd.contains("<Representation id=\"251-fr-FR.3\" codecs=\"opus\""));
+        assert!(mpd.contains("<Representation id=\"250-en.4\""));
+        // No empty codecs attribute for a MIME type without codecs
+        assert!(mpd.contains("<Representation id=\"140\" bandwidth=\"130000\""));
+        assert!(!mpd.contains("codecs=\"\""));
+    }
+
+    #[test]
+    fn test_generate() {
+        let mpd = DashManifest::generate(&streaming_data(), None);
+
+        assert!(mpd.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<MPD "));
+        assert!(mpd.contains("mediaPresentationDuration=\"PT212.091S\""));
+        assert!(mpd.ends_with("  </Period>\n</MPD>\n"));
+
+        // mp4 video, webm video, mp4 audio; the DRC and unindexed webm audio are left out
+        let sets: Vec<&str> = mpd
+            .lines()
+            .filter(|line| line.contains("<AdaptationSet"))
+            .collect();
+        assert_eq!(sets.len(), 3);
+        assert!(sets[0].contains("id=\"0\" contentType=\"video\" mimeType=\"video/mp4\""));
+        assert!(sets[1].contains("mimeType=\"video/webm\""));
+        assert!(sets[2].contains("id=\"2\" contentType=\"audio\" mimeType=\"audio/mp4\""));
+        assert!(!mpd.contains("id=\"18\""));
+        assert!(!mpd.contains("id=\"251\""));
+        assert!(!mpd.contains("id=\"250\""));
+
+        // Representations are sorted by bandwidth within a set
+        let itag_136 = mpd.find("<Representation id=\"136\"").unwrap();
+        let itag_137 = mpd.find("<Representation id=\"137\"").unwrap();
+        assert!(itag_136 < itag_137);
+
+        assert!(mpd.contains(
+            "<Representation id=\"137\" codecs=\"avc1.640028\" bandwidth=\"4000000\" width=\"1920\" height=\"1080\" frameRate=\"30\">"
+        ));
+        assert!(mpd.contains(
+            "<BaseURL>https://rr1---sn-abc.googlevideo.com/videoplayback?itag=137&amp;mime=x</BaseURL>"
+        ));
+        assert!(mpd.contains("<SegmentBase indexRange=\"740-1235\">"));
+        assert!(mpd.contains("<Initialization range=\"0-739\"/>"));
+        assert!(mpd.contains("audioSamplingRate=\"44100\""));
+        assert!(mpd.contains("audio_channel_configuration:2011\" value=\"2\"/>"));
+
+        // Every opened element is closed
+        for element in ["AdaptationSet", "Representation", "SegmentBase"] {
+            assert_eq!(
+                mpd.matches(&format!("<{} ", element)).count(),
+                mpd.matches(&format!("</{}>", element)).count(),
+                "{}",
+                element
+            );
+        }
+    }
+}
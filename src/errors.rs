@@ -1,5 +1,6 @@
 use crate::proxies::{GenericProxyConfig, ProxyConfig, WebshareProxyConfig};
 use crate::TranscriptList;
+use chrono::{DateTime, FixedOffset};
 use thiserror::Error;
 
 /// # YouTubeTranscriptApiError
@@ -76,7 +77,8 @@ pub type CookieInvalid = CookieError;
 #[derive(Debug, Error)]
 #[error("{}", self.build_error_message())]
 pub struct CouldNotRetrieveTranscript {
-    /// The YouTube video ID that was being accessed
+    /// The YouTube video ID that was being accessed (the playlist ID for
+    /// `PlaylistUnavailable` errors, the channel for `ChannelUnavailable` errors)
     pub video_id: String,
 
     /// The specific reason why the transcript couldn't be retrieved
@@ -89,7 +91,11 @@ pub struct CouldNotRetrieveTranscript {
 ///
 /// This enum provides specific information about why transcript retrieval failed,
 /// which is useful for error handling and providing helpful feedback to users.
+///
+/// New reasons may be added in minor releases, so matches on this enum need a
+/// wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum CouldNotRetrieveTranscriptReason {
     /// Subtitles/transcripts are disabled for this video
     TranscriptsDisabled,
@@ -139,17 +145,54 @@ pub enum CouldNotRetrieveTranscriptReason {
     /// The video is age-restricted and requires authentication
     AgeRestricted,
 
+    /// The video is an upcoming live stream or premiere, or a live stream that is offline
+    LiveStreamOffline {
+        /// The message shown by YouTube (e.g., "This live event will begin in 3 hours.")
+        reason: Option<String>,
+
+        /// When the stream or premiere is scheduled to start, if announced
+        scheduled_start_time: Option<DateTime<FixedOffset>>,
+    },
+
     /// The YouTube data structure couldn't be parsed
     YouTubeDataUnparsable(String),
+
+    /// The playlist does not exist or is private, with the message shown by YouTube.
+    ///
+    /// The `video_id` of the error holds the playlist ID.
+    PlaylistUnavailable(String),
+
+    /// The channel could not be found or resolved, with a description of the problem.
+    ///
+    /// The `video_id` of the error holds the channel ID, handle or URL.
+    ChannelUnavailable(String),
+
+    /// The download of a streaming format failed, with a description of the problem.
+    DownloadFailed(String),
 }
 
 impl CouldNotRetrieveTranscript {
     /// Builds a detailed error message based on the error reason
     fn build_error_message(&self) -> String {
-        let base_error = format!(
-            "Could not retrieve a transcript for the video {}!",
-            self.video_id.replace("{video_id}", &self.video_id)
-        );
+        let base_error = match &self.reason {
+            // `video_id` holds the playlist ID or channel for these errors
+            Some(CouldNotRetrieveTranscriptReason::PlaylistUnavailable(_)) => {
+                format!("Could not retrieve the playlist {}!", self.video_id)
+            }
+            Some(CouldNotRetrieveTranscriptReason::ChannelUnavailable(_)) => {
+                format!("Could not retrieve the channel {}!", self.video_id)
+            }
+            Some(CouldNotRetrieveTranscriptReason::DownloadFailed(_)) => {
+                format!(
+                    "Could not download a stream of the video {}!",
+                    self.video_id
+                )
+            }
+            _ => format!(
+                "Could not retrieve a transcript for the video {}!",
+                self.video_id.replace("{video_id}", &self.video_id)
+            ),
+        };
 
         match &self.reason {
             Some(reason) => {
@@ -225,9 +268,28 @@ impl CouldNotRetrieveTranscript {
                     CouldNotRetrieveTranscriptReason::AgeRestricted => {
                         "This video is age-restricted. Therefore, you will have to authenticate to be able to retrieve transcripts for it. You will have to provide a cookie to authenticate yourself.".to_string()
                     },
+                    CouldNotRetrieveTranscriptReason::LiveStreamOffline { reason, scheduled_start_time } => {
+                        let mut message = "The video is an upcoming live stream or premiere, or a live stream that is currently offline".to_string();
+                        if let Some(reason) = reason {
+                            message.push_str(&format!(": {}", reason));
+                        }
+                        if let Some(start) = scheduled_start_time {
+                            message.push_str(&format!("\n\nIt is scheduled to start at {}. Transcripts become available once the stream is live.", start.to_rfc3339()));
+                        }
+                        message
+                    },
                     CouldNotRetrieveTranscriptReason::YouTubeDataUnparsable(details) => {
                         format!("The data required to fetch the transcript is not parsable: {}. This should not happen, please open an issue (make sure to include the video ID)!", details)
                     },
+                    CouldNotRetrieveTranscriptReason::PlaylistUnavailable(message) => {
+                        format!("The playlist is not available: {}", message)
+                    },
+                    CouldNotRetrieveTranscriptReason::ChannelUnavailable(message) => {
+                        format!("The channel is not available: {}", message)
+                    },
+                    CouldNotRetrieveTranscriptReason::DownloadFailed(message) => {
+                        format!("The download of the stream failed: {}", message)
+                    },
                 };
 
                 format!("{} This is most likely caused by:\n\n{}", base_error, cause)
@@ -273,6 +335,18 @@ pub type InvalidVideoId = CouldNotRetrieveTranscript;
 /// Type alias for when the video is age-restricted and requires authentication
 pub type AgeRestricted = CouldNotRetrieveTranscript;
 
+/// Type alias for when the video is an upcoming or offline live stream
+pub type LiveStreamOffline = CouldNotRetrieveTranscript;
+
+/// Type alias for when a playlist does not exist or is private
+pub type PlaylistUnavailable = CouldNotRetrieveTranscript;
+
+/// Type alias for when a channel cannot be found
+pub type ChannelUnavailable = CouldNotRetrieveTranscript;
+
+/// Type alias for when the download of a streaming format fails
+pub type DownloadFailed = CouldNotRetrieveTranscript;
+
 /// Type alias for when YouTube data cannot be parsed
 pub type YouTubeDataUnparsable = CouldNotRetrieveTranscript;
 
@@ -550,4 +624,74 @@ mod tests {
         assert!(message.contains("not parsable"));
         assert!(message.contains("open an issue"));
     }
+
+    #[test]
+    fn test_build_error_message_live_stream_offline() {
+        let error = CouldNotRetrieveTranscript {
+            video_id: "dQw4w9WgXcQ".to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::LiveStreamOffline {
+                reason: Some("This live event will begin in 3 hours.".to_string()),
+                scheduled_start_time: DateTime::parse_from_rfc3339("2025-01-01T18:00:00+00:00")
+                    .ok(),
+            }),
+        };
+
+        let message = error.build_error_message();
+        assert!(message.contains("upcoming live stream"));
+        assert!(message.contains("will begin in 3 hours"));
+        assert!(message.contains("2025-01-01T18:00:00+00:00"));
+
+        let error = CouldNotRetrieveTranscript {
+            video_id: "dQw4w9WgXcQ".to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::LiveStreamOffline {
+                reason: None,
+                scheduled_start_time: None,
+            }),
+        };
+
+        let message = error.build_error_message();
+        assert!(message.contains("currently offline"));
+        assert!(!message.contains("scheduled"));
+    }
+
+    #[test]
+    fn test_build_error_message_playlist_unavailable() {
+        let error = CouldNotRetrieveTranscript {
+            video_id: "PLxxxxxxxx".to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::PlaylistUnavailable(
+                "The playlist does not exist.".to_string(),
+            )),
+        };
+
+        let message = error.build_error_message();
+        assert!(message.starts_with("Could not retrieve the playlist PLxxxxxxxx!"));
+        assert!(!message.contains("transcript"));
+        assert!(message.contains("The playlist does not exist."));
+
+        let error = CouldNotRetrieveTranscript {
+            video_id: "@missing".to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::ChannelUnavailable(
+                "The handle could not be resolved".to_string(),
+            )),
+        };
+        let message = error.build_error_message();
+        assert!(message.starts_with("Could not retrieve the channel @missing!"));
+        assert!(message.contains("The channel is not available"));
+    }
+
+    #[test]
+    fn test_build_error_message_download_failed() {
+        let error = CouldNotRetrieveTranscript {
+            video_id: "dQw4w9WgXcQ".to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::DownloadFailed(
+                "Downloaded 12 bytes, expected 25".to_string(),
+            )),
+        };
+
+        let message = error.build_error_message();
+        assert!(message.starts_with("Could not download a stream of the video dQw4w9WgXcQ!"));
+        assert!(!message.contains("transcript"));
+        assert!(message.contains("The download of the stream failed"));
+        assert!(message.contains("Downloaded 12 bytes, expected 25"));
+    }
 }
@@ -13,7 +13,14 @@ use std::collections::HashMap;
 use std::iter::Iterator;
 use std::vec::IntoIter;
 
-use crate::models::FetchedTranscriptSnippet;
+use regex::{Regex, RegexBuilder};
+
+use crate::models::{
+    Chapter, FetchedTranscriptSnippet, Heatmap, HeatmapSegment, TranscriptMatch,
+    TranscriptSearchOptions,
+};
+use crate::transcript_deduplicator::TranscriptDeduplicator;
+use crate::transcript_diff::TranscriptDiff;
 
 /// A complete transcript with all the snippets and metadata.
 ///
@@ -259,6 +266,553 @@ impl FetchedTranscript {
         let last = &self.snippets[self.snippets.len() - 1];
         last.start + last.duration
     }
+
+    /// Returns the snippet on screen at the given time.
+    ///
+    /// Snippets are expected to be sorted by start time, as returned by YouTube,
+    /// which allows the lookup to use a binary search.
+    ///
+    /// # Parameters
+    ///
+    /// * `time` - The timestamp to look up, in seconds
+    ///
+    /// # Returns
+    ///
+    /// The snippet whose `[start, start + duration)` interval contains `time`,
+    /// or `None` if nothing is being said at that moment.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// # let fetched = api.fetch_transcript("dQw4w9WgXcQ", &["en"], false).await?;
+    /// // What was said at 12:34?
+    /// if let Some(snippet) = fetched.snippet_at(12.0 * 60.0 + 34.0) {
+    ///     println!("At 12:34: {}", snippet.text);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn snippet_at(&self, time: f64) -> Option<&FetchedTranscriptSnippet> {
+        let index = self.snippets.partition_point(|s| s.start <= time);
+        if index == 0 {
+            return None;
+        }
+
+        let snippet = &self.snippets[index - 1];
+        if time < snippet.start + snippet.duration {
+            Some(snippet)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the snippets displayed between two timestamps.
+    ///
+    /// This includes every snippet starting within `[start, end)`, plus the snippet
+    /// already on screen at `start` if it began earlier. Snippets are expected to
+    /// be sorted by start time.
+    ///
+    /// # Parameters
+    ///
+    /// * `start` - Start of the time window, in seconds
+    /// * `end` - End of the time window, in seconds
+    ///
+    /// # Returns
+    ///
+    /// A slice of the matching snippets, which is empty if the window is empty or
+    /// contains no speech.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// # let fetched = api.fetch_transcript("dQw4w9WgXcQ", &["en"], false).await?;
+    /// // Everything said during the second minute
+    /// for snippet in fetched.range(60.0, 120.0) {
+    ///     println!("[{:.1}s]: {}", snippet.start, snippet.text);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn range(&self, start: f64, end: f64) -> &[FetchedTranscriptSnippet] {
+        if end <= start {
+            return &[];
+        }
+
+        let mut lower = self.snippets.partition_point(|s| s.start < start);
+        if lower > 0 {
+            let previous = &self.snippets[lower - 1];
+            if previous.start + previous.duration > start {
+                lower -= 1;
+            }
+        }
+        let upper = self.snippets.partition_point(|s| s.start < end);
+
+        &self.snippets[lower..upper.max(lower)]
+    }
+
+    /// Searches the transcript for a word, phrase or regular expression.
+    ///
+    /// The search runs over the full transcript text (as returned by [`text()`](Self::text)),
+    /// so phrases split across consecutive snippets are still found. Each match is
+    /// reported with the timestamps of the snippets it spans and some surrounding text.
+    ///
+    /// When `options.regex` is `false`, the query is treated as literal text and any
+    /// whitespace in it matches any run of whitespace in the transcript.
+    ///
+    /// # Parameters
+    ///
+    /// * `query` - The text or regular expression to look for
+    /// * `options` - Matching options (case sensitivity, whole words, regex, context size)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<TranscriptMatch>, regex::Error>` - All non-overlapping matches in
+    ///   transcript order, or an error if `query` is not a valid regular expression
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::YouTubeTranscriptApi;
+    /// # use yt_transcript_rs::models::TranscriptSearchOptions;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// # let fetched = api.fetch_transcript("dQw4w9WgXcQ", &["en"], false).await?;
+    /// let matches = fetched.search("gradient descent", &TranscriptSearchOptions::default())?;
+    ///
+    /// for m in matches {
+    ///     println!("[{:.1}s]: ...{}[{}]{}...", m.start, m.context_before, m.text, m.context_after);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search(
+        &self,
+        query: &str,
+        options: &TranscriptSearchOptions,
+    ) -> Result<Vec<TranscriptMatch>, regex::Error> {
+        if query.trim().is_empty() || self.snippets.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let regex = Self::build_search_regex(query, options)?;
+
+        // Offsets of each snippet in the joined text, matching the layout of `text()`
+        let mut full_text = String::new();
+        let mut offsets = Vec::with_capacity(self.snippets.len());
+        for (i, snippet) in self.snippets.iter().enumerate() {
+            if i > 0 {
+                full_text.push(' ');
+            }
+            offsets.push(full_text.len());
+            full_text.push_str(&snippet.text);
+        }
+
+        let snippet_index = |byte: usize| offsets.partition_point(|&o| o <= byte).max(1) - 1;
+
+        let matches = regex
+            .find_iter(&full_text)
+            .filter(|m| !m.as_str().is_empty())
+            .map(|m| {
+                let first_snippet = snippet_index(m.start());
+                let last_snippet = snippet_index(m.end() - 1);
+                let first = &self.snippets[first_snippet];
+                let last = &self.snippets[last_snippet];
+
+                TranscriptMatch {
+                    text: m.as_str().to_string(),
+                    start: first.start,
+                    end: last.start + last.duration,
+                    first_snippet,
+                    last_snippet,
+                    context_before: Self::tail_chars(
+                        &full_text[..m.start()],
+                        options.context_chars,
+                    ),
+                    context_after: full_text[m.end()..]
+                        .chars()
+                        .take(options.context_chars)
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Ok(matches)
+    }
+
+    /// Returns a copy of the transcript with every timestamp moved by `offset` seconds.
+    ///
+    /// A negative offset moves the transcript earlier. Snippets that would end before
+    /// `0.0` are dropped and a snippet straddling `0.0` is trimmed so that no timestamp
+    /// is negative.
+    ///
+    /// # Parameters
+    ///
+    /// * `offset` - The number of seconds to add to every start time
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// # let fetched = api.fetch_transcript("dQw4w9WgXcQ", &["en"], false).await?;
+    /// // The re-uploaded video has a 5 second intro added at the beginning
+    /// let adjusted = fetched.shift(5.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn shift(&self, offset: f64) -> Self {
+        let snippets = self
+            .snippets
+            .iter()
+            .map(|snippet| FetchedTranscriptSnippet {
+                text: snippet.text.clone(),
+                start: snippet.start + offset,
+                duration: snippet.duration,
+            })
+            .collect();
+
+        self.with_snippets(snippets).clip(0.0, f64::INFINITY)
+    }
+
+    /// Returns a copy of the transcript with all timings multiplied by `factor`.
+    ///
+    /// This is useful when a video has been re-encoded at a different playback speed:
+    /// a video sped up 2x needs a factor of `0.5`, a video slowed down to half speed
+    /// needs a factor of `2.0`. Both start times and durations are scaled.
+    ///
+    /// # Parameters
+    ///
+    /// * `factor` - The multiplier applied to every start time and duration
+    ///
+    /// # Returns
+    ///
+    /// * `Option<FetchedTranscript>` - The scaled transcript, or `None` if `factor` is
+    ///   not a positive, finite number
+    pub fn scale(&self, factor: f64) -> Option<Self> {
+        if !factor.is_finite() || factor <= 0.0 {
+            return None;
+        }
+
+        let snippets = self
+            .snippets
+            .iter()
+            .map(|snippet| FetchedTranscriptSnippet {
+                text: snippet.text.clone(),
+                start: snippet.start * factor,
+                duration: snippet.duration * factor,
+            })
+            .collect();
+
+        Some(self.with_snippets(snippets))
+    }
+
+    /// Returns the part of the transcript between two timestamps.
+    ///
+    /// Snippets entirely outside `[start, end)` are dropped, and snippets partially
+    /// overlapping the window are trimmed to fit inside it. Timestamps are kept as-is;
+    /// chain with [`shift()`](Self::shift) to make the clip start at zero.
+    ///
+    /// # Parameters
+    ///
+    /// * `start` - Start of the window to keep, in seconds
+    /// * `end` - End of the window to keep, in seconds
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// # let fetched = api.fetch_transcript("dQw4w9WgXcQ", &["en"], false).await?;
+    /// // Transcript of a clip cut from 1:00 to 2:30, re-based to start at zero
+    /// let clip = fetched.clip(60.0, 150.0).shift(-60.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clip(&self, start: f64, end: f64) -> Self {
+        let snippets = self
+            .snippets
+            .iter()
+            .filter_map(|snippet| {
+                let clipped_start = snippet.start.max(start);
+                let clipped_end = (snippet.start + snippet.duration).min(end);
+
+                // Zero-length snippets are kept when they sit inside the window
+                let inside = if snippet.duration > 0.0 {
+                    clipped_end > clipped_start
+                } else {
+                    snippet.start >= start && snippet.start < end
+                };
+                if !inside {
+                    return None;
+                }
+
+                Some(FetchedTranscriptSnippet {
+                    text: snippet.text.clone(),
+                    start: clipped_start,
+                    duration: clipped_end - clipped_start,
+                })
+            })
+            .collect();
+
+        self.with_snippets(snippets)
+    }
+
+    /// Returns a transcript with the snippets of `other` appended after this one.
+    ///
+    /// The snippets of `other` are shifted by `offset` seconds, which is typically the
+    /// start of that part within the combined video. The metadata (video ID, language)
+    /// of `self` is kept.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The transcript to append
+    /// * `offset` - The number of seconds added to every timestamp of `other`
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let part1 = api.fetch_transcript("part1_video_id", &["en"], false).await?;
+    /// let part2 = api.fetch_transcript("part2_video_id", &["en"], false).await?;
+    ///
+    /// // Part 2 starts where part 1 ends
+    /// let full = part1.concat(&part2, part1.duration());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn concat(&self, other: &FetchedTranscript, offset: f64) -> Self {
+        let mut snippets = self.snippets.clone();
+        snippets.extend(other.shift(offset).snippets);
+        snippets.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+        self.with_snippets(snippets)
+    }
+
+    /// Returns a copy of the transcript where no snippet runs into the next one.
+    ///
+    /// Snippets are sorted by start time, then any snippet whose `start + duration`
+    /// exceeds the start of the following snippet has its duration shortened so that
+    /// it ends exactly when the next one begins.
+    pub fn fix_overlaps(&self) -> Self {
+        let mut snippets = self.snippets.clone();
+        snippets.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+        for i in 1..snippets.len() {
+            let next_start = snippets[i].start;
+            let current = &mut snippets[i - 1];
+            if current.start + current.duration > next_start {
+                current.duration = next_start - current.start;
+            }
+        }
+
+        self.with_snippets(snippets)
+    }
+
+    /// Returns a copy of the transcript with rolling caption repeats removed.
+    ///
+    /// Auto-generated and live captions often repeat the end of one line at the start
+    /// of the next one. This removes the repeated words using a
+    /// [`TranscriptDeduplicator`] with its default settings, which makes `text()` and
+    /// `search()` results much cleaner.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let fetched = api.fetch_transcript("dQw4w9WgXcQ", &["en"], false).await?;
+    ///
+    /// if fetched.is_generated() {
+    ///     println!("{}", fetched.deduplicate().text());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn deduplicate(&self) -> Self {
+        self.with_snippets(TranscriptDeduplicator::new().deduplicate(&self.snippets))
+    }
+
+    /// Compares this transcript with a newer version or another track.
+    ///
+    /// This is a shorthand for [`TranscriptDiff::between(self, other)`](TranscriptDiff::between),
+    /// which reports inserted, deleted and changed snippets with a word-level diff of
+    /// the changed ones.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The transcript to compare against this one
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::FetchedTranscript;
+    /// # fn example(archived: FetchedTranscript, current: FetchedTranscript) {
+    /// let diff = archived.diff(&current);
+    ///
+    /// if !diff.is_empty() {
+    ///     println!("Captions were updated:\n{}", diff);
+    /// }
+    /// # }
+    /// ```
+    pub fn diff(&self, other: &FetchedTranscript) -> TranscriptDiff {
+        TranscriptDiff::between(self, other)
+    }
+
+    /// Splits the transcript into one transcript per chapter.
+    ///
+    /// Each snippet is assigned to the chapter in which it starts, so no snippet is
+    /// duplicated or cut. Snippets starting before the first chapter are assigned to
+    /// the first one, and snippets starting after the last chapter ends to the last
+    /// one. Timestamps are kept relative to the start of the video.
+    ///
+    /// # Parameters
+    ///
+    /// * `chapters` - The chapters of the video, in chronological order
+    ///
+    /// # Returns
+    ///
+    /// A list of `(chapter, transcript)` pairs with one entry per chapter, including
+    /// chapters without any speech. Returns an empty list if `chapters` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let chapters = api.fetch_chapters("dQw4w9WgXcQ").await?;
+    /// let fetched = api.fetch_transcript("dQw4w9WgXcQ", &["en"], false).await?;
+    ///
+    /// for (chapter, transcript) in fetched.split_by_chapters(&chapters) {
+    ///     println!("## {} ({:.0}s)\n{}", chapter.title, chapter.start, transcript.text());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn split_by_chapters(&self, chapters: &[Chapter]) -> Vec<(Chapter, FetchedTranscript)> {
+        if chapters.is_empty() {
+            return Vec::new();
+        }
+
+        let mut groups: Vec<Vec<FetchedTranscriptSnippet>> = vec![Vec::new(); chapters.len()];
+
+        for snippet in &self.snippets {
+            // Index of the last chapter starting at or before the snippet
+            let index = chapters
+                .partition_point(|chapter| chapter.start <= snippet.start)
+                .saturating_sub(1);
+            groups[index].push(snippet.clone());
+        }
+
+        chapters
+            .iter()
+            .cloned()
+            .zip(groups)
+            .map(|(chapter, snippets)| (chapter, self.with_snippets(snippets)))
+            .collect()
+    }
+
+    /// Returns what is said during the most replayed segments of the video.
+    ///
+    /// Each segment gets the text of the snippets displayed during it, as returned by
+    /// [`range`](FetchedTranscript::range). Heatmap segments are short (about 1% of
+    /// the video each), so a snippet can appear in several neighbouring segments.
+    ///
+    /// # Parameters
+    ///
+    /// * `heatmap` - The heatmap of the video
+    /// * `n` - Number of segments to return
+    ///
+    /// # Returns
+    ///
+    /// A list of `(segment, text)` pairs, most replayed segment first.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let infos = api.fetch_video_infos("dQw4w9WgXcQ").await?;
+    /// let fetched = api.fetch_transcript("dQw4w9WgXcQ", &["en"], false).await?;
+    ///
+    /// if let Some(heatmap) = &infos.heatmap {
+    ///     for (segment, text) in fetched.most_replayed_text(heatmap, 3) {
+    ///         println!("[{:.0}s-{:.0}s] {}", segment.start, segment.end, text);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn most_replayed_text(&self, heatmap: &Heatmap, n: usize) -> Vec<(HeatmapSegment, String)> {
+        heatmap
+            .most_replayed(n)
+            .into_iter()
+            .map(|segment| {
+                let text = self
+                    .range(segment.start, segment.end)
+                    .iter()
+                    .map(|snippet| snippet.text.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(" ");
+                (segment.clone(), text)
+            })
+            .collect()
+    }
+
+    /// Creates a transcript with the same metadata as this one but different snippets.
+    fn with_snippets(&self, snippets: Vec<FetchedTranscriptSnippet>) -> Self {
+        Self {
+            snippets,
+            video_id: self.video_id.clone(),
+            language: self.language.clone(),
+            language_code: self.language_code.clone(),
+            is_generated: self.is_generated,
+        }
+    }
+
+    /// Compiles the regular expression used by `search()` from a query and its options.
+    fn build_search_regex(
+        query: &str,
+        options: &TranscriptSearchOptions,
+    ) -> Result<Regex, regex::Error> {
+        let pattern = if options.regex {
+            query.to_string()
+        } else {
+            query
+                .split_whitespace()
+                .map(regex::escape)
+                .collect::<Vec<_>>()
+                .join(r"\s+")
+        };
+
+        let pattern = if options.whole_word {
+            format!(r"\b(?:{})\b", pattern)
+        } else {
+            pattern
+        };
+
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!options.case_sensitive)
+            .build()
+    }
+
+    /// Returns at most the last `count` characters of `text`.
+    fn tail_chars(text: &str, count: usize) -> String {
+        let skip = text.chars().count().saturating_sub(count);
+        text.chars().skip(skip).collect()
+    }
 }
 
 impl IntoIterator for FetchedTranscript {
@@ -410,6 +964,241 @@ mod tests {
         assert_eq!(segments[2].text, "of the transcript system");
     }
 
+    #[test]
+    fn test_snippet_at() {
+        let transcript = create_test_transcript();
+
+        assert_eq!(transcript.snippet_at(0.0).unwrap().text, "Hello world");
+        assert_eq!(transcript.snippet_at(3.4).unwrap().text, "Hello world");
+        assert_eq!(transcript.snippet_at(3.5).unwrap().text, "This is a test");
+        assert_eq!(
+            transcript.snippet_at(10.0).unwrap().text,
+            "of the transcript system"
+        );
+
+        // Before the first and after the last snippet
+        assert!(transcript.snippet_at(-1.0).is_none());
+        assert!(transcript.snippet_at(10.5).is_none());
+    }
+
+    #[test]
+    fn test_range() {
+        let transcript = create_test_transcript();
+
+        // Snippet already on screen at the window start is included
+        let parts = transcript.range(2.0, 6.0);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].text, "Hello world");
+        assert_eq!(parts[1].text, "This is a test");
+
+        let parts = transcript.range(3.5, 100.0);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].text, "This is a test");
+
+        assert!(transcript.range(20.0, 30.0).is_empty());
+        assert!(transcript.range(6.0, 2.0).is_empty());
+    }
+
+    #[test]
+    fn test_search_across_snippets() {
+        let transcript = create_test_transcript();
+
+        // "test of" spans the second and third snippets
+        let matches = transcript
+            .search("TEST OF", &TranscriptSearchOptions::default())
+            .unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].text, "test of");
+        assert_eq!(matches[0].first_snippet, 1);
+        assert_eq!(matches[0].last_snippet, 2);
+        assert_eq!(matches[0].start, 3.5);
+        assert_eq!(matches[0].end, 10.5);
+        assert_eq!(matches[0].context_before, "Hello world This is a ");
+        assert_eq!(matches[0].context_after, " the transcript system");
+    }
+
+    #[test]
+    fn test_search_options() {
+        let transcript = create_test_transcript();
+
+        // Case-sensitive search
+        let options = TranscriptSearchOptions {
+            case_sensitive: true,
+            ..Default::default()
+        };
+        assert!(transcript.search("hello", &options).unwrap().is_empty());
+        assert_eq!(transcript.search("Hello", &options).unwrap().len(), 1);
+
+        // Whole-word search does not match inside "This"
+        let options = TranscriptSearchOptions {
+            whole_word: true,
+            ..Default::default()
+        };
+        let matches = transcript.search("is", &options).unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].first_snippet, 1);
+        assert_eq!(
+            transcript
+                .search("is", &TranscriptSearchOptions::default())
+                .unwrap()
+                .len(),
+            2
+        );
+
+        // Regex search with limited context
+        let options = TranscriptSearchOptions {
+            regex: true,
+            context_chars: 3,
+            ..Default::default()
+        };
+        let matches = transcript.search(r"t\w+t", &options).unwrap();
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].text, "test");
+        assert_eq!(matches[0].context_before, " a ");
+        assert_eq!(matches[1].text, "transcript");
+        assert_eq!(matches[1].start, 6.3);
+
+        // Invalid regex is reported as an error
+        assert!(transcript.search("(", &options).is_err());
+    }
+
+    #[test]
+    fn test_shift() {
+        let transcript = create_test_transcript();
+
+        let shifted = transcript.shift(2.0);
+        assert_eq!(shifted.snippets.len(), 3);
+        assert_eq!(shifted.snippets[0].start, 2.0);
+        assert_eq!(shifted.snippets[2].start, 8.3);
+        assert_eq!(shifted.video_id, "test123");
+
+        // Negative offsets drop and trim snippets before zero
+        let shifted = transcript.shift(-5.0);
+        assert_eq!(shifted.snippets.len(), 2);
+        assert_eq!(shifted.snippets[0].text, "This is a test");
+        assert_eq!(shifted.snippets[0].start, 0.0);
+        assert!((shifted.snippets[0].duration - 1.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_scale() {
+        let transcript = create_test_transcript();
+
+        let scaled = transcript.scale(2.0).unwrap();
+        assert_eq!(scaled.snippets[1].start, 7.0);
+        assert_eq!(scaled.snippets[1].duration, 5.6);
+        assert_eq!(scaled.duration(), 21.0);
+    }
+
+    #[test]
+    fn test_scale_invalid_factor() {
+        let transcript = create_test_transcript();
+
+        assert!(transcript.scale(0.0).is_none());
+        assert!(transcript.scale(-1.0).is_none());
+        assert!(transcript.scale(f64::NAN).is_none());
+        assert!(transcript.scale(f64::INFINITY).is_none());
+    }
+
+    #[test]
+    fn test_clip() {
+        let transcript = create_test_transcript();
+
+        let clipped = transcript.clip(2.0, 7.0);
+        assert_eq!(clipped.snippets.len(), 3);
+        assert_eq!(clipped.snippets[0].start, 2.0);
+        assert_eq!(clipped.snippets[0].duration, 1.5);
+        assert_eq!(clipped.snippets[1].start, 3.5);
+        assert_eq!(clipped.snippets[1].duration, 2.8);
+        assert_eq!(clipped.snippets[2].start, 6.3);
+        assert!((clipped.snippets[2].duration - 0.7).abs() < 1e-9);
+
+        // Window boundaries falling exactly on snippet boundaries
+        let clipped = transcript.clip(3.5, 6.3);
+        assert_eq!(clipped.snippets.len(), 1);
+        assert_eq!(clipped.snippets[0].text, "This is a test");
+
+        assert!(transcript.clip(20.0, 30.0).snippets.is_empty());
+    }
+
+    #[test]
+    fn test_concat() {
+        let part1 = create_test_transcript();
+        let part2 = create_test_transcript();
+
+        let combined = part1.concat(&part2, part1.duration());
+        assert_eq!(combined.snippets.len(), 6);
+        assert_eq!(combined.snippets[3].text, "Hello world");
+        assert_eq!(combined.snippets[3].start, 10.5);
+        assert_eq!(combined.duration(), 21.0);
+    }
+
+    #[test]
+    fn test_fix_overlaps() {
+        let mut transcript = create_test_transcript();
+        transcript.snippets[0].duration = 5.0;
+        transcript.snippets[1].duration = 10.0;
+
+        let fixed = transcript.fix_overlaps();
+        assert_eq!(fixed.snippets[0].duration, 3.5);
+        assert!((fixed.snippets[1].duration - 2.8).abs() < 1e-9);
+        assert_eq!(fixed.snippets[2].duration, 4.2);
+    }
+
+    #[test]
+    fn test_split_by_chapters() {
+        let transcript = create_test_transcript();
+        let chapter = |title: &str, start: f64, end: f64| Chapter {
+            title: title.to_string(),
+            start,
+            end,
+        };
+        let chapters = vec![
+            chapter("Intro", 1.0, 3.5),
+            chapter("Empty", 3.5, 3.5),
+            chapter("Main", 3.5, 6.0),
+        ];
+
+        let parts = transcript.split_by_chapters(&chapters);
+
+        assert_eq!(parts.len(), 3);
+        // The snippet starting before the first chapter belongs to it
+        assert_eq!(parts[0].0.title, "Intro");
+        assert_eq!(parts[0].1.text(), "Hello world");
+        assert!(parts[1].1.snippets.is_empty());
+        // The snippet starting after the last chapter ends belongs to it
+        assert_eq!(parts[2].1.text(), "This is a test of the transcript system");
+        assert_eq!(parts[2].1.video_id, "test123");
+
+        assert!(transcript.split_by_chapters(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_most_replayed_text() {
+        let transcript = create_test_transcript();
+        let segment = |start: f64, end: f64, intensity: f64| HeatmapSegment {
+            start,
+            end,
+            intensity,
+        };
+        let heatmap = Heatmap {
+            segments: vec![
+                segment(0.0, 3.0, 0.2),
+                segment(3.0, 6.0, 1.0),
+                segment(6.0, 9.0, 0.6),
+            ],
+            key_moments: vec![],
+        };
+
+        let top = transcript.most_replayed_text(&heatmap, 2);
+
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].0.start, 3.0);
+        // The snippet already displayed at the start of the segment is included
+        assert_eq!(top[0].1, "Hello world This is a test");
+        assert_eq!(top[1].1, "This is a test of the transcript system");
+    }
+
     #[test]
     fn test_serialization() {
         let transcript = create_test_transcript();
@@ -0,0 +1,553 @@
+use std::path::Path;
+
+use reqwest::header::{CONTENT_RANGE, RANGE};
+use reqwest::{Client, StatusCode};
+use tokio::fs::OpenOptions;
+use tokio::io::AsyncWriteExt;
+
+use crate::errors::{CouldNotRetrieveTranscript, CouldNotRetrieveTranscriptReason};
+use crate::models::{DownloadProgress, StreamingFormat};
+
+/// # FormatDownloader
+///
+/// Downloads the stream of a `StreamingFormat` to a file.
+///
+/// YouTube throttles requests for a whole stream to roughly real-time speed, but
+/// serves byte ranges of up to about 10 MB at full speed, so the stream is
+/// requested in chunks with HTTP `Range` headers.
+///
+/// Downloads are resumable: when the file already exists, it is treated as the
+/// beginning of the stream and only the missing bytes are requested. A file larger
+/// than the stream is downloaded again from the start, unless the server reports
+/// that it already holds the whole stream.
+///
+/// The size of the stream is checked against the `content_length` of the format,
+/// both when the server reports it and once the download is complete.
+///
+/// Stream URLs expire after a few hours (see `StreamingData::expires_in_seconds`)
+/// and are usually bound to the IP address that fetched them, so the downloader
+/// should use the client of the API that fetched the streaming data, as
+/// `YouTubeTranscriptApi::download_format` does.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let api = YouTubeTranscriptApi::new(None, None, None)?;
+/// let streaming_data = api.fetch_streaming_data("dQw4w9WgXcQ").await?;
+/// let audio = streaming_data.best_audio().expect("No audio format");
+///
+/// let size = api
+///     .download_format("dQw4w9WgXcQ", audio, "audio.m4a", |progress| {
+///         if let Some(fraction) = progress.fraction() {
+///             println!("{:.0}%", fraction * 100.0);
+///         }
+///     })
+///     .await?;
+/// println!("Downloaded {} bytes", size);
+/// # Ok(())
+/// # }
+/// ```
+pub struct FormatDownloader {
+    /// HTTP client used for making requests to YouTube
+    client: Client,
+    /// Size of the byte ranges requested at once
+    chunk_size: u64,
+}
+
+impl FormatDownloader {
+    /// Default size of the requested byte ranges (10 MB)
+    pub const DEFAULT_CHUNK_SIZE: u64 = 10 * 1024 * 1024;
+
+    /// Creates a new downloader requesting chunks of `DEFAULT_CHUNK_SIZE` bytes.
+    ///
+    /// # Parameters
+    ///
+    /// * `client` - The HTTP client, configured with the proxy and cookies of the API
+    pub fn new(client: Client) -> Self {
+        Self::with_chunk_size(client, Self::DEFAULT_CHUNK_SIZE)
+    }
+
+    /// Creates a new downloader with a custom chunk size.
+    ///
+    /// # Parameters
+    ///
+    /// * `client` - The HTTP client, configured with the proxy and cookies of the API
+    /// * `chunk_size` - Size of the byte ranges requested at once (at least 1 byte)
+    pub fn with_chunk_size(client: Client, chunk_size: u64) -> Self {
+        Self {
+            client,
+            chunk_size: chunk_size.max(1),
+        }
+    }
+
+    /// Downloads the stream of a format to a file, resuming a partial download.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - The format to download; protected formats must have been deciphered
+    /// * `path` - The destination file
+    /// * `video_id` - The YouTube video ID (used for error reporting)
+    /// * `on_progress` - Called after each block of data written to the file
+    ///
+    /// # Returns
+    ///
+    /// * `Result<u64, CouldNotRetrieveTranscript>` - The size of the downloaded file, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YouTubeRequestFailed` error if a request fails or YouTube returns an
+    /// error status (usually 403 once the URL has expired), and a `DownloadFailed`
+    /// error if the format has no URL, the file cannot be written, the server sends
+    /// another range than the requested one, or the size of the stream does not
+    /// match the `content_length` of the format.
+    pub async fn download<F>(
+        &self,
+        format: &StreamingFormat,
+        path: impl AsRef<Path>,
+        video_id: &str,
+        mut on_progress: F,
+    ) -> Result<u64, CouldNotRetrieveTranscript>
+    where
+        F: FnMut(&DownloadProgress),
+    {
+        let path = path.as_ref();
+        let Some(url) = format.url.as_deref() else {
+            return Err(Self::failed(
+                video_id,
+                format!(
+                    "The format {} has no URL; its signature must be deciphered first",
+                    format.itag
+                ),
+            ));
+        };
+
+        let expected = format.parsed_content_length();
+        let mut resumed_from = tokio::fs::metadata(path)
+            .await
+            .map(|metadata| metadata.len())
+            .unwrap_or(0);
+        if expected.is_some_and(|expected| resumed_from > expected) {
+            resumed_from = 0;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resumed_from > 0)
+            .truncate(resumed_from == 0)
+            .open(path)
+            .await
+            .map_err(|e| {
+                Self::failed(video_id, format!("Cannot open {}: {}", path.display(), e))
+            })?;
+
+        let mut progress = DownloadProgress {
+            downloaded: resumed_from,
+            total: expected,
+            resumed_from,
+        };
+
+        while progress
+            .total
+            .is_none_or(|total| progress.downloaded < total)
+        {
+            let start = progress.downloaded;
+            let mut end = start + self.chunk_size - 1;
+            if let Some(total) = progress.total {
+                end = end.min(total - 1);
+            }
+
+            let mut response = self
+                .client
+                .get(url)
+                .header(RANGE, format!("bytes={}-{}", start, end))
+                .send()
+                .await
+                .map_err(|e| Self::request_failed(video_id, e.to_string()))?;
+
+            let content_range = response
+                .headers()
+                .get(CONTENT_RANGE)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string);
+            let range_total = content_range
+                .as_deref()
+                .and_then(Self::parse_content_range_total);
+
+            let whole_stream = match response.status() {
+                StatusCode::PARTIAL_CONTENT => {
+                    let range_start = content_range
+                        .as_deref()
+                        .and_then(Self::parse_content_range_start);
+                    if range_start != Some(start) {
+                        return Err(Self::failed(
+                            video_id,
+                            format!(
+                                "Requested bytes from {}, but the server sent {}",
+                                start,
+                                content_range.as_deref().unwrap_or("no Content-Range")
+                            ),
+                        ));
+                    }
+                    false
+                }
+                // The server ignored the range and sent the whole stream
+                StatusCode::OK if start == 0 => true,
+                StatusCode::RANGE_NOT_SATISFIABLE => {
+                    // Nothing left after `start`: the stream is complete
+                    if range_total == Some(start)
+                        && progress.total.is_none_or(|total| total == start)
+                    {
+                        progress.total = Some(start);
+                        break;
+                    }
+                    // The resumed file does not match the stream: start over
+                    if progress.resumed_from > 0 && start == progress.resumed_from {
+                        file.set_len(0).await.map_err(|e| {
+                            Self::failed(
+                                video_id,
+                                format!("Cannot truncate {}: {}", path.display(), e),
+                            )
+                        })?;
+                        progress.downloaded = 0;
+                        progress.resumed_from = 0;
+                        continue;
+                    }
+                    // The end of a stream of unknown size was reached
+                    if progress.total.is_none()
+                        && range_total.is_none()
+                        && start > progress.resumed_from
+                    {
+                        break;
+                    }
+                    return Err(Self::request_failed(
+                        video_id,
+                        format!("Download request returned status: {}", response.status()),
+                    ));
+                }
+                status => {
+                    return Err(Self::request_failed(
+                        video_id,
+                        format!("Download request returned status: {}", status),
+                    ))
+                }
+            };
+
+            let reported_total = if whole_stream {
+                response.content_length()
+            } else {
+                range_total
+            };
+            match (progress.total, reported_total) {
+                (Some(total), Some(reported)) if total != reported => {
+                    return Err(Self::failed(
+                        video_id,
+                        format!(
+                            "The stream has {} bytes, but the format announced {}",
+                            reported, total
+                        ),
+                    ));
+                }
+                (None, Some(reported)) => progress.total = Some(reported),
+                _ => {}
+            }
+
+            let chunk_start = progress.downloaded;
+            while let Some(bytes) = response
+                .chunk()
+                .await
+                .map_err(|e| Self::request_failed(video_id, e.to_string()))?
+            {
+                file.write_all(&bytes).await.map_err(|e| {
+                    Self::failed(video_id, format!("Cannot write {}: {}", path.display(), e))
+                })?;
+                progress.downloaded += bytes.len() as u64;
+                on_progress(&progress);
+            }
+
+            if whole_stream {
+                progress.total.get_or_insert(progress.downloaded);
+                break;
+            }
+            if progress.downloaded == chunk_start {
+                // An empty chunk would loop forever
+                break;
+            }
+        }
+
+        file.flush().await.map_err(|e| {
+            Self::failed(video_id, format!("Cannot write {}: {}", path.display(), e))
+        })?;
+
+        if let Some(total) = progress.total {
+            if progress.downloaded != total {
+                return Err(Self::failed(
+                    video_id,
+                    format!(
+                        "Downloaded {} bytes, expected {}",
+                        progress.downloaded, total
+                    ),
+                ));
+            }
+        }
+
+        Ok(progress.downloaded)
+    }
+
+    /// Returns the first byte of a `Content-Range` header (`bytes 0-9/25`).
+    fn parse_content_range_start(content_range: &str) -> Option<u64> {
+        let range = content_range.trim().strip_prefix("bytes")?.trim_start();
+        range.split_once('-')?.0.trim().parse().ok()
+    }
+
+    /// Returns the complete length of a `Content-Range` header (`bytes 0-9/25`, `bytes */25`).
+    fn parse_content_range_total(content_range: &str) -> Option<u64> {
+        content_range.rsplit_once('/')?.1.trim().parse().ok()
+    }
+
+    fn request_failed(video_id: &str, message: String) -> CouldNotRetrieveTranscript {
+        CouldNotRetrieveTranscript {
+            video_id: video_id.to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::YouTubeRequestFailed(
+                message,
+            )),
+        }
+    }
+
+    fn failed(video_id: &str, message: String) -> CouldNotRetrieveTranscript {
+        CouldNotRetrieveTranscript {
+            video_id: video_id.to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::DownloadFailed(message)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming_data_extractor::StreamingDataExtractor;
+    use serde_json::json;
+
+    const STREAM: &[u8] = b"0123456789abcdefghijklmno";
+
+    fn format(url: &str, content_length: Option<usize>) -> StreamingFormat {
+        let mut format = json!({
+            "itag": 140, "url": url, "mimeType": "audio/mp4; codecs=\"mp4a.40.2\"",
+            "bitrate": 130000, "quality": "tiny", "projectionType": "RECTANGULAR",
+            "approxDurationMs": "212091"
+        });
+        if let Some(length) = content_length {
+            format["contentLength"] = json!(length.to_string());
+        }
+        let player_response = json!({"streamingData": {"adaptiveFormats": [format]}});
+
+        StreamingDataExtractor::extract_streaming_data(&player_response, "test")
+            .unwrap()
+            .adaptive_formats
+            .remove(0)
+    }
+
+    async fn mock_range(
+        server: &mut mockito::ServerGuard,
+        start: usize,
+        end: usize,
+    ) -> mockito::Mock {
+        server
+            .mock("GET", "/videoplayback")
+            .match_header("range", format!("bytes={}-{}", start, end).as_str())
+            .with_status(206)
+            .with_header(
+                "content-range",
+                &format!("bytes {}-{}/{}", start, end, STREAM.len()),
+            )
+            .with_body(&STREAM[start..=end])
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_download_in_chunks() {
+        let mut server = mockito::Server::new_async().await;
+        let mocks = vec![
+            mock_range(&mut server, 0, 9).await,
+            mock_range(&mut server, 10, 19).await,
+            mock_range(&mut server, 20, 24).await,
+        ];
+
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("audio.m4a");
+        let format = format(&format!("{}/videoplayback", server.url()), Some(25));
+
+        let mut reports = Vec::new();
+        let size = FormatDownloader::with_chunk_size(Client::new(), 10)
+            .download(&format, &path, "test", |progress| reports.push(*progress))
+            .await
+            .unwrap();
+
+        assert_eq!(size, 25);
+        assert_eq!(std::fs::read(&path).unwrap(), STREAM);
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+
+        let last = reports.last().unwrap();
+        assert_eq!(last.downloaded, 25);
+        assert_eq!(last.total, Some(25));
+        assert_eq!(last.fraction(), Some(1.0));
+    }
+
+    #[tokio::test]
+    async fn test_resume_partial_file() {
+        let mut server = mockito::Server::new_async().await;
+        let mocks = vec![
+            mock_range(&mut server, 12, 21).await,
+            mock_range(&mut server, 22, 24).await,
+        ];
+
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("audio.m4a");
+        std::fs::write(&path, &STREAM[..12]).unwrap();
+
+        // Without content length, the size comes from the Content-Range header
+        let format = format(&format!("{}/videoplayback", server.url()), None);
+        let mut first = None;
+        let size = FormatDownloader::with_chunk_size(Client::new(), 10)
+            .download(&format, &path, "test", |progress| {
+                first.get_or_insert(*progress);
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(size, 25);
+        assert_eq!(std::fs::read(&path).unwrap(), STREAM);
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+        assert_eq!(first.unwrap().resumed_from, 12);
+        assert_eq!(first.unwrap().total, Some(25));
+    }
+
+    #[tokio::test]
+    async fn test_resume_complete_file() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/videoplayback")
+            .match_header("range", "bytes=25-34")
+            .with_status(416)
+            .with_header("content-range", "bytes */25")
+            .create_async()
+            .await;
+
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("audio.m4a");
+        std::fs::write(&path, STREAM).unwrap();
+
+        let format = format(&format!("{}/videoplayback", server.url()), None);
+        let size = FormatDownloader::with_chunk_size(Client::new(), 10)
+            .download(&format, &path, "test", |_| {})
+            .await
+            .unwrap();
+
+        assert_eq!(size, 25);
+        assert_eq!(std::fs::read(&path).unwrap(), STREAM);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_resume_restarts_mismatched_file() {
+        let mut server = mockito::Server::new_async().await;
+        let not_satisfiable = server
+            .mock("GET", "/videoplayback")
+            .match_header("range", "bytes=30-39")
+            .with_status(416)
+            .create_async()
+            .await;
+        let mocks = vec![
+            mock_range(&mut server, 0, 9).await,
+            mock_range(&mut server, 10, 19).await,
+            mock_range(&mut server, 20, 24).await,
+        ];
+
+        // A leftover file of another, larger stream
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("audio.m4a");
+        std::fs::write(&path, [b'x'; 30]).unwrap();
+
+        let format = format(&format!("{}/videoplayback", server.url()), None);
+        let size = FormatDownloader::with_chunk_size(Client::new(), 10)
+            .download(&format, &path, "test", |_| {})
+            .await
+            .unwrap();
+
+        assert_eq!(size, 25);
+        assert_eq!(std::fs::read(&path).unwrap(), STREAM);
+        not_satisfiable.assert_async().await;
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_content_range_start_mismatch() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/videoplayback")
+            .match_header("range", "bytes=0-9")
+            .with_status(206)
+            .with_header("content-range", "bytes 5-14/25")
+            .with_body(&STREAM[5..15])
+            .create_async()
+            .await;
+
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("audio.m4a");
+        let format = format(&format!("{}/videoplayback", server.url()), Some(25));
+
+        let error = FormatDownloader::with_chunk_size(Client::new(), 10)
+            .download(&format, &path, "test", |_| {})
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.reason,
+            Some(CouldNotRetrieveTranscriptReason::DownloadFailed(_))
+        ));
+        assert!(error.to_string().contains("bytes 5-14/25"));
+    }
+
+    #[tokio::test]
+    async fn test_content_length_mismatch() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/videoplayback")
+            .match_header("range", "bytes=0-29")
+            .with_status(206)
+            .with_header("content-range", "bytes 0-24/25")
+            .with_body(STREAM)
+            .create_async()
+            .await;
+
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("audio.m4a");
+        let format = format(&format!("{}/videoplayback", server.url()), Some(30));
+
+        let error = FormatDownloader::with_chunk_size(Client::new(), 100)
+            .download(&format, &path, "test", |_| {})
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.reason,
+            Some(CouldNotRetrieveTranscriptReason::DownloadFailed(_))
+        ));
+
+        // Formats without URL cannot be downloaded
+        let mut protected = format.clone();
+        protected.url = None;
+        let error = FormatDownloader::new(Client::new())
+            .download(&protected, &path, "test", |_| {})
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("deciphered"));
+    }
+}
@@ -0,0 +1,370 @@
+use std::cmp::Reverse;
+
+use crate::models::{AudioQuality, CodecFamily, StreamingFormat};
+
+/// # FormatSelector
+///
+/// A query over the streaming formats of a video, created by `StreamingData::select()`.
+///
+/// Each filter keeps the formats matching it, and the query ends with one of
+/// `formats()`, `sorted()`, `best()` or `smallest()`.
+///
+/// Formats are ranked by height, then frame rate, then audio quality, then by
+/// whether they avoid dynamic range compression, and finally by bitrate. HDR is
+/// not preferred over SDR: use `hdr(true)` to ask for it.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+/// # use yt_transcript_rs::models::AudioQuality;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let api = YouTubeTranscriptApi::new(None, None, None)?;
+/// let streaming_data = api.fetch_streaming_data("dQw4w9WgXcQ").await?;
+///
+/// // All opus tracks of at least medium quality, best first
+/// for format in streaming_data
+///     .select()
+///     .audio_only()
+///     .codec("opus")
+///     .min_audio_quality(AudioQuality::Medium)
+///     .sorted()
+/// {
+///     println!("{} {:?} bps", format.itag, format.bitrate);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FormatSelector<'a> {
+    formats: Vec<&'a StreamingFormat>,
+}
+
+impl<'a> FormatSelector<'a> {
+    /// Creates a query over the given formats.
+    pub fn new(formats: impl IntoIterator<Item = &'a StreamingFormat>) -> Self {
+        Self {
+            formats: formats.into_iter().collect(),
+        }
+    }
+
+    /// Keeps the formats matching a custom predicate.
+    pub fn filter(mut self, predicate: impl Fn(&StreamingFormat) -> bool) -> Self {
+        self.formats.retain(|format| predicate(format));
+        self
+    }
+
+    /// Keeps the formats with both video and audio.
+    pub fn muxed(self) -> Self {
+        self.filter(StreamingFormat::is_muxed)
+    }
+
+    /// Keeps the formats with video and no audio.
+    pub fn video_only(self) -> Self {
+        self.filter(|format| format.has_video() && !format.has_audio())
+    }
+
+    /// Keeps the formats with audio and no video.
+    pub fn audio_only(self) -> Self {
+        self.filter(|format| format.has_audio() && !format.has_video())
+    }
+
+    /// Keeps the formats in the given container (e.g., "mp4", "webm").
+    pub fn container(self, container: &str) -> Self {
+        self.filter(|format| {
+            format
+                .media_type()
+                .is_some_and(|media_type| media_type.container.eq_ignore_ascii_case(container))
+        })
+    }
+
+    /// Keeps the formats with a codec starting with `codec` (e.g., "avc1", "vp09", "opus").
+    ///
+    /// The comparison ignores case, and "vp9" also matches the "vp09" codec strings.
+    pub fn codec(self, codec: &str) -> Self {
+        let codec = codec.to_ascii_lowercase();
+        self.filter(|format| {
+            format.media_type().is_some_and(|media_type| {
+                media_type.codecs.iter().any(|c| {
+                    let identifier = c.identifier.to_ascii_lowercase();
+                    identifier.starts_with(&codec)
+                        || (codec == "vp9" && c.family == CodecFamily::Vp9)
+                })
+            })
+        })
+    }
+
+    /// Keeps the formats with a codec of the given family.
+    pub fn codec_family(self, family: CodecFamily) -> Self {
+        self.filter(|format| {
+            format
+                .media_type()
+                .is_some_and(|media_type| media_type.has_codec(family))
+        })
+    }
+
+    /// Keeps the formats with a video bit depth of at least `bit_depth`.
+    pub fn min_bit_depth(self, bit_depth: u8) -> Self {
+        self.filter(|format| {
+            format
+                .media_type()
+                .and_then(|media_type| media_type.video_codec()?.bit_depth)
+                .is_some_and(|depth| depth >= bit_depth)
+        })
+    }
+
+    /// Keeps the formats with a height of at least `height` pixels.
+    pub fn min_height(self, height: u32) -> Self {
+        self.filter(|format| format.height.is_some_and(|h| h >= height))
+    }
+
+    /// Keeps the formats with a height of at most `height` pixels.
+    pub fn max_height(self, height: u32) -> Self {
+        self.filter(|format| format.height.is_some_and(|h| h <= height))
+    }
+
+    /// Keeps the formats with a frame rate of at least `fps`.
+    pub fn min_fps(self, fps: u32) -> Self {
+        self.filter(|format| format.fps.is_some_and(|f| f >= fps))
+    }
+
+    /// Keeps the formats with a frame rate of at most `fps`.
+    pub fn max_fps(self, fps: u32) -> Self {
+        self.filter(|format| format.fps.is_some_and(|f| f <= fps))
+    }
+
+    /// Keeps the formats with a bitrate of at least `bitrate` bits per second.
+    pub fn min_bitrate(self, bitrate: u64) -> Self {
+        self.filter(|format| format.bitrate.is_some_and(|b| b >= bitrate))
+    }
+
+    /// Keeps the formats with a bitrate of at most `bitrate` bits per second.
+    pub fn max_bitrate(self, bitrate: u64) -> Self {
+        self.filter(|format| format.bitrate.is_some_and(|b| b <= bitrate))
+    }
+
+    /// Keeps the formats with an audio quality of at least `quality`.
+    pub fn min_audio_quality(self, quality: AudioQuality) -> Self {
+        self.filter(|format| format.parsed_audio_quality().is_some_and(|q| q >= quality))
+    }
+
+    /// Keeps the HDR formats if `hdr` is true, the SDR formats otherwise.
+    pub fn hdr(self, hdr: bool) -> Self {
+        self.filter(|format| format.is_hdr() == hdr)
+    }
+
+    /// Keeps the formats with dynamic range compression if `drc` is true, the others otherwise.
+    pub fn drc(self, drc: bool) -> Self {
+        self.filter(|format| format.is_drc() == drc)
+    }
+
+    /// Returns the matching formats, in their original order.
+    pub fn formats(self) -> Vec<&'a StreamingFormat> {
+        self.formats
+    }
+
+    /// Returns the matching formats, best first.
+    pub fn sorted(mut self) -> Vec<&'a StreamingFormat> {
+        self.formats
+            .sort_by_key(|format| Reverse(Self::quality_key(format)));
+        self.formats
+    }
+
+    /// Returns the best matching format.
+    pub fn best(self) -> Option<&'a StreamingFormat> {
+        self.formats
+            .into_iter()
+            .max_by_key(|format| Self::quality_key(format))
+    }
+
+    /// Returns the matching format with the smallest size.
+    ///
+    /// The size is `content_length` when available, and is otherwise estimated
+    /// from the bitrate and the duration.
+    pub fn smallest(self) -> Option<&'a StreamingFormat> {
+        self.formats
+            .into_iter()
+            .min_by_key(|format| (Self::size(format), format.bitrate))
+    }
+
+    /// Returns the number of matching formats.
+    pub fn count(&self) -> usize {
+        self.formats.len()
+    }
+
+    /// Returns whether no format matches.
+    pub fn is_empty(&self) -> bool {
+        self.formats.is_empty()
+    }
+
+    fn quality_key(format: &StreamingFormat) -> (u32, u32, Option<AudioQuality>, bool, u64) {
+        (
+            format.height.unwrap_or(0),
+            format.fps.unwrap_or(0),
+            format.parsed_audio_quality(),
+            !format.is_drc(),
+            format.average_bitrate.or(format.bitrate).unwrap_or(0),
+        )
+    }
+
+    fn size(format: &StreamingFormat) -> u64 {
+        format.parsed_content_length().unwrap_or_else(|| {
+            let duration_ms: u64 = format
+                .approx_duration_ms
+                .as_deref()
+                .and_then(|duration| duration.parse().ok())
+                .unwrap_or(0);
+            format.bitrate.unwrap_or(0).saturating_mul(duration_ms) / 8000
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::StreamingData;
+    use crate::streaming_data_extractor::StreamingDataExtractor;
+    use serde_json::{json, Value};
+
+    fn format(itag: u32, mime_type: &str, bitrate: u64, extra: Value) -> Value {
+        let mut format = json!({
+            "itag": itag,
+            "mimeType": mime_type,
+            "bitrate": bitrate,
+            "quality": "medium",
+            "projectionType": "RECTANGULAR",
+            "approxDurationMs": "100000"
+        });
+        format
+            .as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        format
+    }
+
+    fn streaming_data() -> StreamingData {
+        let player_response = json!({"streamingData": {
+            "expiresInSeconds": "21540",
+            "formats": [
+                format(18, "video/mp4; codecs=\"avc1.42001E, mp4a.40.2\"", 500_000,
+                    json!({"width": 640, "height": 360, "fps": 30, "audioQuality": "AUDIO_QUALITY_LOW", "contentLength": "6000000"})),
+                format(22, "video/mp4; codecs=\"avc1.64001F, mp4a.40.2\"", 1_200_000,
+                    json!({"width": 1280, "height": 720, "fps": 30, "audioQuality": "AUDIO_QUALITY_MEDIUM"}))
+            ],
+            "adaptiveFormats": [
+                format(137, "video/mp4; codecs=\"avc1.640028\"", 4_000_000,
+                    json!({"width": 1920, "height": 1080, "fps": 30})),
+                format(136, "video/mp4; codecs=\"avc1.4d401f\"", 2_000_000,
+                    json!({"width": 1280, "height": 720, "fps": 30})),
+                format(298, "video/mp4; codecs=\"avc1.4d4020\"", 3_000_000,
+                    json!({"width": 1280, "height": 720, "fps": 60})),
+                format(337, "video/webm; codecs=\"vp09.02.51.10.01.09.16.09.00\"", 20_000_000,
+                    json!({"width": 3840, "height": 2160, "fps": 60, "colorInfo": {
+                        "primaries": "COLOR_PRIMARIES_BT2020",
+                        "transferCharacteristics": "COLOR_TRANSFER_CHARACTERISTICS_SMPTEST2084"
+                    }})),
+                format(140, "audio/mp4; codecs=\"mp4a.40.2\"", 130_000,
+                    json!({"audioQuality": "AUDIO_QUALITY_MEDIUM", "audioChannels": 2})),
+                format(251, "audio/webm; codecs=\"opus\"", 140_000,
+                    json!({"audioQuality": "AUDIO_QUALITY_MEDIUM", "audioChannels": 2})),
+                format(251, "audio/webm; codecs=\"opus\"", 150_000,
+                    json!({"audioQuality": "AUDIO_QUALITY_MEDIUM", "audioChannels": 2, "isDrc": true})),
+                format(249, "audio/webm; codecs=\"opus\"", 50_000,
+                    json!({"audioQuality": "AUDIO_QUALITY_LOW", "audioChannels": 2}))
+            ]
+        }});
+
+        StreamingDataExtractor::extract_streaming_data(&player_response, "test").unwrap()
+    }
+
+    #[test]
+    fn test_presets() {
+        let data = streaming_data();
+
+        // Highest resolution, HDR included since it is the only 2160p format
+        assert_eq!(data.best_video().unwrap().itag, 337);
+        assert_eq!(data.best_video_under(1080).unwrap().itag, 137);
+        // 60 fps ranks above 30 fps at the same height
+        assert_eq!(data.best_video_under(720).unwrap().itag, 298);
+        // Same quality: the non-DRC track wins despite its lower bitrate
+        let audio = data.best_audio().unwrap();
+        assert_eq!((audio.itag, audio.is_drc()), (251, false));
+
+        assert_eq!(data.best_muxed().unwrap().itag, 22);
+        // 22 has no content length: 1.2 Mbps over 100 s is estimated at 15 MB
+        assert_eq!(data.smallest_muxed().unwrap().itag, 18);
+    }
+
+    #[test]
+    fn test_filters() {
+        let data = streaming_data();
+
+        let itags = |selector: FormatSelector| -> Vec<u32> {
+            selector.sorted().iter().map(|f| f.itag).collect()
+        };
+
+        assert_eq!(
+            itags(data.select().video_only().container("mp4").codec("avc1")),
+            vec![137, 298, 136]
+        );
+        assert_eq!(itags(data.select().codec("vp9").hdr(true)), vec![337]);
+        assert_eq!(
+            itags(data.select().hdr(false).min_height(720).max_fps(30)),
+            vec![137, 22, 136]
+        );
+        assert_eq!(
+            itags(data.select().audio_only().drc(false).max_bitrate(140_000)),
+            vec![251, 140, 249]
+        );
+        assert_eq!(
+            itags(
+                data.select()
+                    .min_audio_quality(AudioQuality::Medium)
+                    .muxed()
+            ),
+            vec![22]
+        );
+        assert_eq!(
+            itags(
+                data.select()
+                    .codec_family(CodecFamily::Avc)
+                    .min_height(1080)
+            ),
+            vec![137]
+        );
+        assert_eq!(itags(data.select().min_bit_depth(10)), vec![337]);
+        assert_eq!(
+            itags(data.select().codec_family(CodecFamily::Aac).audio_only()),
+            vec![140]
+        );
+        assert!(data.select().container("3gpp").is_empty());
+        assert_eq!(data.select().codec("OPUS").count(), 3);
+    }
+
+    #[test]
+    fn test_mime_type_case() {
+        let player_response = json!({"streamingData": {"adaptiveFormats": [
+            format(136, "Video/MP4; Codecs=\"avc1.4d401f\"", 2_000_000, json!({"height": 720})),
+            format(140, "AUDIO/mp4; CODECS=\"mp4a.40.2\"", 130_000, json!({})),
+            format(18, "video/mp4; codecs=\"avc1.42001E, mp4a.40.2\"", 500_000, json!({}))
+        ]}});
+        let data =
+            StreamingDataExtractor::extract_streaming_data(&player_response, "test").unwrap();
+
+        let video = &data.adaptive_formats[0];
+        assert!(video.has_video() && !video.has_audio());
+        assert_eq!(video.container().as_deref(), Some("mp4"));
+        assert_eq!(video.codecs(), ["avc1.4d401f"]);
+
+        let itags = |selector: FormatSelector| -> Vec<u32> {
+            selector.formats().iter().map(|f| f.itag).collect()
+        };
+        assert_eq!(itags(data.select().video_only().codec("avc1")), vec![136]);
+        assert_eq!(
+            itags(data.select().audio_only().container("MP4")),
+            vec![140]
+        );
+        // Muxed formats are recognized by their codec list, without audio fields
+        assert_eq!(itags(data.select().muxed()), vec![18]);
+    }
+}
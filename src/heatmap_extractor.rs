@@ -0,0 +1,276 @@
+use serde_json::Value;
+
+use crate::json_utils::{find_all, find_first, text_field};
+use crate::models::{Heatmap, HeatmapSegment, KeyMoment};
+
+/// # HeatmapExtractor
+///
+/// Extracts the "most replayed" heatmap of a video from `ytInitialData`.
+///
+/// Current pages send the heatmap as a `macroMarkersListEntity` entity mutation of
+/// type `MARKER_TYPE_HEATMAP`; older pages put it in the player bar markers map
+/// under the `HEATSEEKER` key. Both carry the segments with their normalized
+/// intensity, and decorations labelling moments such as the "Most replayed" peak.
+///
+/// The key moments that YouTube marks on the progress bar of some videos are sent
+/// as a separate `macroMarkersListEntity` of type `MARKER_TYPE_TIMESTAMPS`, and are
+/// added to the key moments of the heatmap.
+pub struct HeatmapExtractor;
+
+impl HeatmapExtractor {
+    /// Extracts the heatmap from the `ytInitialData` JSON.
+    ///
+    /// # Parameters
+    ///
+    /// * `initial_data` - The parsed `ytInitialData` JSON object
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Heatmap>` - The heatmap, or `None` if the video has none
+    pub fn extract_heatmap(initial_data: &Value) -> Option<Heatmap> {
+        let heatmap = Self::extract_from_entities(initial_data)
+            .or_else(|| Self::extract_from_markers_map(initial_data))?;
+
+        if heatmap.segments.is_empty() {
+            None
+        } else {
+            Some(heatmap)
+        }
+    }
+
+    /// Extracts the heatmap from the `macroMarkersListEntity` mutations.
+    fn extract_from_entities(initial_data: &Value) -> Option<Heatmap> {
+        let markers_list = Self::markers_list(initial_data, "MARKER_TYPE_HEATMAP")?;
+
+        let segments = markers_list
+            .get("markers")
+            .and_then(|m| m.as_array())
+            .map(|markers| {
+                markers
+                    .iter()
+                    .filter_map(|marker| {
+                        Self::segment(
+                            marker.get("startMillis")?,
+                            marker.get("durationMillis")?,
+                            marker.get("intensityScoreNormalized")?,
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut key_moments = markers_list
+            .get("markersDecoration")
+            .map(Self::key_moments)
+            .unwrap_or_default();
+        if let Some(timestamps) = Self::markers_list(initial_data, "MARKER_TYPE_TIMESTAMPS") {
+            key_moments.extend(Self::timestamp_moments(timestamps));
+        }
+
+        Some(Self::build(segments, key_moments))
+    }
+
+    /// Returns the `markersList` of the `macroMarkersListEntity` of a marker type.
+    fn markers_list<'a>(initial_data: &'a Value, marker_type: &str) -> Option<&'a Value> {
+        find_all(initial_data, "macroMarkersListEntity")
+            .into_iter()
+            .filter_map(|entity| entity.get("markersList"))
+            .find(|list| list.get("markerType").and_then(|t| t.as_str()) == Some(marker_type))
+    }
+
+    /// Extracts the key moments of a `MARKER_TYPE_TIMESTAMPS` markers list.
+    fn timestamp_moments(markers_list: &Value) -> Vec<KeyMoment> {
+        markers_list
+            .get("markers")
+            .and_then(|m| m.as_array())
+            .map(|markers| {
+                markers
+                    .iter()
+                    .filter_map(|marker| {
+                        let start = Self::seconds(marker.get("startMillis")?)?;
+                        let duration = marker
+                            .get("durationMillis")
+                            .and_then(Self::seconds)
+                            .unwrap_or(0.0);
+
+                        Some(KeyMoment {
+                            label: text_field(marker, "title")?,
+                            start,
+                            end: start + duration,
+                            peak: start,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Extracts the heatmap from the legacy `HEATSEEKER` entry of the markers map.
+    fn extract_from_markers_map(initial_data: &Value) -> Option<Heatmap> {
+        let heatseeker = find_first(initial_data, "markersMap")?
+            .as_array()?
+            .iter()
+            .find(|entry| entry.get("key").and_then(|k| k.as_str()) == Some("HEATSEEKER"))?
+            .get("value")?;
+
+        let segments = find_all(heatseeker, "heatMarkerRenderer")
+            .into_iter()
+            .filter_map(|marker| {
+                Self::segment(
+                    marker.get("timeRangeStartMillis")?,
+                    marker.get("markerDurationMillis")?,
+                    marker.get("heatMarkerIntensityScoreNormalized")?,
+                )
+            })
+            .collect();
+
+        Some(Self::build(segments, Self::key_moments(heatseeker)))
+    }
+
+    /// Extracts the labelled moments of the heatmap decorations.
+    fn key_moments(decorations: &Value) -> Vec<KeyMoment> {
+        let mut renderers = find_all(decorations, "timedMarkerDecorations")
+            .into_iter()
+            .filter_map(|d| d.as_array())
+            .flatten()
+            .collect::<Vec<_>>();
+        renderers.extend(find_all(decorations, "timedMarkerDecorationRenderer"));
+
+        renderers
+            .into_iter()
+            .filter_map(|decoration| {
+                let start = Self::seconds(decoration.get("visibleTimeRangeStartMillis")?)?;
+                let end = Self::seconds(decoration.get("visibleTimeRangeEndMillis")?)?;
+                let peak = decoration
+                    .get("decorationTimeMillis")
+                    .and_then(Self::seconds)
+                    .unwrap_or(start);
+
+                Some(KeyMoment {
+                    label: text_field(decoration, "label").unwrap_or_default(),
+                    start,
+                    end,
+                    peak,
+                })
+            })
+            .collect()
+    }
+
+    fn build(mut segments: Vec<HeatmapSegment>, mut key_moments: Vec<KeyMoment>) -> Heatmap {
+        segments.sort_by(|a, b| a.start.total_cmp(&b.start));
+        key_moments.sort_by(|a, b| a.start.total_cmp(&b.start));
+        Heatmap {
+            segments,
+            key_moments,
+        }
+    }
+
+    fn segment(start: &Value, duration: &Value, intensity: &Value) -> Option<HeatmapSegment> {
+        let start = Self::seconds(start)?;
+        let duration = Self::seconds(duration)?;
+        Some(HeatmapSegment {
+            start,
+            end: start + duration,
+            intensity: intensity.as_f64()?.clamp(0.0, 1.0),
+        })
+    }
+
+    /// Converts milliseconds, sent either as a number or as a string, to seconds.
+    fn seconds(millis: &Value) -> Option<f64> {
+        let millis = match millis {
+            Value::String(s) => s.parse().ok()?,
+            other => other.as_f64()?,
+        };
+        Some(millis / 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_extract_from_entities() {
+        let initial_data = json!({
+            "frameworkUpdates": {"entityBatchUpdate": {"mutations": [
+                {"payload": {"macroMarkersListEntity": {"markersList": {
+                    "markerType": "MARKER_TYPE_TIMESTAMPS",
+                    "markers": [
+                        {"startMillis": "0", "durationMillis": "1000", "title": "Intro"},
+                        {"startMillis": "5000", "durationMillis": "3000", "title": {"simpleText": "The answer"}},
+                        {"startMillis": "9000", "durationMillis": "1000"}
+                    ]
+                }}}},
+                {"payload": {"macroMarkersListEntity": {"markersList": {
+                    "markerType": "MARKER_TYPE_HEATMAP",
+                    "markers": [
+                        {"startMillis": "2000", "durationMillis": "2000", "intensityScoreNormalized": 1},
+                        {"startMillis": "0", "durationMillis": "2000", "intensityScoreNormalized": 0.25}
+                    ],
+                    "markersDecoration": {"timedMarkerDecorations": [{
+                        "visibleTimeRangeStartMillis": 1500,
+                        "visibleTimeRangeEndMillis": 4000,
+                        "decorationTimeMillis": 3000,
+                        "label": {"runs": [{"text": "Most replayed"}]}
+                    }]}
+                }}}}
+            ]}}
+        });
+
+        let heatmap = HeatmapExtractor::extract_heatmap(&initial_data).unwrap();
+        assert_eq!(heatmap.segments.len(), 2);
+        assert_eq!(heatmap.segments[0].start, 0.0);
+        assert_eq!(heatmap.segments[1].end, 4.0);
+        assert_eq!(heatmap.intensity_at(3.0), Some(1.0));
+        assert_eq!(heatmap.most_replayed(1)[0].start, 2.0);
+
+        // Heatmap decorations and key moment markers, in chronological order; markers
+        // without title are ignored
+        let moment = |label: &str, start: f64, end: f64, peak: f64| KeyMoment {
+            label: label.to_string(),
+            start,
+            end,
+            peak,
+        };
+        assert_eq!(
+            heatmap.key_moments,
+            vec![
+                moment("Intro", 0.0, 1.0, 0.0),
+                moment("Most replayed", 1.5, 4.0, 3.0),
+                moment("The answer", 5.0, 8.0, 5.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_from_markers_map() {
+        let initial_data = json!({
+            "playerOverlays": {"playerOverlayRenderer": {"decoratedPlayerBarRenderer": {
+                "decoratedPlayerBarRenderer": {"playerBar": {"multiMarkersPlayerBarRenderer": {
+                    "markersMap": [{"key": "HEATSEEKER", "value": {"heatmap": {"heatmapRenderer": {
+                        "heatMarkers": [
+                            {"heatMarkerRenderer": {
+                                "timeRangeStartMillis": 0,
+                                "markerDurationMillis": 5000,
+                                "heatMarkerIntensityScoreNormalized": 0.8
+                            }}
+                        ],
+                        "heatMarkersDecorations": [{"timedMarkerDecorationRenderer": {
+                            "visibleTimeRangeStartMillis": 0,
+                            "visibleTimeRangeEndMillis": 5000,
+                            "label": {"runs": [{"text": "Most replayed"}]}
+                        }}]
+                    }}}}]
+                }}}
+            }}}
+        });
+
+        let heatmap = HeatmapExtractor::extract_heatmap(&initial_data).unwrap();
+        assert_eq!(heatmap.segments[0].end, 5.0);
+        assert_eq!(heatmap.segments[0].intensity, 0.8);
+        assert_eq!(heatmap.key_moments[0].peak, 0.0);
+
+        assert!(HeatmapExtractor::extract_heatmap(&json!({"contents": {}})).is_none());
+    }
+}
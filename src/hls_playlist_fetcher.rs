@@ -0,0 +1,141 @@
+use reqwest::Client;
+
+use crate::errors::{CouldNotRetrieveTranscript, CouldNotRetrieveTranscriptReason};
+use crate::hls_playlist_parser::HlsPlaylistParser;
+use crate::models::{HlsMasterPlaylist, HlsMediaPlaylist};
+
+/// # HlsPlaylistFetcher
+///
+/// Downloads and parses the HLS playlists of live streams.
+///
+/// The master playlist URL comes from `StreamingData::hls_manifest_url`, and the
+/// media playlist URLs from its variants. The media playlist of a live stream only
+/// lists the latest segments, so it has to be fetched again to follow the stream.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// # use reqwest::Client;
+/// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+/// # use yt_transcript_rs::hls_playlist_fetcher::HlsPlaylistFetcher;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let api = YouTubeTranscriptApi::new(None, None, None)?;
+/// let streaming_data = api.fetch_streaming_data("jfKfPfyJRdk").await?;
+/// let manifest_url = streaming_data.hls_manifest_url.expect("Not a live stream");
+///
+/// let fetcher = HlsPlaylistFetcher::new(Client::new());
+/// let master = fetcher.fetch_master_playlist(&manifest_url, "jfKfPfyJRdk").await?;
+/// let variant = master.best_under(720).expect("No variant");
+/// let media = fetcher.fetch_media_playlist(&variant.url, "jfKfPfyJRdk").await?;
+/// println!("{} segments", media.segments.len());
+/// # Ok(())
+/// # }
+/// ```
+pub struct HlsPlaylistFetcher {
+    /// HTTP client used for making requests to YouTube
+    client: Client,
+}
+
+impl HlsPlaylistFetcher {
+    /// Creates a new HLS playlist fetcher.
+    ///
+    /// # Parameters
+    ///
+    /// * `client` - The HTTP client, configured with the proxy and cookies of the API
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Downloads and parses a master playlist.
+    ///
+    /// # Parameters
+    ///
+    /// * `url` - The URL of the master playlist
+    /// * `video_id` - The YouTube video ID (used for error reporting)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<HlsMasterPlaylist, CouldNotRetrieveTranscript>` - The playlist, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YouTubeRequestFailed` error if the request fails, and a
+    /// `YouTubeDataUnparsable` error if the response is not an HLS playlist.
+    pub async fn fetch_master_playlist(
+        &self,
+        url: &str,
+        video_id: &str,
+    ) -> Result<HlsMasterPlaylist, CouldNotRetrieveTranscript> {
+        let playlist = self.fetch_playlist(url, video_id).await?;
+
+        HlsPlaylistParser::parse_master(&playlist, Some(url))
+            .ok_or_else(|| Self::unparsable(video_id))
+    }
+
+    /// Downloads and parses a media playlist.
+    ///
+    /// # Parameters
+    ///
+    /// * `url` - The URL of the media playlist, usually `HlsVariant::url`
+    /// * `video_id` - The YouTube video ID (used for error reporting)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<HlsMediaPlaylist, CouldNotRetrieveTranscript>` - The playlist, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YouTubeRequestFailed` error if the request fails, and a
+    /// `YouTubeDataUnparsable` error if the response is not an HLS playlist.
+    pub async fn fetch_media_playlist(
+        &self,
+        url: &str,
+        video_id: &str,
+    ) -> Result<HlsMediaPlaylist, CouldNotRetrieveTranscript> {
+        let playlist = self.fetch_playlist(url, video_id).await?;
+
+        HlsPlaylistParser::parse_media(&playlist, Some(url))
+            .ok_or_else(|| Self::unparsable(video_id))
+    }
+
+    async fn fetch_playlist(
+        &self,
+        url: &str,
+        video_id: &str,
+    ) -> Result<String, CouldNotRetrieveTranscript> {
+        let request_failed = |message: String| CouldNotRetrieveTranscript {
+            video_id: video_id.to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::YouTubeRequestFailed(
+                message,
+            )),
+        };
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| request_failed(format!("HLS playlist request failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(request_failed(format!(
+                "HLS playlist request returned status: {}",
+                response.status()
+            )));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|e| request_failed(format!("HLS playlist request failed: {}", e)))
+    }
+
+    fn unparsable(video_id: &str) -> CouldNotRetrieveTranscript {
+        CouldNotRetrieveTranscript {
+            video_id: video_id.to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::YouTubeDataUnparsable(
+                "Response is not an HLS playlist".to_string(),
+            )),
+        }
+    }
+}
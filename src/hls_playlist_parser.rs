@@ -0,0 +1,271 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use url::Url;
+
+use crate::models::{HlsMasterPlaylist, HlsMediaPlaylist, HlsSegment, HlsVariant};
+
+/// Matches the itag path segment of YouTube manifest URLs: `/itag/95/`
+static ITAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"/itag/(\d+)(?:/|$)").unwrap());
+
+/// # HlsPlaylistParser
+///
+/// Parses the HLS playlists (RFC 8216) that YouTube serves for live streams.
+///
+/// The `hlsManifestUrl` of the streaming data points to a master playlist listing
+/// one variant stream per format, each with its own media playlist of short
+/// segments. Only the tags needed to pick a variant and download its segments are
+/// read; other tags are ignored.
+///
+/// Relative URIs are resolved against the URL of the playlist when one is given.
+/// YouTube itself always uses absolute URLs.
+pub struct HlsPlaylistParser;
+
+impl HlsPlaylistParser {
+    /// Parses a master playlist.
+    ///
+    /// # Parameters
+    ///
+    /// * `playlist` - The content of the playlist
+    /// * `base_url` - The URL the playlist was fetched from, to resolve relative URIs
+    ///
+    /// # Returns
+    ///
+    /// * `Option<HlsMasterPlaylist>` - The playlist, or `None` if the content is not an HLS
+    ///   master playlist (a media playlist, with `#EXTINF` segments, is rejected) or has
+    ///   a variant without URI
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use yt_transcript_rs::hls_playlist_parser::HlsPlaylistParser;
+    /// let playlist = "#EXTM3U
+    /// #EXT-X-STREAM-INF:BANDWIDTH=1484588,CODECS=\"avc1.4d401f,mp4a.40.2\",RESOLUTION=1280x720,FRAME-RATE=30
+    /// https://manifest.googlevideo.com/api/manifest/hls_playlist/id/abc.1/itag/95/file/index.m3u8
+    /// ";
+    ///
+    /// let master = HlsPlaylistParser::parse_master(playlist, None).unwrap();
+    /// let variant = &master.variants[0];
+    /// assert_eq!(variant.bandwidth, 1484588);
+    /// assert_eq!(variant.codecs, ["avc1.4d401f", "mp4a.40.2"]);
+    /// assert_eq!((variant.width, variant.height), (Some(1280), Some(720)));
+    /// assert_eq!(variant.itag, Some(95));
+    /// ```
+    pub fn parse_master(playlist: &str, base_url: Option<&str>) -> Option<HlsMasterPlaylist> {
+        if Self::has_tag(playlist, "#EXTINF:") {
+            return None;
+        }
+
+        let mut lines = Self::lines(playlist)?;
+        let mut variants = Vec::new();
+
+        while let Some(line) = lines.next() {
+            let Some(attributes) = line.strip_prefix("#EXT-X-STREAM-INF:") else {
+                continue;
+            };
+            // The URI is the line right after the tag: a variant without URI makes
+            // the playlist invalid, rather than taking the URI of the next variant
+            let uri = lines.next().filter(|line| !line.starts_with('#'))?;
+            let url = Self::resolve(uri, base_url);
+            let attributes = Self::parse_attributes(attributes);
+            let attribute = |name: &str| {
+                attributes
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.as_str())
+            };
+
+            let resolution = attribute("RESOLUTION").and_then(|r| r.split_once('x'));
+            variants.push(HlsVariant {
+                bandwidth: attribute("BANDWIDTH")
+                    .and_then(|b| b.parse().ok())
+                    .unwrap_or(0),
+                average_bandwidth: attribute("AVERAGE-BANDWIDTH").and_then(|b| b.parse().ok()),
+                codecs: attribute("CODECS")
+                    .map(|codecs| {
+                        codecs
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|codec| !codec.is_empty())
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                width: resolution.and_then(|(width, _)| width.parse().ok()),
+                height: resolution.and_then(|(_, height)| height.parse().ok()),
+                frame_rate: attribute("FRAME-RATE").and_then(|f| f.parse().ok()),
+                itag: ITAG
+                    .captures(&url)
+                    .and_then(|captures| captures[1].parse().ok()),
+                url,
+            });
+        }
+
+        Some(HlsMasterPlaylist { variants })
+    }
+
+    /// Parses a media playlist.
+    ///
+    /// # Parameters
+    ///
+    /// * `playlist` - The content of the playlist
+    /// * `base_url` - The URL the playlist was fetched from, to resolve relative URIs
+    ///
+    /// # Returns
+    ///
+    /// * `Option<HlsMediaPlaylist>` - The playlist, or `None` if the content is not an HLS
+    ///   media playlist (a master playlist, with `#EXT-X-STREAM-INF` variants, is rejected)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use yt_transcript_rs::hls_playlist_parser::HlsPlaylistParser;
+    /// let playlist = "#EXTM3U
+    /// #EXT-X-TARGETDURATION:5
+    /// #EXT-X-MEDIA-SEQUENCE:120
+    /// #EXTINF:5.005,
+    /// seg/120.ts
+    /// #EXTINF:4.995,
+    /// seg/121.ts
+    /// ";
+    ///
+    /// let media = HlsPlaylistParser::parse_media(playlist, Some("https://example.com/live/index.m3u8")).unwrap();
+    /// assert_eq!(media.segments.len(), 2);
+    /// assert_eq!(media.segments[1].sequence, 121);
+    /// assert_eq!(media.segments[1].url, "https://example.com/live/seg/121.ts");
+    /// assert!(!media.ended);
+    /// ```
+    pub fn parse_media(playlist: &str, base_url: Option<&str>) -> Option<HlsMediaPlaylist> {
+        if Self::has_tag(playlist, "#EXT-X-STREAM-INF:") {
+            return None;
+        }
+
+        let mut media = HlsMediaPlaylist::default();
+        let mut duration = None;
+        let mut program_date_time = None;
+
+        for line in Self::lines(playlist)? {
+            if let Some(value) = line.strip_prefix("#EXT-X-TARGETDURATION:") {
+                media.target_duration = value.trim().parse().unwrap_or(0);
+            } else if let Some(value) = line.strip_prefix("#EXT-X-MEDIA-SEQUENCE:") {
+                media.media_sequence = value.trim().parse().unwrap_or(0);
+            } else if let Some(value) = line.strip_prefix("#EXTINF:") {
+                // `#EXTINF:<duration>,[<title>]`
+                let value = value.split(',').next().unwrap_or_default();
+                duration = Some(value.trim().parse().unwrap_or(0.0));
+            } else if let Some(value) = line.strip_prefix("#EXT-X-PROGRAM-DATE-TIME:") {
+                program_date_time = Some(value.trim().to_string());
+            } else if line == "#EXT-X-ENDLIST" {
+                media.ended = true;
+            } else if !line.starts_with('#') {
+                media.segments.push(HlsSegment {
+                    sequence: media.media_sequence + media.segments.len() as u64,
+                    duration: duration.take().unwrap_or(0.0),
+                    url: Self::resolve(line, base_url),
+                    program_date_time: program_date_time.take(),
+                });
+            }
+        }
+
+        Some(media)
+    }
+
+    /// Returns the non-empty lines of a playlist after its `#EXTM3U` header.
+    fn lines(playlist: &str) -> Option<impl Iterator<Item = &str>> {
+        let mut lines = playlist
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty());
+
+        (lines.next()?.trim_start_matches('\u{feff}') == "#EXTM3U").then_some(lines)
+    }
+
+    /// Returns whether a line of the playlist starts with `tag`.
+    fn has_tag(playlist: &str, tag: &str) -> bool {
+        playlist
+            .lines()
+            .any(|line| line.trim_start().starts_with(tag))
+    }
+
+    /// Parses an attribute list (`BANDWIDTH=1484588,CODECS="avc1.4d401f,mp4a.40.2"`),
+    /// where quoted values may contain commas.
+    fn parse_attributes(attributes: &str) -> Vec<(&str, String)> {
+        let mut parsed = Vec::new();
+        let mut rest = attributes.trim();
+
+        while let Some((name, value)) = rest.split_once('=') {
+            let (value, remainder) = match value.strip_prefix('"') {
+                Some(quoted) => match quoted.split_once('"') {
+                    Some((value, remainder)) => (value, remainder),
+                    None => (quoted, ""),
+                },
+                None => value.split_once(',').unwrap_or((value, "")),
+            };
+
+            parsed.push((name.trim(), value.to_string()));
+            rest = remainder.trim_start_matches(',').trim_start();
+        }
+
+        parsed
+    }
+
+    /// Resolves a URI against the URL of its playlist.
+    fn resolve(uri: &str, base_url: Option<&str>) -> String {
+        base_url
+            .and_then(|base| Url::parse(base).ok())
+            .and_then(|base| base.join(uri).ok())
+            .map(String::from)
+            .unwrap_or_else(|| uri.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_attributes() {
+        let attributes = HlsPlaylistParser::parse_attributes(
+            r#"BANDWIDTH=290288,CODECS="avc1.4d400c,mp4a.40.5",RESOLUTION=256x144,CLOSED-CAPTIONS=NONE"#,
+        );
+
+        assert_eq!(
+            attributes,
+            vec![
+                ("BANDWIDTH", "290288".to_string()),
+                ("CODECS", "avc1.4d400c,mp4a.40.5".to_string()),
+                ("RESOLUTION", "256x144".to_string()),
+                ("CLOSED-CAPTIONS", "NONE".to_string()),
+            ]
+        );
+        assert!(HlsPlaylistParser::parse_attributes("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(HlsPlaylistParser::parse_master("", None).is_none());
+        assert!(HlsPlaylistParser::parse_master("<html></html>", None).is_none());
+        assert!(HlsPlaylistParser::parse_media("#EXTINF:5,\nseg.ts", None).is_none());
+
+        // A playlist of the other type is rejected
+        let master = "#EXTM3U\n#EXT-X-STREAM-INF:BANDWIDTH=1\nhttps://example.com/95.m3u8\n";
+        let media = "#EXTM3U\n#EXT-X-TARGETDURATION:5\n#EXTINF:5.0,\nhttps://example.com/1.ts\n";
+        assert!(HlsPlaylistParser::parse_master(master, None).is_some());
+        assert!(HlsPlaylistParser::parse_media(master, None).is_none());
+        assert!(HlsPlaylistParser::parse_media(media, None).is_some());
+        assert!(HlsPlaylistParser::parse_master(media, None).is_none());
+
+        // A variant without URI makes the playlist invalid, instead of taking the URI
+        // of the next variant
+        assert!(
+            HlsPlaylistParser::parse_master("#EXTM3U\n#EXT-X-STREAM-INF:BANDWIDTH=1\n", None)
+                .is_none()
+        );
+        let missing_uri = "#EXTM3U\n#EXT-X-STREAM-INF:BANDWIDTH=1\n\n#EXT-X-STREAM-INF:BANDWIDTH=2\nhttps://example.com/2.m3u8\n";
+        assert!(HlsPlaylistParser::parse_master(missing_uri, None).is_none());
+
+        let master = HlsPlaylistParser::parse_master("#EXTM3U\n", None).unwrap();
+        assert!(master.variants.is_empty());
+        assert_eq!(master.best(), None);
+    }
+}
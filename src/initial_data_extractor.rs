@@ -0,0 +1,381 @@
+use serde_json::Value;
+
+use crate::errors::{CouldNotRetrieveTranscript, CouldNotRetrieveTranscriptReason};
+use crate::json_utils::{extract_text, find_all, find_first, parse_count, text_field};
+use crate::models::{InitialData, RelatedVideo};
+
+/// # InitialDataExtractor
+///
+/// Extracts page-level information from YouTube's `ytInitialData` variable.
+///
+/// While the player response describes the video stream, `ytInitialData` describes
+/// the watch page around it: the like button, the channel owner block, the related
+/// videos sidebar and the engagement panels. Since its layout changes often, renderers
+/// are looked up by name anywhere in the tree rather than by full path, and any field
+/// that cannot be found is left empty.
+pub struct InitialDataExtractor;
+
+impl InitialDataExtractor {
+    /// Extracts the watch page data from the `ytInitialData` JSON.
+    ///
+    /// # Parameters
+    ///
+    /// * `initial_data` - The parsed `ytInitialData` JSON object
+    /// * `video_id` - The YouTube video ID (used for error reporting)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<InitialData, CouldNotRetrieveTranscript>` - The extracted data or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YouTubeDataUnparsable` error if the JSON has no `contents` section,
+    /// which means it is not the initial data of a watch page.
+    pub fn extract_initial_data(
+        initial_data: &Value,
+        video_id: &str,
+    ) -> Result<InitialData, CouldNotRetrieveTranscript> {
+        let Some(contents) = initial_data.get("contents") else {
+            return Err(CouldNotRetrieveTranscript {
+                video_id: video_id.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::YouTubeDataUnparsable(
+                    "ytInitialData has no contents section".to_string(),
+                )),
+            });
+        };
+
+        let mut data = InitialData::default();
+
+        if let Some(primary) = find_first(contents, "videoPrimaryInfoRenderer") {
+            data.date_text = text_field(primary, "dateText");
+            data.view_count_text = find_first(primary, "viewCount")
+                .and_then(|v| v.get("videoViewCountRenderer"))
+                .and_then(|r| text_field(r, "viewCount"));
+            Self::extract_likes(primary, &mut data);
+        }
+
+        if let Some(owner) = find_first(contents, "videoOwnerRenderer") {
+            data.channel_name = text_field(owner, "title");
+            data.subscriber_count_text = text_field(owner, "subscriberCountText");
+            data.channel_badges = owner
+                .get("badges")
+                .and_then(|b| b.as_array())
+                .map(|badges| badges.iter().filter_map(Self::badge_label).collect())
+                .unwrap_or_default();
+        }
+
+        if let Some(secondary) = find_first(contents, "secondaryResults") {
+            data.related_videos = Self::extract_related_videos(secondary);
+        }
+
+        data.transcript_params = Self::extract_transcript_params(initial_data);
+        data.comments_continuation = Self::extract_comments_continuation(initial_data);
+
+        Ok(data)
+    }
+
+    /// Extracts the like count from the like button.
+    ///
+    /// Newer pages use a `likeButtonViewModel` whose accessibility text contains the
+    /// exact count ("like this video along with 1,234 other people"), older pages a
+    /// `segmentedLikeDislikeButtonRenderer` labelled "1,234 likes".
+    fn extract_likes(primary: &Value, data: &mut InitialData) {
+        if let Some(view_model) = find_first(primary, "likeButtonViewModel") {
+            data.like_count = find_first(view_model, "accessibilityText")
+                .and_then(|t| t.as_str())
+                .and_then(parse_count);
+            data.like_count_text = find_first(view_model, "title")
+                .and_then(|t| t.as_str())
+                .map(str::to_string);
+        } else if let Some(button) = find_first(primary, "segmentedLikeDislikeButtonRenderer")
+            .and_then(|r| find_first(r, "toggleButtonRenderer"))
+        {
+            data.like_count = button
+                .get("defaultText")
+                .and_then(|t| find_first(t, "label"))
+                .and_then(|l| l.as_str())
+                .and_then(parse_count);
+            data.like_count_text = text_field(button, "defaultText");
+        }
+    }
+
+    /// Returns the display label of a channel badge.
+    fn badge_label(badge: &Value) -> Option<String> {
+        let renderer = badge.get("metadataBadgeRenderer")?;
+
+        ["tooltip", "label", "style"]
+            .iter()
+            .find_map(|key| renderer.get(*key).and_then(|v| v.as_str()))
+            .map(str::to_string)
+    }
+
+    /// Extracts the related videos from the sidebar.
+    ///
+    /// Both the classic `compactVideoRenderer` and the newer `lockupViewModel` layouts
+    /// are supported. Playlists, mixes and other non-video entries are skipped.
+    fn extract_related_videos(secondary: &Value) -> Vec<RelatedVideo> {
+        let mut videos: Vec<RelatedVideo> = find_all(secondary, "compactVideoRenderer")
+            .into_iter()
+            .filter_map(|renderer| {
+                Some(RelatedVideo {
+                    video_id: renderer.get("videoId")?.as_str()?.to_string(),
+                    title: text_field(renderer, "title")?,
+                    channel_name: text_field(renderer, "longBylineText")
+                        .or_else(|| text_field(renderer, "shortBylineText")),
+                    length_text: text_field(renderer, "lengthText"),
+                    view_count_text: text_field(renderer, "viewCountText"),
+                })
+            })
+            .collect();
+
+        videos.extend(
+            find_all(secondary, "lockupViewModel")
+                .into_iter()
+                .filter(|lockup| {
+                    lockup.get("contentType").and_then(|t| t.as_str())
+                        == Some("LOCKUP_CONTENT_TYPE_VIDEO")
+                })
+                .filter_map(Self::lockup_video),
+        );
+
+        videos
+    }
+
+    /// Converts a `lockupViewModel` entry into a related video.
+    fn lockup_video(lockup: &Value) -> Option<RelatedVideo> {
+        let metadata = lockup.get("metadata")?.get("lockupMetadataViewModel")?;
+
+        // Metadata rows hold the channel name first, then "views • age"
+        let rows: Vec<String> = find_all(metadata, "metadataParts")
+            .into_iter()
+            .filter_map(|parts| {
+                parts
+                    .as_array()?
+                    .first()?
+                    .get("text")
+                    .and_then(extract_text)
+            })
+            .collect();
+
+        Some(RelatedVideo {
+            video_id: lockup.get("contentId")?.as_str()?.to_string(),
+            title: text_field(metadata, "title")?,
+            channel_name: rows.first().cloned(),
+            length_text: lockup
+                .get("contentImage")
+                .and_then(|image| find_first(image, "thumbnailBadgeViewModel"))
+                .and_then(|badge| text_field(badge, "text")),
+            view_count_text: rows.get(1).cloned(),
+        })
+    }
+
+    /// Extracts the `get_transcript` parameters from the transcript engagement panel.
+    fn extract_transcript_params(initial_data: &Value) -> Option<String> {
+        initial_data
+            .get("engagementPanels")?
+            .as_array()?
+            .iter()
+            .filter_map(|panel| panel.get("engagementPanelSectionListRenderer"))
+            .find(|renderer| {
+                renderer.get("panelIdentifier").and_then(|p| p.as_str())
+                    == Some("engagement-panel-searchable-transcript")
+            })
+            .and_then(|renderer| find_first(renderer, "getTranscriptEndpoint"))
+            .and_then(|endpoint| endpoint.get("params"))
+            .and_then(|params| params.as_str())
+            .map(str::to_string)
+    }
+
+    /// Extracts the continuation token of the first page of comments.
+    ///
+    /// The comments of a watch page are not part of `ytInitialData`; the comment
+    /// section only holds the token to request them from the InnerTube `next`
+    /// endpoint. The same token is found in `next` responses for the video.
+    ///
+    /// # Parameters
+    ///
+    /// * `initial_data` - The parsed `ytInitialData` JSON object, or a `next` response
+    ///
+    /// # Returns
+    ///
+    /// * `Option<String>` - The token, or `None` if comments are turned off
+    pub fn extract_comments_continuation(initial_data: &Value) -> Option<String> {
+        find_all(initial_data, "itemSectionRenderer")
+            .into_iter()
+            .find(|section| {
+                section.get("sectionIdentifier").and_then(|s| s.as_str())
+                    == Some("comment-item-section")
+            })
+            .and_then(|section| find_first(section, "continuationCommand"))
+            .and_then(|command| command.get("token"))
+            .and_then(|token| token.as_str())
+            .map(str::to_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample_initial_data() -> Value {
+        json!({
+            "contents": {"twoColumnWatchNextResults": {
+                "results": {"results": {"contents": [
+                    {"videoPrimaryInfoRenderer": {
+                        "viewCount": {"videoViewCountRenderer": {
+                            "viewCount": {"simpleText": "1,234,567 views"}
+                        }},
+                        "dateText": {"simpleText": "Oct 25, 2009"},
+                        "videoActions": {"menuRenderer": {"topLevelButtons": [
+                            {"segmentedLikeDislikeButtonViewModel": {
+                                "likeButtonViewModel": {"likeButtonViewModel": {
+                                    "toggleButtonViewModel": {"toggleButtonViewModel": {
+                                        "defaultButtonViewModel": {"buttonViewModel": {
+                                            "title": "18K",
+                                            "accessibilityText": "like this video along with 18,345 other people"
+                                        }}
+                                    }}
+                                }}
+                            }}
+                        ]}}
+                    }},
+                    {"videoSecondaryInfoRenderer": {
+                        "owner": {"videoOwnerRenderer": {
+                            "title": {"runs": [{"text": "Example Channel"}]},
+                            "subscriberCountText": {"simpleText": "3.9M subscribers"},
+                            "badges": [{"metadataBadgeRenderer": {
+                                "style": "BADGE_STYLE_TYPE_VERIFIED",
+                                "tooltip": "Verified"
+                            }}]
+                        }}
+                    }},
+                    {"itemSectionRenderer": {
+                        "sectionIdentifier": "comment-item-section",
+                        "contents": [{"continuationItemRenderer": {"continuationEndpoint": {
+                            "continuationCommand": {"token": "Eg0SC2RRdzR3OVdnWGNR"}
+                        }}}]
+                    }}
+                ]}},
+                "secondaryResults": {"secondaryResults": {"results": [
+                    {"compactVideoRenderer": {
+                        "videoId": "abc123",
+                        "title": {"simpleText": "Related one"},
+                        "longBylineText": {"runs": [{"text": "Other Channel"}]},
+                        "lengthText": {"simpleText": "4:20"},
+                        "viewCountText": {"simpleText": "42 views"}
+                    }},
+                    {"lockupViewModel": {
+                        "contentId": "def456",
+                        "contentType": "LOCKUP_CONTENT_TYPE_VIDEO",
+                        "contentImage": {"thumbnailViewModel": {"overlays": [
+                            {"thumbnailOverlayBadgeViewModel": {"thumbnailBadges": [
+                                {"thumbnailBadgeViewModel": {"text": "10:01"}}
+                            ]}}
+                        ]}},
+                        "metadata": {"lockupMetadataViewModel": {
+                            "title": {"content": "Related two"},
+                            "metadata": {"contentMetadataViewModel": {"metadataRows": [
+                                {"metadataParts": [{"text": {"content": "Third Channel"}}]},
+                                {"metadataParts": [
+                                    {"text": {"content": "1.2M views"}},
+                                    {"text": {"content": "2 years ago"}}
+                                ]}
+                            ]}}
+                        }}
+                    }},
+                    {"lockupViewModel": {
+                        "contentId": "PL123",
+                        "contentType": "LOCKUP_CONTENT_TYPE_PLAYLIST"
+                    }}
+                ]}}
+            }},
+            "engagementPanels": [
+                {"engagementPanelSectionListRenderer": {
+                    "panelIdentifier": "engagement-panel-structured-description"
+                }},
+                {"engagementPanelSectionListRenderer": {
+                    "panelIdentifier": "engagement-panel-searchable-transcript",
+                    "content": {"continuationItemRenderer": {"continuationEndpoint": {
+                        "getTranscriptEndpoint": {"params": "CgtkUXc0dzlXZ1hjUQ"}
+                    }}}
+                }}
+            ]
+        })
+    }
+
+    #[test]
+    fn test_extract_initial_data() {
+        let data =
+            InitialDataExtractor::extract_initial_data(&sample_initial_data(), "dQw4w9WgXcQ")
+                .unwrap();
+
+        assert_eq!(data.like_count, Some(18_345));
+        assert_eq!(data.like_count_text.as_deref(), Some("18K"));
+        assert_eq!(data.date_text.as_deref(), Some("Oct 25, 2009"));
+        assert_eq!(data.view_count_text.as_deref(), Some("1,234,567 views"));
+        assert_eq!(data.channel_name.as_deref(), Some("Example Channel"));
+        assert_eq!(data.channel_badges, vec!["Verified".to_string()]);
+        assert_eq!(
+            data.subscriber_count_text.as_deref(),
+            Some("3.9M subscribers")
+        );
+        assert_eq!(
+            data.transcript_params.as_deref(),
+            Some("CgtkUXc0dzlXZ1hjUQ")
+        );
+        assert_eq!(
+            data.comments_continuation.as_deref(),
+            Some("Eg0SC2RRdzR3OVdnWGNR")
+        );
+
+        assert_eq!(data.related_videos.len(), 2);
+        assert_eq!(data.related_videos[0].video_id, "abc123");
+        assert_eq!(
+            data.related_videos[0].channel_name.as_deref(),
+            Some("Other Channel")
+        );
+        assert_eq!(data.related_videos[1].video_id, "def456");
+        assert_eq!(data.related_videos[1].title, "Related two");
+        assert_eq!(
+            data.related_videos[1].channel_name.as_deref(),
+            Some("Third Channel")
+        );
+        assert_eq!(data.related_videos[1].length_text.as_deref(), Some("10:01"));
+        assert_eq!(
+            data.related_videos[1].view_count_text.as_deref(),
+            Some("1.2M views")
+        );
+    }
+
+    #[test]
+    fn test_legacy_like_button() {
+        let initial_data = json!({
+            "contents": {"videoPrimaryInfoRenderer": {
+                "segmentedLikeDislikeButtonRenderer": {"likeButton": {"toggleButtonRenderer": {
+                    "defaultText": {
+                        "accessibility": {"accessibilityData": {"label": "2,001 likes"}},
+                        "simpleText": "2K"
+                    }
+                }}}
+            }}
+        });
+
+        let data = InitialDataExtractor::extract_initial_data(&initial_data, "test").unwrap();
+
+        assert_eq!(data.like_count, Some(2_001));
+        assert_eq!(data.like_count_text.as_deref(), Some("2K"));
+        assert!(data.related_videos.is_empty());
+        assert!(data.transcript_params.is_none());
+    }
+
+    #[test]
+    fn test_missing_contents() {
+        let result = InitialDataExtractor::extract_initial_data(&json!({}), "test");
+
+        assert!(matches!(
+            result.unwrap_err().reason,
+            Some(CouldNotRetrieveTranscriptReason::YouTubeDataUnparsable(_))
+        ));
+    }
+}
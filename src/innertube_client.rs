@@ -144,4 +144,155 @@ impl InnerTubeClient {
 
         Ok(data)
     }
+
+    /// Calls the InnerTube `browse` endpoint, used for playlists and channels.
+    ///
+    /// # Parameters
+    ///
+    /// * `browse_id` - The ID of the page to browse (e.g., "VL" followed by a playlist ID)
+    /// * `params` - Optional encoded parameters selecting a tab or a filter of the page
+    pub async fn browse(
+        &self,
+        browse_id: &str,
+        params: Option<&str>,
+    ) -> Result<Value, CouldNotRetrieveTranscript> {
+        let mut body = json!({ "browseId": browse_id });
+        if let Some(params) = params {
+            body["params"] = json!(params);
+        }
+
+        self.call_endpoint("browse", body, browse_id).await
+    }
+
+    /// Calls the InnerTube `next` endpoint, which returns the watch page data of a
+    /// video (related videos, comments) and the contents of the playlist being played.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The video being watched, if any
+    /// * `playlist_id` - The playlist being played, if any
+    pub async fn next(
+        &self,
+        video_id: Option<&str>,
+        playlist_id: Option<&str>,
+    ) -> Result<Value, CouldNotRetrieveTranscript> {
+        let mut body = json!({});
+        if let Some(video_id) = video_id {
+            body["videoId"] = json!(video_id);
+        }
+        if let Some(playlist_id) = playlist_id {
+            body["playlistId"] = json!(playlist_id);
+        }
+
+        let id = video_id.or(playlist_id).unwrap_or_default();
+        self.call_endpoint("next", body, id).await
+    }
+
+    /// Calls the InnerTube `search` endpoint.
+    ///
+    /// # Parameters
+    ///
+    /// * `query` - The search query
+    /// * `params` - Optional encoded parameters selecting filters
+    pub async fn search(
+        &self,
+        query: &str,
+        params: Option<&str>,
+    ) -> Result<Value, CouldNotRetrieveTranscript> {
+        let mut body = json!({ "query": query });
+        if let Some(params) = params {
+            body["params"] = json!(params);
+        }
+
+        self.call_endpoint("search", body, query).await
+    }
+
+    /// Resolves a YouTube URL (e.g., a channel handle URL) to the endpoint it opens.
+    ///
+    /// # Parameters
+    ///
+    /// * `url` - The URL to resolve (e.g., `https://www.youtube.com/@handle`)
+    pub async fn resolve_url(&self, url: &str) -> Result<Value, CouldNotRetrieveTranscript> {
+        self.call_endpoint("navigation/resolve_url", json!({ "url": url }), url)
+            .await
+    }
+
+    /// Fetches the next page of results of a paginated endpoint.
+    ///
+    /// # Parameters
+    ///
+    /// * `endpoint` - The endpoint that returned the continuation token (e.g., "browse")
+    /// * `token` - The continuation token
+    /// * `id` - The ID of the video, playlist or channel being paginated (used for error reporting)
+    pub async fn continuation(
+        &self,
+        endpoint: &str,
+        token: &str,
+        id: &str,
+    ) -> Result<Value, CouldNotRetrieveTranscript> {
+        self.call_endpoint(endpoint, json!({ "continuation": token }), id)
+            .await
+    }
+
+    /// Posts a request to an InnerTube endpoint with the web client context.
+    ///
+    /// # Parameters
+    ///
+    /// * `endpoint` - The endpoint name (e.g., "browse", "next", "search")
+    /// * `body` - The request fields, to which the client context is added
+    /// * `id` - The ID of the requested resource (used for error reporting)
+    async fn call_endpoint(
+        &self,
+        endpoint: &str,
+        mut body: Value,
+        id: &str,
+    ) -> Result<Value, CouldNotRetrieveTranscript> {
+        let url = format!("https://www.youtube.com/youtubei/v1/{}", endpoint);
+
+        body["context"] = json!({
+            "client": {
+                "clientName": "WEB",
+                "clientVersion": "2.20231219.04.00",
+                "hl": "en",
+                "gl": "US"
+            }
+        });
+
+        let response = self.client
+            .post(&url)
+            .json(&body)
+            .header("Content-Type", "application/json")
+            .header("User-Agent", "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/137.0.0.0 Safari/537.36")
+            .send()
+            .await
+            .map_err(|e| CouldNotRetrieveTranscript {
+                video_id: id.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::YouTubeRequestFailed(
+                    format!("InnerTube {} API request failed: {}", endpoint, e)
+                )),
+            })?;
+
+        if !response.status().is_success() {
+            return Err(CouldNotRetrieveTranscript {
+                video_id: id.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::YouTubeRequestFailed(
+                    format!(
+                        "InnerTube {} API returned status: {}",
+                        endpoint,
+                        response.status()
+                    ),
+                )),
+            });
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| CouldNotRetrieveTranscript {
+                video_id: id.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::YouTubeDataUnparsable(
+                    format!("Failed to parse InnerTube {} response: {}", endpoint, e),
+                )),
+            })
+    }
 }
@@ -156,7 +156,8 @@ impl JsVarParser {
     /// to extract nested JavaScript objects correctly.
     ///
     /// The approach:
-    /// 1. Finds the variable name in the HTML
+    /// 1. Finds the assignment of the variable in the HTML (`var name = `,
+    ///    `window["name"] = `, `window.name = ` or `name = `)
     /// 2. Locates the opening brace of the object
     /// 3. Tracks nested braces to find the matching closing brace
     /// 4. Handles string literals and escape sequences properly
@@ -174,8 +175,7 @@ impl JsVarParser {
     /// # Errors
     ///
     /// Returns a `CouldNotRetrieveTranscript` error with `YouTubeDataUnparsable` reason when:
-    /// - The variable name is not found in the HTML
-    /// - No opening brace is found after the variable name
+    /// - No assignment of an object literal to the variable is found in the HTML
     /// - The HTML ends before finding a matching closing brace
     /// - The extracted text is not valid JSON
     ///
@@ -188,47 +188,21 @@ impl JsVarParser {
         html: &str,
         video_id: &str,
     ) -> Result<serde_json::Value, CouldNotRetrieveTranscript> {
-        // Step 1: Split by "var {var_name}"
-        let var_marker = format!("var {}", self.var_name);
-        let parts: Vec<&str> = html.split(&var_marker).collect();
-
-        if parts.len() <= 1 {
-            // Try with just the var name (without 'var' prefix)
-            let parts: Vec<&str> = html.split(&self.var_name).collect();
-            if parts.len() <= 1 {
-                return Err(CouldNotRetrieveTranscript {
-                    video_id: video_id.to_string(),
-                    reason: Some(CouldNotRetrieveTranscriptReason::YouTubeDataUnparsable(
-                        format!("JavaScript variable '{}' not found in HTML", self.var_name),
-                    )),
-                });
-            }
-        }
-
-        // Take the part after the variable name
-        let after_var = if parts.len() > 1 { parts[1] } else { "" };
+        // Steps 1-3: Find the assignment to the variable and the opening brace of its value
+        let Some(value_start) = self.find_assignment(html) else {
+            return Err(CouldNotRetrieveTranscript {
+                video_id: video_id.to_string(),
+                reason: Some(CouldNotRetrieveTranscriptReason::YouTubeDataUnparsable(
+                    format!(
+                        "Assignment of JavaScript variable '{}' not found in HTML",
+                        self.var_name
+                    ),
+                )),
+            });
+        };
 
-        // Step 2: Create iterator over the characters after the variable name
-        let mut chars = after_var.chars();
-
-        // Step 3: Find the opening brace
-        loop {
-            match chars.next() {
-                Some('{') => break,
-                Some(_) => continue,
-                None => {
-                    return Err(CouldNotRetrieveTranscript {
-                        video_id: video_id.to_string(),
-                        reason: Some(CouldNotRetrieveTranscriptReason::YouTubeDataUnparsable(
-                            format!(
-                                "Opening brace not found after JavaScript variable '{}'",
-                                self.var_name
-                            ),
-                        )),
-                    });
-                }
-            }
-        }
+        // Iterator over the characters after the opening brace
+        let mut chars = html[value_start + 1..].chars();
 
         // Step 4: Find the matching closing brace
         let mut json_chars = vec!['{'];
@@ -281,6 +255,36 @@ impl JsVarParser {
         }
     }
 
+    /// Locates the assignment of an object literal to the variable.
+    ///
+    /// The following forms are recognized, with any amount of whitespace around `=`:
+    /// - `var name = {...}`
+    /// - `window["name"] = {...}` and `window['name'] = {...}`
+    /// - `window.name = {...}`
+    /// - `name = {...}`
+    ///
+    /// Assignments of other values (such as `window["name"] = null;`, which YouTube
+    /// uses to reset variables) are skipped.
+    ///
+    /// # Returns
+    ///
+    /// The byte offset of the opening brace of the assigned object, or `None` if the
+    /// variable is never assigned an object literal.
+    fn find_assignment(&self, html: &str) -> Option<usize> {
+        let name = regex::escape(&self.var_name);
+        let pattern = format!(
+            r#"(?:var\s+{name}|window\[\s*["']{name}["']\s*\]|window\.{name}|\b{name})\s*=\s*"#
+        );
+        let re = Regex::new(&pattern).ok()?;
+
+        let value_start = re
+            .find_iter(html)
+            .map(|m| m.end())
+            .find(|&end| html[end..].starts_with('{'));
+
+        value_start
+    }
+
     /// Parses a JavaScript variable using regular expressions as a fallback method.
     ///
     /// This method tries multiple regex patterns to extract the variable value when
@@ -313,6 +317,10 @@ impl JsVarParser {
     ) -> Result<serde_json::Value, CouldNotRetrieveTranscript> {
         // Common patterns for finding JavaScript variables
         let patterns = [
+            format!(
+                r#"window\["{}"\]\s*=\s*(.*?);</script>"#,
+                regex::escape(&self.var_name)
+            ),
             format!(r"{}\ =\ (.*?);</script>", regex::escape(&self.var_name)),
             format!(r"{}=(.*?);</script>", regex::escape(&self.var_name)),
             format!(r#"{} = (.*?);"#, regex::escape(&self.var_name)),
@@ -0,0 +1,249 @@
+/// Helpers for navigating YouTube's renderer-based JSON structures.
+///
+/// The `ytInitialData` page variable and the InnerTube `browse`, `next` and `search`
+/// responses are deeply nested trees of "renderers" whose exact location changes
+/// frequently. Rather than hard-coding full paths, extractors look renderers up by
+/// key anywhere in the tree with these helpers.
+use serde_json::Value;
+
+/// Returns the first value stored under `key`, searching the tree depth-first.
+pub(crate) fn find_first<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    match value {
+        Value::Object(map) => {
+            if let Some(found) = map.get(key) {
+                return Some(found);
+            }
+            map.values().find_map(|v| find_first(v, key))
+        }
+        Value::Array(array) => array.iter().find_map(|v| find_first(v, key)),
+        _ => None,
+    }
+}
+
+/// Returns every value stored under `key`, in document order.
+///
+/// The search does not descend into matched values, so nested occurrences of the
+/// same key inside a match are not returned separately.
+pub(crate) fn find_all<'a>(value: &'a Value, key: &str) -> Vec<&'a Value> {
+    let mut results = Vec::new();
+    collect_all(value, key, &mut results);
+    results
+}
+
+fn collect_all<'a>(value: &'a Value, key: &str, results: &mut Vec<&'a Value>) {
+    match value {
+        Value::Object(map) => {
+            for (k, v) in map {
+                if k == key {
+                    results.push(v);
+                } else {
+                    collect_all(v, key, results);
+                }
+            }
+        }
+        Value::Array(array) => {
+            for v in array {
+                collect_all(v, key, results);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Extracts display text from one of YouTube's text representations.
+///
+/// Supports plain strings, `{"simpleText": ...}`, `{"runs": [{"text": ...}]}` and
+/// the `{"content": ...}` attributed strings used by newer view models.
+pub(crate) fn extract_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Object(map) => {
+            if let Some(Value::String(s)) = map.get("simpleText") {
+                return Some(s.clone());
+            }
+            if let Some(Value::Array(runs)) = map.get("runs") {
+                let text: String = runs
+                    .iter()
+                    .filter_map(|run| run.get("text").and_then(|t| t.as_str()))
+                    .collect();
+                return Some(text);
+            }
+            if let Some(Value::String(s)) = map.get("content") {
+                return Some(s.clone());
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Extracts the text stored under `key` in `value`, if any.
+pub(crate) fn text_field(value: &Value, key: &str) -> Option<String> {
+    value.get(key).and_then(extract_text)
+}
+
+/// Parses a count such as "1,234,567", "1,234,567 views" or "12 345 likes".
+///
+/// This is the single set of rules used for counts across the crate, both for
+/// the labels of InnerTube renderers and for the numeric strings of the player
+/// response. The count is the only number of the text and must stand as a word
+/// of its own; thousands separators (`,`, `_`, spaces and non-breaking spaces)
+/// between its digits are ignored.
+///
+/// Returns `None` if the text contains no number or several, if the number is
+/// attached to other characters like "abc12", or if it is fractional or
+/// abbreviated like "1.2M" (see [`parse_abbreviated_count`]).
+pub(crate) fn parse_count(text: &str) -> Option<u64> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let attached_before = text[..start]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c == '.' || c.is_alphanumeric());
+    if attached_before {
+        return None;
+    }
+
+    let mut digits = String::new();
+    let mut chars = text[start..].char_indices().peekable();
+    let mut end = text.len() - start;
+
+    while let Some((index, c)) = chars.next() {
+        let next_is_digit = chars.peek().is_some_and(|(_, next)| next.is_ascii_digit());
+        if c.is_ascii_digit() {
+            digits.push(c);
+        } else if !(matches!(c, ',' | '_' | ' ' | '\u{a0}') && next_is_digit) {
+            end = index;
+            break;
+        }
+    }
+
+    let rest = &text[start + end..];
+    let attached = rest
+        .chars()
+        .next()
+        .is_some_and(|c| c == '.' || c.is_alphanumeric());
+    if attached || rest.contains(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    digits.parse().ok()
+}
+
+/// Returns the message of the first error alert of an InnerTube response.
+///
+/// `browse` responses for missing or private playlists and channels carry an
+/// `alertRenderer` of type "ERROR" instead of contents.
+pub(crate) fn extract_error_alert(response: &Value) -> Option<String> {
+    response
+        .get("alerts")?
+        .as_array()?
+        .iter()
+        .filter_map(|alert| alert.get("alertRenderer"))
+        .find(|alert| alert.get("type").and_then(|t| t.as_str()) == Some("ERROR"))
+        .and_then(|alert| text_field(alert, "text"))
+}
+
+/// Returns the continuation token of a paginated InnerTube response, if there are more pages.
+pub(crate) fn extract_continuation_token(page: &Value) -> Option<String> {
+    find_all(page, "continuationItemRenderer")
+        .into_iter()
+        .find_map(|renderer| find_first(renderer, "continuationCommand"))
+        .and_then(|command| command.get("token"))
+        .and_then(|token| token.as_str())
+        .map(|token| token.to_string())
+}
+
+/// Parses a count that may be abbreviated, such as "1.2K", "3M" or "1,234".
+///
+/// Abbreviated counts are approximations: "1.2K" is parsed as 1200.
+pub(crate) fn parse_abbreviated_count(text: &str) -> Option<u64> {
+    let text: String = text
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, ',' | ' ' | '\u{a0}'))
+        .collect();
+    let number_end = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let (number, suffix) = text.split_at(number_end);
+    let number: f64 = number.parse().ok()?;
+
+    let multiplier = match suffix.chars().next().map(|c| c.to_ascii_uppercase()) {
+        Some('K') => 1_000.0,
+        Some('M') => 1_000_000.0,
+        Some('B') => 1_000_000_000.0,
+        _ => 1.0,
+    };
+
+    Some((number * multiplier).round() as u64)
+}
+
+/// Parses a display length such as "4:13" or "1:02:03" into seconds.
+pub(crate) fn parse_length_text(text: &str) -> Option<u32> {
+    let mut seconds = 0u32;
+    for part in text.trim().split(':') {
+        let value: u32 = part.parse().ok()?;
+        seconds = seconds.checked_mul(60)?.checked_add(value)?;
+    }
+    Some(seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_find_first_and_all() {
+        let value = json!({
+            "a": [{"target": 1}, {"nested": {"target": 2}}],
+            "b": {"target": {"target": 3}}
+        });
+
+        assert_eq!(find_first(&value, "target"), Some(&json!(1)));
+        assert_eq!(find_all(&value, "target").len(), 3);
+        assert!(find_first(&value, "missing").is_none());
+    }
+
+    #[test]
+    fn test_extract_text() {
+        assert_eq!(
+            extract_text(&json!({"simpleText": "Hello"})).as_deref(),
+            Some("Hello")
+        );
+        assert_eq!(
+            extract_text(&json!({"runs": [{"text": "Hello "}, {"text": "world"}]})).as_deref(),
+            Some("Hello world")
+        );
+        assert_eq!(
+            extract_text(&json!({"content": "Attributed"})).as_deref(),
+            Some("Attributed")
+        );
+        assert!(extract_text(&json!(42)).is_none());
+    }
+
+    #[test]
+    fn test_parse_counts() {
+        assert_eq!(parse_count("1,234,567 views"), Some(1_234_567));
+        assert_eq!(parse_count("No views"), None);
+        assert_eq!(parse_count(" 12 345 likes"), Some(12_345));
+        assert_eq!(parse_count("1_000"), Some(1_000));
+        assert_eq!(
+            parse_count("like this video along with 1,234 other people"),
+            Some(1_234)
+        );
+        assert_eq!(parse_count("1.2M views"), None);
+        assert_eq!(parse_count("3K"), None);
+        assert_eq!(parse_count("1,234 of 5,678"), None);
+        assert_eq!(parse_count("abc12"), None);
+        assert_eq!(parse_count(".5"), None);
+        assert_eq!(parse_count("(12)"), Some(12));
+        assert_eq!(parse_length_text("4:13"), Some(253));
+        assert_eq!(parse_length_text("1:02:03"), Some(3723));
+        assert_eq!(parse_length_text("LIVE"), None);
+        assert_eq!(parse_abbreviated_count("1.2K"), Some(1_200));
+        assert_eq!(parse_abbreviated_count("3M likes"), Some(3_000_000));
+        assert_eq!(parse_abbreviated_count("1,234"), Some(1_234));
+        assert_eq!(parse_abbreviated_count(""), None);
+    }
+}
@@ -101,19 +101,42 @@
 //! ```
 
 pub mod api;
+pub mod bilingual_transcript;
 pub mod captions_extractor;
+pub mod channel_fetcher;
+pub mod chapters_extractor;
+pub mod comment_fetcher;
 pub mod cookie_jar_loader;
+pub mod dash_manifest;
 pub mod errors;
 pub mod fetched_transcript;
+pub mod format_downloader;
+pub mod format_selector;
+pub mod heatmap_extractor;
+pub mod hls_playlist_fetcher;
+pub mod hls_playlist_parser;
+pub mod initial_data_extractor;
 pub mod innertube_client;
 pub mod js_var_parser;
+pub(crate) mod json_utils;
+pub mod links_extractor;
+pub mod live_caption_follower;
+pub mod live_details_extractor;
+pub mod media_type_parser;
 pub mod microformat_extractor;
 pub mod models;
 pub mod playability_asserter;
+pub mod player_js_fetcher;
+pub mod playlist_fetcher;
 pub mod proxies;
+pub mod search_fetcher;
+pub mod signature_decipher;
+pub mod storyboard_extractor;
 pub mod streaming_data_extractor;
 pub mod tests;
 pub mod transcript;
+pub mod transcript_deduplicator;
+pub mod transcript_diff;
 pub mod transcript_list;
 pub mod transcript_parser;
 pub mod video_data_fetcher;
@@ -123,23 +146,69 @@ pub mod youtube_page_fetcher;
 pub use api::YouTubeTranscriptApi;
 pub use cookie_jar_loader::CookieJarLoader;
 pub use errors::{
-    AgeRestricted, CookieError, CookieInvalid, CookiePathInvalid, CouldNotRetrieveTranscript,
-    FailedToCreateConsentCookie, InvalidVideoId, IpBlocked, NoTranscriptFound, NotTranslatable,
+    AgeRestricted, ChannelUnavailable, CookieError, CookieInvalid, CookiePathInvalid,
+    CouldNotRetrieveTranscript, DownloadFailed, FailedToCreateConsentCookie, InvalidVideoId,
+    IpBlocked, LiveStreamOffline, NoTranscriptFound, NotTranslatable, PlaylistUnavailable,
     RequestBlocked, TranscriptsDisabled, TranslationLanguageNotAvailable, VideoUnavailable,
     VideoUnplayable, YouTubeDataUnparsable, YouTubeRequestFailed, YouTubeTranscriptApiError,
 };
 
+pub use bilingual_transcript::BilingualTranscript;
 pub use captions_extractor::CaptionsExtractor;
+pub use channel_fetcher::ChannelFetcher;
+pub use chapters_extractor::ChaptersExtractor;
+pub use comment_fetcher::{CommentFetcher, CommentStream};
+pub use dash_manifest::DashManifest;
 pub use fetched_transcript::FetchedTranscript;
+pub use format_downloader::FormatDownloader;
+pub use format_selector::FormatSelector;
+pub use heatmap_extractor::HeatmapExtractor;
+pub use hls_playlist_fetcher::HlsPlaylistFetcher;
+pub use hls_playlist_parser::HlsPlaylistParser;
+pub use initial_data_extractor::InitialDataExtractor;
+pub use links_extractor::LinksExtractor;
+pub use live_caption_follower::LiveCaptionFollower;
+pub use live_details_extractor::LiveDetailsExtractor;
+pub use media_type_parser::MediaTypeParser;
+pub use models::BilingualSnippet;
+pub use models::Chapter;
 pub use models::FetchedTranscriptSnippet;
+pub use models::LiveDetails;
 pub use models::VideoDetails;
 pub use models::VideoInfos;
 pub use models::VideoThumbnail;
-pub use models::{ColorInfo, Range, StreamingData, StreamingFormat};
+pub use models::{
+    AudioQuality, ColorInfo, DownloadProgress, Range, SignatureCipher, SkippedFormat,
+    StreamingData, StreamingFormat,
+};
+pub use models::{
+    Card, DescriptionLink, DescriptionLinkKind, EndscreenElement, EndscreenElementKind, LinkTarget,
+    VideoLinks,
+};
+pub use models::{ChannelTab, ChannelUploads, ChannelVideo};
+pub use models::{Codec, CodecFamily, MediaKind, MediaType};
+pub use models::{Comment, CommentSortOrder};
+pub use models::{Heatmap, HeatmapSegment, KeyMoment};
+pub use models::{HlsMasterPlaylist, HlsMediaPlaylist, HlsSegment, HlsVariant};
+pub use models::{InitialData, RelatedVideo};
 pub use models::{MicroformatData, MicroformatEmbed, MicroformatThumbnail};
+pub use models::{Playlist, PlaylistEntry};
+pub use models::{
+    SearchChannel, SearchDuration, SearchFilters, SearchPage, SearchPlaylist, SearchResult,
+    SearchResultType, SearchUploadDate, SearchVideo,
+};
+pub use models::{Storyboard, StoryboardFrame, StoryboardLevel};
+pub use models::{TranscriptMatch, TranscriptSearchOptions};
 pub use playability_asserter::PlayabilityAsserter;
+pub use player_js_fetcher::PlayerJsFetcher;
+pub use playlist_fetcher::PlaylistFetcher;
+pub use search_fetcher::SearchFetcher;
+pub use signature_decipher::{CipherOperation, SignatureDecipher};
+pub use storyboard_extractor::StoryboardExtractor;
 pub use streaming_data_extractor::StreamingDataExtractor;
 pub use transcript::Transcript;
+pub use transcript_deduplicator::TranscriptDeduplicator;
+pub use transcript_diff::{SnippetChange, TranscriptDiff, WordChange};
 pub use transcript_list::TranscriptList;
 pub use video_details_extractor::VideoDetailsExtractor;
 pub use youtube_page_fetcher::YoutubePageFetcher;
@@ -0,0 +1,483 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde_json::Value;
+use url::Url;
+
+use crate::json_utils::{extract_text, find_first, parse_length_text, text_field};
+use crate::models::{
+    Card, DescriptionLink, DescriptionLinkKind, EndscreenElement, EndscreenElementKind, LinkTarget,
+    VideoLinks,
+};
+
+/// Matches a web address, with or without scheme
+static URL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?:https?://|www\.)[^\s<>"]+"#).unwrap());
+
+/// Matches a hashtag, which must contain at least one letter ("#1" is not a hashtag)
+static HASHTAG: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|[^\p{L}\p{N}_&/#])(#[\p{L}\p{N}_]*[\p{L}_][\p{L}\p{N}_]*)").unwrap()
+});
+
+/// Matches a channel handle, but not the domain of an email address
+static MENTION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:^|[^\p{L}\p{N}_.@])(@[\p{L}\p{N}_.\-]+)").unwrap());
+
+/// Matches a candidate timestamp, validated by `parse_timestamp`
+static TIMESTAMP: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b\d+(?::\d+)+\b").unwrap());
+
+/// # LinksExtractor
+///
+/// Extracts the links a creator attached to a video: endscreen elements, info cards
+/// and the URLs, hashtags, @mentions and timestamps of the description.
+///
+/// Endscreen elements and cards come from the `endscreen` and `cards` sections of
+/// the player response. Their destinations are InnerTube endpoints (`watchEndpoint`,
+/// `browseEndpoint`, `urlEndpoint`), which are turned into a `LinkTarget`. External
+/// URLs are unwrapped from YouTube's `/redirect` page.
+pub struct LinksExtractor;
+
+impl LinksExtractor {
+    /// Extracts the endscreen, cards and description links from the player response.
+    ///
+    /// # Parameters
+    ///
+    /// * `player_response` - The parsed YouTube player response JSON object
+    ///
+    /// # Returns
+    ///
+    /// * `VideoLinks` - The links, with empty lists for the missing sections
+    pub fn extract_links(player_response: &Value) -> VideoLinks {
+        let description = player_response
+            .get("videoDetails")
+            .and_then(|details| details.get("shortDescription"))
+            .and_then(|description| description.as_str())
+            .unwrap_or_default();
+
+        VideoLinks {
+            endscreen: Self::extract_endscreen(player_response),
+            cards: Self::extract_cards(player_response),
+            description: Self::parse_description(description),
+        }
+    }
+
+    /// Extracts the endscreen elements from the player response.
+    ///
+    /// # Parameters
+    ///
+    /// * `player_response` - The parsed YouTube player response JSON object
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<EndscreenElement>` - The elements sorted by appearance time, empty if the video has no endscreen
+    pub fn extract_endscreen(player_response: &Value) -> Vec<EndscreenElement> {
+        let mut elements: Vec<EndscreenElement> = player_response
+            .get("endscreen")
+            .and_then(|e| e.get("endscreenRenderer"))
+            .and_then(|r| r.get("elements"))
+            .and_then(|e| e.as_array())
+            .map(|elements| {
+                elements
+                    .iter()
+                    .filter_map(|e| e.get("endscreenElementRenderer"))
+                    .filter_map(Self::endscreen_element)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        elements.sort_by(|a, b| a.start.total_cmp(&b.start));
+        elements
+    }
+
+    /// Extracts the info cards from the player response.
+    ///
+    /// # Parameters
+    ///
+    /// * `player_response` - The parsed YouTube player response JSON object
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<Card>` - The cards sorted by appearance time, empty if the video has none
+    pub fn extract_cards(player_response: &Value) -> Vec<Card> {
+        let mut cards: Vec<Card> = player_response
+            .get("cards")
+            .and_then(|c| c.get("cardCollectionRenderer"))
+            .and_then(|r| r.get("cards"))
+            .and_then(|c| c.as_array())
+            .map(|cards| {
+                cards
+                    .iter()
+                    .filter_map(|c| c.get("cardRenderer"))
+                    .filter_map(Self::card)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        cards.sort_by(|a, b| a.start.total_cmp(&b.start));
+        cards
+    }
+
+    /// Finds the URLs, hashtags, @mentions and timestamps of a video description.
+    ///
+    /// Hashtags, mentions and timestamps that are part of a URL are not reported
+    /// separately. Trailing punctuation is not considered part of a URL.
+    ///
+    /// # Parameters
+    ///
+    /// * `description` - The video description
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<DescriptionLink>` - The links in order of appearance, with byte offsets into `description`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use yt_transcript_rs::links_extractor::LinksExtractor;
+    /// # use yt_transcript_rs::models::DescriptionLinkKind;
+    /// let description = "Recorded with @someone. Slides: https://example.com/slides.\n1:30 Demo #rust";
+    /// let links = LinksExtractor::parse_description(description);
+    ///
+    /// let texts: Vec<&str> = links.iter().map(|l| &description[l.start..l.end]).collect();
+    /// assert_eq!(texts, ["@someone", "https://example.com/slides", "1:30", "#rust"]);
+    /// assert_eq!(links[2].kind, DescriptionLinkKind::Timestamp { seconds: 90.0 });
+    /// ```
+    pub fn parse_description(description: &str) -> Vec<DescriptionLink> {
+        let mut links: Vec<DescriptionLink> = URL
+            .find_iter(description)
+            .map(|m| {
+                let text = Self::trim_url(m.as_str());
+                Self::link(DescriptionLinkKind::Url, text, m.start())
+            })
+            .collect();
+
+        let mut others: Vec<DescriptionLink> = Vec::new();
+        for (regex, kind) in [
+            (&*HASHTAG, DescriptionLinkKind::Hashtag),
+            (&*MENTION, DescriptionLinkKind::Mention),
+        ] {
+            for captures in regex.captures_iter(description) {
+                let m = captures.get(1).unwrap();
+                let text = m.as_str().trim_end_matches(['.', '-']);
+                // Nothing is left of "@." once the punctuation is trimmed
+                if text.len() > 1 {
+                    others.push(Self::link(kind.clone(), text, m.start()));
+                }
+            }
+        }
+        for m in TIMESTAMP.find_iter(description) {
+            if let Some(seconds) = Self::parse_timestamp(m.as_str()) {
+                let kind = DescriptionLinkKind::Timestamp { seconds };
+                others.push(Self::link(kind, m.as_str(), m.start()));
+            }
+        }
+
+        // URLs take precedence over what they contain ("example.com/#about")
+        others.retain(|link| {
+            !links
+                .iter()
+                .any(|url| link.start < url.end && url.start < link.end)
+        });
+        links.extend(others);
+        links.sort_by_key(|link| link.start);
+        links
+    }
+
+    fn endscreen_element(renderer: &Value) -> Option<EndscreenElement> {
+        let target = renderer.get("endpoint").and_then(Self::link_target);
+        let kind = match renderer.get("style").and_then(|s| s.as_str())? {
+            "VIDEO" => EndscreenElementKind::Video,
+            "PLAYLIST" => EndscreenElementKind::Playlist,
+            "CHANNEL" if renderer.get("isSubscribe").and_then(|s| s.as_bool()) == Some(true) => {
+                EndscreenElementKind::Subscribe
+            }
+            "CHANNEL" => EndscreenElementKind::Channel,
+            "WEBSITE" => EndscreenElementKind::Link,
+            _ => return None,
+        };
+
+        Some(EndscreenElement {
+            kind,
+            title: text_field(renderer, "title").unwrap_or_default(),
+            target,
+            start: Self::seconds(renderer.get("startMs")?)?,
+            end: Self::seconds(renderer.get("endMs")?)?,
+            left: Self::number(renderer, "left"),
+            top: Self::number(renderer, "top"),
+            width: Self::number(renderer, "width"),
+            aspect_ratio: Self::number(renderer, "aspectRatio"),
+        })
+    }
+
+    fn card(renderer: &Value) -> Option<Card> {
+        let content = renderer.get("content")?;
+        let endpoint = ["action", "command", "endpoint"]
+            .iter()
+            .find_map(|key| find_first(content, key))
+            .unwrap_or(content);
+
+        let title = ["videoTitle", "playlistTitle", "channelName", "title"]
+            .iter()
+            .find_map(|key| find_first(content, key).and_then(extract_text))
+            .unwrap_or_default();
+
+        let start = renderer
+            .get("cueRanges")
+            .and_then(|ranges| ranges.as_array())
+            .and_then(|ranges| ranges.first())
+            .and_then(|range| range.get("startCardActiveMs"))
+            .and_then(Self::seconds)
+            .unwrap_or(0.0);
+
+        Some(Card {
+            title,
+            teaser: renderer
+                .get("teaser")
+                .and_then(|teaser| find_first(teaser, "message"))
+                .and_then(extract_text),
+            target: Self::link_target(endpoint),
+            start,
+        })
+    }
+
+    /// Converts an InnerTube navigation endpoint into a link target.
+    fn link_target(endpoint: &Value) -> Option<LinkTarget> {
+        if let Some(url) = find_first(endpoint, "urlEndpoint")
+            .and_then(|e| e.get("url"))
+            .and_then(|u| u.as_str())
+        {
+            return Some(LinkTarget::Url(Self::unwrap_redirect(url)));
+        }
+
+        if let Some(watch) = find_first(endpoint, "watchEndpoint") {
+            let id_of = |key: &str| watch.get(key).and_then(|id| id.as_str());
+            return match (id_of("playlistId"), id_of("videoId")) {
+                (Some(playlist_id), _) => Some(LinkTarget::Playlist(playlist_id.to_string())),
+                (None, Some(video_id)) => Some(LinkTarget::Video(video_id.to_string())),
+                (None, None) => None,
+            };
+        }
+
+        let browse_id = find_first(endpoint, "browseEndpoint")
+            .and_then(|e| e.get("browseId"))
+            .and_then(|id| id.as_str())?;
+        Some(match browse_id.strip_prefix("VL") {
+            Some(playlist_id) => LinkTarget::Playlist(playlist_id.to_string()),
+            None => LinkTarget::Channel(browse_id.to_string()),
+        })
+    }
+
+    /// Returns the destination of a `youtube.com/redirect` URL, or the URL itself.
+    fn unwrap_redirect(url: &str) -> String {
+        Url::parse(url)
+            .ok()
+            .filter(|parsed| {
+                parsed.path() == "/redirect"
+                    && parsed
+                        .host_str()
+                        .is_some_and(|host| host.ends_with("youtube.com"))
+            })
+            .and_then(|parsed| {
+                parsed
+                    .query_pairs()
+                    .find(|(key, _)| key == "q")
+                    .map(|(_, value)| value.into_owned())
+            })
+            .unwrap_or_else(|| url.to_string())
+    }
+
+    /// Removes trailing punctuation from a URL, keeping closing parentheses that
+    /// belong to it ("https://en.wikipedia.org/wiki/Rust_(language)").
+    fn trim_url(url: &str) -> &str {
+        let mut url = url;
+        loop {
+            let trimmed = url.trim_end_matches(['.', ',', ';', ':', '!', '?', '\'', '"']);
+            let trimmed = match trimmed.strip_suffix(')') {
+                Some(inner) if inner.matches('(').count() < trimmed.matches(')').count() => inner,
+                _ => trimmed,
+            };
+            if trimmed.len() == url.len() {
+                return url;
+            }
+            url = trimmed;
+        }
+    }
+
+    /// Parses a "m:ss", "mm:ss" or "h:mm:ss" timestamp into seconds.
+    fn parse_timestamp(timestamp: &str) -> Option<f64> {
+        let parts: Vec<&str> = timestamp.split(':').collect();
+        let valid = matches!(parts.len(), 2 | 3)
+            && parts[0].len() <= 2
+            && parts[1..]
+                .iter()
+                .all(|part| part.len() == 2 && part.as_bytes()[0] < b'6');
+
+        if valid {
+            parse_length_text(timestamp).map(f64::from)
+        } else {
+            None
+        }
+    }
+
+    fn link(kind: DescriptionLinkKind, text: &str, start: usize) -> DescriptionLink {
+        DescriptionLink {
+            kind,
+            text: text.to_string(),
+            start,
+            end: start + text.len(),
+        }
+    }
+
+    /// Converts milliseconds, sent either as a string or as a number, to seconds.
+    fn seconds(millis: &Value) -> Option<f64> {
+        let millis = match millis {
+            Value::String(s) => s.parse().ok()?,
+            other => other.as_f64()?,
+        };
+        Some(millis / 1000.0)
+    }
+
+    fn number(renderer: &Value, key: &str) -> f64 {
+        renderer.get(key).and_then(|n| n.as_f64()).unwrap_or(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_extract_endscreen() {
+        let player_response = json!({
+            "endscreen": {"endscreenRenderer": {"elements": [
+                {"endscreenElementRenderer": {
+                    "style": "WEBSITE", "left": 0.6, "top": 0.2, "width": 0.3, "aspectRatio": 1.0,
+                    "startMs": "195000", "endMs": "212000",
+                    "title": {"simpleText": "Merch"},
+                    "endpoint": {"urlEndpoint": {
+                        "url": "https://www.youtube.com/redirect?event=endscreen&q=https%3A%2F%2Fshop.example.com%2F%3Fa%3D1"
+                    }}
+                }},
+                {"endscreenElementRenderer": {
+                    "style": "VIDEO", "left": 0.05, "top": 0.1, "width": 0.4, "aspectRatio": 1.7777,
+                    "startMs": "193000", "endMs": "212000",
+                    "title": {"simpleText": "Next video"},
+                    "endpoint": {"watchEndpoint": {"videoId": "9bZkp7q19f0"}}
+                }},
+                {"endscreenElementRenderer": {
+                    "style": "CHANNEL", "isSubscribe": true,
+                    "startMs": "193000", "endMs": "212000",
+                    "title": {"simpleText": "Rick Astley"},
+                    "endpoint": {"browseEndpoint": {"browseId": "UCuAXFkgsw1L7xaCfnd5JJOw"}}
+                }},
+                {"endscreenElementRenderer": {"style": "UNKNOWN", "startMs": "0", "endMs": "1"}}
+            ]}}
+        });
+
+        let elements = LinksExtractor::extract_endscreen(&player_response);
+        assert_eq!(elements.len(), 3);
+
+        assert_eq!(elements[0].kind, EndscreenElementKind::Video);
+        assert_eq!(
+            elements[0].target,
+            Some(LinkTarget::Video("9bZkp7q19f0".to_string()))
+        );
+        assert_eq!((elements[0].start, elements[0].end), (193.0, 212.0));
+        assert_eq!(elements[0].width, 0.4);
+
+        assert_eq!(elements[1].kind, EndscreenElementKind::Subscribe);
+        assert_eq!(
+            elements[1].target,
+            Some(LinkTarget::Channel("UCuAXFkgsw1L7xaCfnd5JJOw".to_string()))
+        );
+
+        assert_eq!(elements[2].kind, EndscreenElementKind::Link);
+        assert_eq!(
+            elements[2].target,
+            Some(LinkTarget::Url("https://shop.example.com/?a=1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_extract_cards() {
+        let player_response = json!({
+            "videoDetails": {"shortDescription": "More at https://example.com"},
+            "cards": {"cardCollectionRenderer": {"cards": [
+                {"cardRenderer": {
+                    "teaser": {"simpleCardTeaserRenderer": {"message": {"simpleText": "Full playlist"}}},
+                    "content": {"playlistInfoCardContentRenderer": {
+                        "playlistTitle": {"simpleText": "All episodes"},
+                        "action": {"watchEndpoint": {"videoId": "abc", "playlistId": "PL123"}}
+                    }},
+                    "cueRanges": [{"startCardActiveMs": "60000", "endCardActiveMs": "65000"}]
+                }},
+                {"cardRenderer": {
+                    "teaser": {"simpleCardTeaserRenderer": {"message": {"simpleText": "Watch this"}}},
+                    "content": {"videoInfoCardContentRenderer": {
+                        "videoTitle": {"simpleText": "Part 1"},
+                        "channelName": {"simpleText": "Someone"},
+                        "action": {"clickTrackingParams": "x", "watchEndpoint": {"videoId": "9bZkp7q19f0"}}
+                    }},
+                    "cueRanges": [{"startCardActiveMs": "12500"}]
+                }}
+            ]}}
+        });
+
+        let links = LinksExtractor::extract_links(&player_response);
+        assert!(links.endscreen.is_empty());
+        assert_eq!(links.description.len(), 1);
+        assert_eq!(
+            links.cards,
+            vec![
+                Card {
+                    title: "Part 1".to_string(),
+                    teaser: Some("Watch this".to_string()),
+                    target: Some(LinkTarget::Video("9bZkp7q19f0".to_string())),
+                    start: 12.5,
+                },
+                Card {
+                    title: "All episodes".to_string(),
+                    teaser: Some("Full playlist".to_string()),
+                    target: Some(LinkTarget::Playlist("PL123".to_string())),
+                    start: 60.0,
+                },
+            ]
+        );
+        assert_eq!(links.video_ids(), vec!["9bZkp7q19f0"]);
+    }
+
+    #[test]
+    fn test_parse_description() {
+        let description = "Wiki: https://en.wikipedia.org/wiki/Rust_(language), docs (www.rust-lang.org/learn).\n\
+            0:00 Intro\n12:05 Ownership\n1:02:03 Q&A (not 99:99 or 1:2:3)\n\
+            Contact: me@example.com or @rustlang. #rust #1 #программирование @. @--";
+        let links = LinksExtractor::parse_description(description);
+
+        for link in &links {
+            assert_eq!(&description[link.start..link.end], link.text);
+        }
+
+        let summary: Vec<(&DescriptionLinkKind, &str)> =
+            links.iter().map(|l| (&l.kind, l.text.as_str())).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    &DescriptionLinkKind::Url,
+                    "https://en.wikipedia.org/wiki/Rust_(language)"
+                ),
+                (&DescriptionLinkKind::Url, "www.rust-lang.org/learn"),
+                (&DescriptionLinkKind::Timestamp { seconds: 0.0 }, "0:00"),
+                (&DescriptionLinkKind::Timestamp { seconds: 725.0 }, "12:05"),
+                (
+                    &DescriptionLinkKind::Timestamp { seconds: 3723.0 },
+                    "1:02:03"
+                ),
+                (&DescriptionLinkKind::Mention, "@rustlang"),
+                (&DescriptionLinkKind::Hashtag, "#rust"),
+                (&DescriptionLinkKind::Hashtag, "#программирование"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use reqwest::Client;
+
+use crate::errors::CouldNotRetrieveTranscript;
+use crate::models::FetchedTranscriptSnippet;
+use crate::transcript::Transcript;
+
+/// # LiveCaptionFollower
+///
+/// Follows the caption track of a live stream as it grows.
+///
+/// While a stream is live, YouTube serves its caption track with the captions
+/// produced so far, and the track grows as the broadcast goes on. The follower
+/// re-fetches the track on each call to [`poll`](LiveCaptionFollower::poll) and only
+/// returns the snippets that were not returned before.
+///
+/// The most recent snippet of a live track is often still being written, so it is
+/// held back until a newer snippet appears. Once the stream has ended, call
+/// [`finish`](LiveCaptionFollower::finish) to get it.
+///
+/// Live captions are usually rolling captions; pass the snippets through a
+/// [`TranscriptDeduplicator`](crate::transcript_deduplicator::TranscriptDeduplicator)
+/// to remove the repeated words.
+///
+/// ## Usage Example
+///
+/// ```rust,no_run
+/// # use yt_transcript_rs::YouTubeTranscriptApi;
+/// # use std::time::Duration;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let api = YouTubeTranscriptApi::new(None, None, None)?;
+/// let mut follower = api.follow_live_captions("jfKfPfyJRdk", &["en"]).await?;
+///
+/// loop {
+///     for snippet in follower.poll().await? {
+///         println!("[{:.1}s] {}", snippet.start, snippet.text);
+///     }
+///
+///     if !api.fetch_live_details("jfKfPfyJRdk").await?.is_live_now {
+///         break;
+///     }
+///     tokio::time::sleep(Duration::from_secs(10)).await;
+/// }
+///
+/// if let Some(snippet) = follower.finish() {
+///     println!("[{:.1}s] {}", snippet.start, snippet.text);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiveCaptionFollower {
+    /// The caption track being followed
+    transcript: Transcript,
+
+    /// HTTP client used to fetch the track
+    client: Client,
+
+    /// Whether to preserve HTML formatting in the snippets
+    preserve_formatting: bool,
+
+    /// Start time of the last snippet returned
+    returned_until: Option<f64>,
+
+    /// Most recent snippet, held back until a newer one appears
+    pending: Option<FetchedTranscriptSnippet>,
+}
+
+impl LiveCaptionFollower {
+    /// Creates a follower for a caption track, without preserving formatting.
+    ///
+    /// # Parameters
+    ///
+    /// * `transcript` - The caption track of the live stream
+    /// * `client` - HTTP client used to fetch the track
+    pub fn new(transcript: Transcript, client: Client) -> Self {
+        Self::with_config(transcript, client, false)
+    }
+
+    /// Creates a follower for a caption track.
+    ///
+    /// # Parameters
+    ///
+    /// * `transcript` - The caption track of the live stream
+    /// * `client` - HTTP client used to fetch the track
+    /// * `preserve_formatting` - Whether to preserve HTML formatting in the snippets
+    pub fn with_config(transcript: Transcript, client: Client, preserve_formatting: bool) -> Self {
+        Self {
+            transcript,
+            client,
+            preserve_formatting,
+            returned_until: None,
+            pending: None,
+        }
+    }
+
+    /// Fetches the caption track and returns the snippets added since the last call.
+    ///
+    /// The first call returns all captions produced so far. The most recent snippet
+    /// is held back, see [`pending`](LiveCaptionFollower::pending).
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<FetchedTranscriptSnippet>, CouldNotRetrieveTranscript>` - The new snippets in chronological order, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Transcript::fetch`]. A failed poll does not lose
+    /// any snippets; the next successful poll returns them.
+    pub async fn poll(
+        &mut self,
+    ) -> Result<Vec<FetchedTranscriptSnippet>, CouldNotRetrieveTranscript> {
+        let fetched = self
+            .transcript
+            .fetch(&self.client, self.preserve_formatting)
+            .await?;
+
+        Ok(self.take_new(fetched.snippets))
+    }
+
+    /// Returns the most recent snippet, which may still be growing.
+    pub fn pending(&self) -> Option<&FetchedTranscriptSnippet> {
+        self.pending.as_ref()
+    }
+
+    /// Returns the held back snippet once the stream has ended.
+    ///
+    /// The snippet is considered returned, so later polls will not return it again.
+    pub fn finish(&mut self) -> Option<FetchedTranscriptSnippet> {
+        let snippet = self.pending.take()?;
+        self.returned_until = Some(snippet.start);
+        Some(snippet)
+    }
+
+    /// Returns the start time of the last snippet returned, or `None` before the first one.
+    pub fn position(&self) -> Option<f64> {
+        self.returned_until
+    }
+
+    /// Selects the snippets that were not returned yet, holding back the most recent one.
+    fn take_new(
+        &mut self,
+        mut snippets: Vec<FetchedTranscriptSnippet>,
+    ) -> Vec<FetchedTranscriptSnippet> {
+        snippets.retain(|snippet| self.returned_until.is_none_or(|t| snippet.start > t));
+        snippets.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+        self.pending = snippets.pop();
+        if let Some(last) = snippets.last() {
+            self.returned_until = Some(last.start);
+        }
+
+        snippets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn follower() -> LiveCaptionFollower {
+        let transcript = Transcript::new(
+            "live123".to_string(),
+            "https://www.youtube.com/api/timedtext?v=live123".to_string(),
+            "English (auto-generated)".to_string(),
+            "en".to_string(),
+            true,
+            vec![],
+        );
+        LiveCaptionFollower::new(transcript, Client::new())
+    }
+
+    fn snippets(starts: &[f64]) -> Vec<FetchedTranscriptSnippet> {
+        starts
+            .iter()
+            .map(|&start| FetchedTranscriptSnippet {
+                text: format!("at {}", start),
+                start,
+                duration: 2.0,
+            })
+            .collect()
+    }
+
+    fn starts(snippets: &[FetchedTranscriptSnippet]) -> Vec<f64> {
+        snippets.iter().map(|s| s.start).collect()
+    }
+
+    #[test]
+    fn test_returns_only_new_snippets() {
+        let mut follower = follower();
+
+        let first = follower.take_new(snippets(&[0.0, 2.0, 4.0]));
+        assert_eq!(starts(&first), vec![0.0, 2.0]);
+        assert_eq!(follower.pending().map(|s| s.start), Some(4.0));
+        assert_eq!(follower.position(), Some(2.0));
+
+        // The track grew: the held back snippet is now returned
+        let second = follower.take_new(snippets(&[0.0, 2.0, 4.0, 6.0, 8.0]));
+        assert_eq!(starts(&second), vec![4.0, 6.0]);
+
+        // Nothing new
+        let third = follower.take_new(snippets(&[0.0, 2.0, 4.0, 6.0, 8.0]));
+        assert!(third.is_empty());
+        assert_eq!(follower.pending().map(|s| s.start), Some(8.0));
+    }
+
+    #[test]
+    fn test_finish_returns_pending_once() {
+        let mut follower = follower();
+        follower.take_new(snippets(&[0.0, 2.0]));
+
+        assert_eq!(follower.finish().map(|s| s.start), Some(2.0));
+        assert!(follower.finish().is_none());
+        assert!(follower.take_new(snippets(&[0.0, 2.0])).is_empty());
+        assert!(follower.pending().is_none());
+    }
+}
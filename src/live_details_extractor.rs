@@ -0,0 +1,191 @@
+use chrono::DateTime;
+use serde_json::Value;
+
+use crate::json_utils::{extract_text, find_first};
+use crate::models::LiveDetails;
+use crate::playability_asserter::PlayabilityAsserter;
+
+/// # LiveDetailsExtractor
+///
+/// Extracts live stream and premiere information from YouTube's player response data.
+///
+/// The information is spread over the player response:
+/// - `videoDetails` flags the video as live content, live now or upcoming
+/// - `microformat.playerMicroformatRenderer.liveBroadcastDetails` holds the actual
+///   start and end times of the broadcast
+/// - The offline slate of the playability status announces the scheduled start
+///   time of upcoming streams and premieres
+///
+/// Unlike the other extractors this never fails: missing fields simply mean the
+/// video is not live.
+pub struct LiveDetailsExtractor;
+
+impl LiveDetailsExtractor {
+    /// Extracts the live details from the player response JSON.
+    ///
+    /// # Parameters
+    ///
+    /// * `player_response` - The parsed YouTube player response JSON object
+    ///
+    /// # Returns
+    ///
+    /// The live stream and premiere state of the video.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use yt_transcript_rs::live_details_extractor::LiveDetailsExtractor;
+    /// # use serde_json::json;
+    /// let player_response = json!({
+    ///     "videoDetails": {"isLiveContent": true, "isLive": true},
+    ///     "microformat": {"playerMicroformatRenderer": {"liveBroadcastDetails": {
+    ///         "isLiveNow": true,
+    ///         "startTimestamp": "2025-01-01T18:00:12+00:00"
+    ///     }}}
+    /// });
+    ///
+    /// let details = LiveDetailsExtractor::extract_live_details(&player_response);
+    /// assert!(details.is_live_now);
+    /// assert!(details.end_timestamp.is_none());
+    /// ```
+    pub fn extract_live_details(player_response: &Value) -> LiveDetails {
+        let video_details = player_response.get("videoDetails");
+        let flag = |key: &str| {
+            video_details
+                .and_then(|d| d.get(key))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false)
+        };
+
+        let broadcast = player_response
+            .get("microformat")
+            .and_then(|m| m.get("playerMicroformatRenderer"))
+            .and_then(|r| r.get("liveBroadcastDetails"));
+        let timestamp = |key: &str| {
+            broadcast
+                .and_then(|b| b.get(key))
+                .and_then(|v| v.as_str())
+                .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        };
+
+        let is_live_content = flag("isLiveContent");
+        let is_upcoming = flag("isUpcoming");
+        let is_live_now = flag("isLive")
+            || broadcast
+                .and_then(|b| b.get("isLiveNow"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+
+        LiveDetails {
+            is_live_content,
+            is_live_now,
+            is_upcoming,
+            is_premiere: Self::is_premiere(player_response, is_live_content, is_upcoming),
+            scheduled_start_time: PlayabilityAsserter::extract_scheduled_start_time(
+                player_response,
+            ),
+            start_timestamp: timestamp("startTimestamp"),
+            end_timestamp: timestamp("endTimestamp"),
+        }
+    }
+
+    /// Returns whether the video is a premiere.
+    ///
+    /// Upcoming premieres are uploads, so they are not flagged as live content. Past
+    /// and airing premieres can only be told apart from live streams by the messages
+    /// YouTube displays, which mention the premiere.
+    fn is_premiere(player_response: &Value, is_live_content: bool, is_upcoming: bool) -> bool {
+        if is_upcoming && !is_live_content {
+            return true;
+        }
+
+        let status = player_response.get("playabilityStatus");
+        let messages = [
+            status.and_then(|s| s.get("reason")),
+            status
+                .and_then(|s| s.get("liveStreamability"))
+                .and_then(|l| find_first(l, "mainText")),
+        ];
+
+        messages
+            .iter()
+            .flatten()
+            .filter_map(|message| extract_text(message))
+            .any(|text| text.to_lowercase().contains("premiere"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_regular_video() {
+        let player_response = json!({"videoDetails": {"isLiveContent": false}});
+
+        let details = LiveDetailsExtractor::extract_live_details(&player_response);
+
+        assert_eq!(details, LiveDetails::default());
+    }
+
+    #[test]
+    fn test_past_live_stream() {
+        let player_response = json!({
+            "videoDetails": {"isLiveContent": true},
+            "microformat": {"playerMicroformatRenderer": {"liveBroadcastDetails": {
+                "isLiveNow": false,
+                "startTimestamp": "2024-05-01T17:00:05+00:00",
+                "endTimestamp": "2024-05-01T19:12:44+00:00"
+            }}}
+        });
+
+        let details = LiveDetailsExtractor::extract_live_details(&player_response);
+
+        assert!(details.is_live_content);
+        assert!(!details.is_live_now);
+        assert!(!details.is_premiere);
+        let duration = details.end_timestamp.unwrap() - details.start_timestamp.unwrap();
+        assert_eq!(duration.num_seconds(), 2 * 3600 + 12 * 60 + 39);
+    }
+
+    #[test]
+    fn test_upcoming_premiere() {
+        let player_response = json!({
+            "videoDetails": {"isLiveContent": false, "isUpcoming": true},
+            "playabilityStatus": {
+                "status": "LIVE_STREAM_OFFLINE",
+                "reason": "Premieres in 2 hours",
+                "liveStreamability": {"liveStreamabilityRenderer": {"offlineSlate": {
+                    "liveStreamOfflineSlateRenderer": {"scheduledStartTime": "1735754400"}
+                }}}
+            }
+        });
+
+        let details = LiveDetailsExtractor::extract_live_details(&player_response);
+
+        assert!(details.is_upcoming);
+        assert!(details.is_premiere);
+        assert_eq!(
+            details.scheduled_start_time.map(|t| t.timestamp()),
+            Some(1_735_754_400)
+        );
+    }
+
+    #[test]
+    fn test_upcoming_live_stream_is_not_premiere() {
+        let player_response = json!({
+            "videoDetails": {"isLiveContent": true, "isUpcoming": true},
+            "playabilityStatus": {
+                "status": "LIVE_STREAM_OFFLINE",
+                "reason": "This live event will begin in 3 hours."
+            }
+        });
+
+        let details = LiveDetailsExtractor::extract_live_details(&player_response);
+
+        assert!(details.is_upcoming);
+        assert!(!details.is_premiere);
+        assert!(details.scheduled_start_time.is_none());
+    }
+}
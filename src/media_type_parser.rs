@@ -0,0 +1,278 @@
+use crate::models::{Codec, CodecFamily, MediaKind, MediaType};
+
+/// # MediaTypeParser
+///
+/// Parses the `mimeType` of streaming formats, such as
+/// `video/webm; codecs="vp09.00.51.08"`, into a `MediaType`.
+///
+/// Codec identifiers follow the RFC 6381 conventions, which encode the profile and
+/// level of the stream:
+/// - AVC: `avc1.PPCCLL`, hexadecimal profile, constraint flags and level (`avc1.640028` is High 4.0)
+/// - VP9: `vp09.PP.LL.DD`, decimal profile, level and bit depth (`vp09.02.51.10` is Profile 2, 5.1, 10 bit)
+/// - AV1: `av01.P.LLT.DD`, profile, level index, tier and bit depth (`av01.0.08M.08` is Main 4.0, 8 bit)
+/// - AAC: `mp4a.40.OT`, audio object type (`mp4a.40.2` is AAC LC)
+///
+/// Short identifiers without these fields (`vp9`, `opus`) are recognized without
+/// profile or level.
+pub struct MediaTypeParser;
+
+impl MediaTypeParser {
+    /// Parses a MIME type.
+    ///
+    /// # Parameters
+    ///
+    /// * `mime_type` - The MIME type (e.g., `video/mp4; codecs="avc1.42001E, mp4a.40.2"`)
+    ///
+    /// # Returns
+    ///
+    /// * `Option<MediaType>` - The parsed MIME type, or `None` if it is not an audio or video type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use yt_transcript_rs::media_type_parser::MediaTypeParser;
+    /// # use yt_transcript_rs::models::{CodecFamily, MediaKind};
+    /// let media_type = MediaTypeParser::parse("video/mp4; codecs=\"avc1.640028\"").unwrap();
+    ///
+    /// assert_eq!(media_type.kind, MediaKind::Video);
+    /// assert_eq!(media_type.container, "mp4");
+    ///
+    /// let codec = media_type.video_codec().unwrap();
+    /// assert_eq!(codec.family, CodecFamily::Avc);
+    /// assert_eq!(codec.profile.as_deref(), Some("High"));
+    /// assert_eq!(codec.level.as_deref(), Some("4.0"));
+    /// ```
+    pub fn parse(mime_type: &str) -> Option<MediaType> {
+        let mut parts = mime_type.split(';');
+        let (kind, container) = parts.next()?.trim().split_once('/')?;
+
+        let kind = match kind.to_ascii_lowercase().as_str() {
+            "video" => MediaKind::Video,
+            "audio" => MediaKind::Audio,
+            _ => return None,
+        };
+
+        let codecs = parts
+            .filter_map(|parameter| parameter.split_once('='))
+            .filter(|(key, _)| key.trim().eq_ignore_ascii_case("codecs"))
+            .flat_map(|(_, value)| value.trim().trim_matches('"').split(','))
+            .map(str::trim)
+            .filter(|identifier| !identifier.is_empty())
+            .map(Self::parse_codec)
+            .collect();
+
+        Some(MediaType {
+            kind,
+            container: container.trim().to_ascii_lowercase(),
+            codecs,
+        })
+    }
+
+    /// Parses a single codec identifier.
+    ///
+    /// Unknown codecs are returned with the `Other` family, and malformed profile or
+    /// level fields are left empty rather than rejected.
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier` - The codec identifier (e.g., "vp09.02.51.10.01.09.16.09.00")
+    ///
+    /// # Returns
+    ///
+    /// * `Codec` - The decoded codec
+    pub fn parse_codec(identifier: &str) -> Codec {
+        let fields: Vec<&str> = identifier.split('.').collect();
+        let mut codec = Codec {
+            family: CodecFamily::Other,
+            identifier: identifier.to_string(),
+            profile: None,
+            level: None,
+            bit_depth: None,
+        };
+
+        match fields[0].to_ascii_lowercase().as_str() {
+            "avc1" | "avc3" => {
+                codec.family = CodecFamily::Avc;
+                Self::decode_avc(&mut codec, fields.get(1).copied());
+            }
+            "vp09" | "vp9" => {
+                codec.family = CodecFamily::Vp9;
+                Self::decode_vp9(&mut codec, &fields[1..]);
+            }
+            "av01" => {
+                codec.family = CodecFamily::Av1;
+                Self::decode_av1(&mut codec, &fields[1..]);
+            }
+            "opus" => codec.family = CodecFamily::Opus,
+            "mp4a" if fields.get(1) == Some(&"40") => {
+                codec.family = CodecFamily::Aac;
+                codec.profile = fields.get(2).and_then(|ot| Self::aac_profile(ot));
+            }
+            _ => {}
+        }
+
+        codec
+    }
+
+    /// Decodes the `PPCCLL` hexadecimal field of an AVC identifier.
+    fn decode_avc(codec: &mut Codec, field: Option<&str>) {
+        let Some(value) = field
+            .filter(|f| f.len() == 6)
+            .and_then(|f| u32::from_str_radix(f, 16).ok())
+        else {
+            return;
+        };
+        let profile_idc = (value >> 16) as u8;
+        let constraints = (value >> 8) as u8;
+        let level_idc = value as u8;
+
+        codec.profile = match profile_idc {
+            66 if constraints & 0x40 != 0 => Some("Constrained Baseline"),
+            66 => Some("Baseline"),
+            77 => Some("Main"),
+            88 => Some("Extended"),
+            100 => Some("High"),
+            110 => Some("High 10"),
+            122 => Some("High 4:2:2"),
+            244 => Some("High 4:4:4"),
+            _ => None,
+        }
+        .map(str::to_string);
+        codec.level = Some(format!("{}.{}", level_idc / 10, level_idc % 10));
+        codec.bit_depth = match profile_idc {
+            110 | 122 | 244 => Some(10),
+            _ => Some(8),
+        };
+    }
+
+    /// Decodes the `PP.LL.DD` decimal fields of a VP9 identifier.
+    fn decode_vp9(codec: &mut Codec, fields: &[&str]) {
+        let number = |index: usize| fields.get(index).and_then(|f| f.parse::<u8>().ok());
+
+        codec.profile = number(0).map(|profile| format!("Profile {}", profile));
+        codec.level = number(1).map(|level| format!("{}.{}", level / 10, level % 10));
+        codec.bit_depth = number(2);
+    }
+
+    /// Decodes the `P.LLT.DD` fields of an AV1 identifier.
+    fn decode_av1(codec: &mut Codec, fields: &[&str]) {
+        codec.profile = match fields.first().copied() {
+            Some("0") => Some("Main"),
+            Some("1") => Some("High"),
+            Some("2") => Some("Professional"),
+            _ => None,
+        }
+        .map(str::to_string);
+
+        // The level index maps to levels 2.0 to 7.3, four minor levels per major level
+        codec.level = fields
+            .get(1)
+            .and_then(|field| field.get(..2))
+            .and_then(|index| index.parse::<u8>().ok())
+            .filter(|index| *index < 24)
+            .map(|index| format!("{}.{}", 2 + index / 4, index % 4));
+        codec.bit_depth = fields.get(2).and_then(|f| f.parse().ok());
+    }
+
+    /// Returns the profile of an AAC audio object type.
+    fn aac_profile(object_type: &str) -> Option<String> {
+        let profile = match object_type {
+            "1" => "Main",
+            "2" => "LC",
+            "5" => "HE",
+            "23" => "LD",
+            "29" => "HE v2",
+            "39" => "ELD",
+            _ => return None,
+        };
+        Some(profile.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn describe(codec: &Codec) -> (CodecFamily, Option<&str>, Option<&str>, Option<u8>) {
+        (
+            codec.family,
+            codec.profile.as_deref(),
+            codec.level.as_deref(),
+            codec.bit_depth,
+        )
+    }
+
+    #[test]
+    fn test_parse_muxed() {
+        let media_type =
+            MediaTypeParser::parse("video/mp4; codecs=\"avc1.42001E, mp4a.40.2\"").unwrap();
+
+        assert_eq!(media_type.kind, MediaKind::Video);
+        assert_eq!(media_type.container, "mp4");
+        assert_eq!(media_type.codecs.len(), 2);
+        assert_eq!(
+            describe(media_type.video_codec().unwrap()),
+            (CodecFamily::Avc, Some("Baseline"), Some("3.0"), Some(8))
+        );
+        assert_eq!(
+            describe(media_type.audio_codec().unwrap()),
+            (CodecFamily::Aac, Some("LC"), None, None)
+        );
+        assert_eq!(media_type.codecs[1].identifier, "mp4a.40.2");
+    }
+
+    #[test]
+    fn test_parse_codecs() {
+        let cases = [
+            (
+                "avc1.42E01E",
+                (
+                    CodecFamily::Avc,
+                    Some("Constrained Baseline"),
+                    Some("3.0"),
+                    Some(8),
+                ),
+            ),
+            (
+                "avc1.4d401f",
+                (CodecFamily::Avc, Some("Main"), Some("3.1"), Some(8)),
+            ),
+            (
+                "avc1.640028",
+                (CodecFamily::Avc, Some("High"), Some("4.0"), Some(8)),
+            ),
+            ("vp9", (CodecFamily::Vp9, None, None, None)),
+            (
+                "vp09.02.51.10.01.09.16.09.00",
+                (CodecFamily::Vp9, Some("Profile 2"), Some("5.1"), Some(10)),
+            ),
+            (
+                "av01.0.08M.08",
+                (CodecFamily::Av1, Some("Main"), Some("4.0"), Some(8)),
+            ),
+            (
+                "av01.0.13M.10.0.110.09.16.09.0",
+                (CodecFamily::Av1, Some("Main"), Some("5.1"), Some(10)),
+            ),
+            ("opus", (CodecFamily::Opus, None, None, None)),
+            ("mp4a.40.5", (CodecFamily::Aac, Some("HE"), None, None)),
+            ("ec-3", (CodecFamily::Other, None, None, None)),
+            ("avc1.zz", (CodecFamily::Avc, None, None, None)),
+        ];
+
+        for (identifier, expected) in cases {
+            let codec = MediaTypeParser::parse_codec(identifier);
+            assert_eq!(describe(&codec), expected, "{}", identifier);
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let media_type = MediaTypeParser::parse("audio/webm").unwrap();
+        assert_eq!(media_type.kind, MediaKind::Audio);
+        assert!(media_type.codecs.is_empty());
+
+        assert!(MediaTypeParser::parse("text/vtt").is_none());
+        assert!(MediaTypeParser::parse("").is_none());
+    }
+}
@@ -1,3 +1,11 @@
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeDelta, Utc};
+
+use crate::dash_manifest::DashManifest;
+use crate::format_selector::FormatSelector;
+use crate::json_utils::parse_count;
+use crate::media_type_parser::MediaTypeParser;
 use crate::transcript_list::TranscriptList;
 use serde::{Deserialize, Serialize};
 
@@ -68,7 +76,7 @@ pub struct TranslationLanguage {
 ///     snippet.text);
 /// // Outputs: [5.2s-7.7s]: Hello, world!
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FetchedTranscriptSnippet {
     /// The text content of this snippet
     pub text: String,
@@ -81,6 +89,119 @@ pub struct FetchedTranscriptSnippet {
     pub duration: f64,
 }
 
+/// # BilingualSnippet
+///
+/// A pair of time-aligned snippets from two transcripts of the same video.
+///
+/// Produced by `BilingualTranscript::align`, typically from an original transcript
+/// and its translation. Either side may be missing when a snippet has no
+/// counterpart in the other transcript, and a side may combine the text of several
+/// snippets when the two transcripts are segmented differently.
+///
+/// ## Fields
+///
+/// * `start` - The timestamp when this pair appears (in seconds)
+/// * `duration` - How long this pair stays on screen (in seconds)
+/// * `primary` - Text from the primary transcript, if any
+/// * `secondary` - Text from the secondary transcript, if any
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BilingualSnippet {
+    /// The timestamp at which this pair appears on screen in seconds
+    pub start: f64,
+
+    /// The duration of how long the pair stays on screen in seconds
+    pub duration: f64,
+
+    /// Text from the primary transcript
+    pub primary: Option<String>,
+
+    /// Text from the secondary transcript
+    pub secondary: Option<String>,
+}
+
+/// # TranscriptSearchOptions
+///
+/// Controls how `FetchedTranscript::search` matches a query against the transcript.
+///
+/// By default the search is case-insensitive, matches substrings anywhere in the
+/// text, treats the query as literal text and returns 40 characters of context
+/// on each side of a match.
+///
+/// ## Example Usage
+///
+/// ```rust,no_run
+/// # use yt_transcript_rs::models::TranscriptSearchOptions;
+/// // Match "descent" as a whole word only, with more surrounding context
+/// let options = TranscriptSearchOptions {
+///     whole_word: true,
+///     context_chars: 80,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptSearchOptions {
+    /// Whether letter case must match exactly
+    pub case_sensitive: bool,
+
+    /// Whether the query must match whole words only
+    pub whole_word: bool,
+
+    /// Whether the query is a regular expression rather than literal text
+    pub regex: bool,
+
+    /// Number of characters of surrounding text returned on each side of a match
+    pub context_chars: usize,
+}
+
+impl Default for TranscriptSearchOptions {
+    fn default() -> Self {
+        Self {
+            case_sensitive: false,
+            whole_word: false,
+            regex: false,
+            context_chars: 40,
+        }
+    }
+}
+
+/// # TranscriptMatch
+///
+/// A single hit returned by `FetchedTranscript::search`.
+///
+/// Matches may span several consecutive snippets, in which case `start` is the
+/// start of the first snippet and `end` the end of the last one.
+///
+/// ## Fields
+///
+/// * `text` - The matched text
+/// * `start` - Start time in seconds of the first snippet containing the match
+/// * `end` - End time in seconds of the last snippet containing the match
+/// * `first_snippet` / `last_snippet` - Indices of the snippets spanned by the match
+/// * `context_before` / `context_after` - Transcript text surrounding the match
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptMatch {
+    /// The matched text
+    pub text: String,
+
+    /// Start time in seconds of the first snippet containing the match
+    pub start: f64,
+
+    /// End time in seconds of the last snippet containing the match
+    pub end: f64,
+
+    /// Index of the first snippet containing the match
+    pub first_snippet: usize,
+
+    /// Index of the last snippet containing the match
+    pub last_snippet: usize,
+
+    /// Transcript text immediately preceding the match
+    pub context_before: String,
+
+    /// Transcript text immediately following the match
+    pub context_after: String,
+}
+
 /// # VideoDetails
 ///
 /// Comprehensive metadata about a YouTube video.
@@ -158,6 +279,27 @@ pub struct VideoDetails {
     pub is_live_content: bool,
 }
 
+impl VideoDetails {
+    /// Returns the number of views as a number.
+    ///
+    /// Returns `None` if `view_count` is not a valid number. Thousands separators,
+    /// surrounding whitespace and a label such as "views" are ignored; a number
+    /// attached to other characters ("abc12", "1.2M") is not valid.
+    pub fn parsed_view_count(&self) -> Option<u64> {
+        parse_count(&self.view_count)
+    }
+
+    /// Returns the duration of the video.
+    pub fn length(&self) -> Duration {
+        Duration::from_secs(u64::from(self.length_seconds))
+    }
+
+    /// Returns the URLs, hashtags, @mentions and timestamps of the description.
+    pub fn description_links(&self) -> Vec<DescriptionLink> {
+        crate::links_extractor::LinksExtractor::parse_description(&self.short_description)
+    }
+}
+
 /// # VideoThumbnail
 ///
 /// Represents a single thumbnail image for a YouTube video.
@@ -247,6 +389,57 @@ pub struct MicroformatData {
     pub view_count: Option<String>,
 }
 
+impl MicroformatData {
+    /// Returns the duration of the video, if `length_seconds` is present and valid.
+    pub fn parsed_length(&self) -> Option<Duration> {
+        self.length_seconds
+            .as_deref()
+            .and_then(parse_count)
+            .map(Duration::from_secs)
+    }
+
+    /// Returns the number of likes, if `like_count` is present and valid.
+    pub fn parsed_like_count(&self) -> Option<u64> {
+        self.like_count.as_deref().and_then(parse_count)
+    }
+
+    /// Returns the number of views, if `view_count` is present and valid.
+    pub fn parsed_view_count(&self) -> Option<u64> {
+        self.view_count.as_deref().and_then(parse_count)
+    }
+
+    /// Returns the publication date, if `publish_date` is present and valid.
+    ///
+    /// See [`MicroformatData::parsed_upload_date`] for the accepted formats.
+    pub fn parsed_publish_date(&self) -> Option<DateTime<FixedOffset>> {
+        self.publish_date.as_deref().and_then(parse_typed_date)
+    }
+
+    /// Returns the upload date, if `upload_date` is present and valid.
+    ///
+    /// YouTube returns RFC 3339 timestamps with the uploader's UTC offset
+    /// ("2016-04-06T09:59:35-07:00"), while older videos only have a date
+    /// ("2016-04-06"). Dates and timestamps without an offset are interpreted as UTC.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let microformat = api.fetch_microformat("dQw4w9WgXcQ").await?;
+    ///
+    /// if let Some(uploaded) = microformat.parsed_upload_date() {
+    ///     println!("Uploaded on {}", uploaded.format("%B %-d, %Y"));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parsed_upload_date(&self) -> Option<DateTime<FixedOffset>> {
+        self.upload_date.as_deref().and_then(parse_typed_date)
+    }
+}
+
 /// Represents embed information in microformat data
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 pub struct MicroformatEmbed {
@@ -274,6 +467,21 @@ pub struct Range {
     pub end: String,
 }
 
+/// Represents the `signatureCipher` of a streaming format whose URL must be signed
+///
+/// YouTube protects some formats by scrambling their signature: the format has no
+/// `url`, and the media URL is `url` with the deciphered `signature` added as the
+/// `signature_param` query parameter. See `SignatureDecipher`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct SignatureCipher {
+    /// Media URL without its signature
+    pub url: String,
+    /// Scrambled signature (`s`)
+    pub signature: String,
+    /// Name of the query parameter receiving the deciphered signature (`sp`)
+    pub signature_param: String,
+}
+
 /// Represents color information for a video format
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 pub struct ColorInfo {
@@ -290,12 +498,15 @@ pub struct ColorInfo {
 pub struct StreamingFormat {
     /// Format identification number
     pub itag: u32,
-    /// URL to the media
+    /// URL to the media, `None` while the signature has not been deciphered
     pub url: Option<String>,
+    /// Signature to decipher before the media can be downloaded, if the format is protected
+    #[serde(default)]
+    pub signature_cipher: Option<SignatureCipher>,
     /// MIME type and codec information
     pub mime_type: String,
     /// Bitrate in bits per second
-    pub bitrate: u64,
+    pub bitrate: Option<u64>,
     /// Video width in pixels (video only)
     pub width: Option<u32>,
     /// Video height in pixels (video only)
@@ -309,19 +520,19 @@ pub struct StreamingFormat {
     /// Content length in bytes
     pub content_length: Option<String>,
     /// Quality label (e.g., "medium", "hd720")
-    pub quality: String,
+    pub quality: Option<String>,
     /// Frames per second (video only)
     pub fps: Option<u32>,
     /// Human-readable quality label (e.g., "720p")
     pub quality_label: Option<String>,
     /// Projection type (e.g., "RECTANGULAR")
-    pub projection_type: String,
+    pub projection_type: Option<String>,
     /// Average bitrate in bits per second
     pub average_bitrate: Option<u64>,
     /// Audio quality (audio only)
     pub audio_quality: Option<String>,
-    /// Approximate duration in milliseconds
-    pub approx_duration_ms: String,
+    /// Approximate duration in milliseconds, missing for live streams
+    pub approx_duration_ms: Option<String>,
     /// Audio sample rate (audio only)
     pub audio_sample_rate: Option<String>,
     /// Number of audio channels (audio only)
@@ -338,6 +549,206 @@ pub struct StreamingFormat {
     pub is_drc: Option<bool>,
     /// Extra tags
     pub xtags: Option<String>,
+    /// Fields of the format that are not mapped to the fields above, and mapped
+    /// fields whose value has an unexpected type (e.g. `"bitrate": "130000"`)
+    #[serde(default)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl StreamingFormat {
+    /// Returns the container of the format (e.g., "mp4", "webm"), from its MIME type.
+    pub fn container(&self) -> Option<String> {
+        self.media_type().map(|media_type| media_type.container)
+    }
+
+    /// Returns the codecs of the format (e.g., `["avc1.42001E", "mp4a.40.2"]`), from its MIME type.
+    pub fn codecs(&self) -> Vec<String> {
+        self.media_type()
+            .map(|media_type| {
+                media_type
+                    .codecs
+                    .into_iter()
+                    .map(|codec| codec.identifier)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the format contains a video track.
+    pub fn has_video(&self) -> bool {
+        self.media_type()
+            .is_some_and(|media_type| media_type.kind == MediaKind::Video)
+    }
+
+    /// Returns whether the format contains an audio track.
+    ///
+    /// Muxed formats have a `video/` MIME type, so they are recognized by the audio
+    /// codec in their codec list, or by a second codec of an unknown family.
+    pub fn has_audio(&self) -> bool {
+        self.media_type().is_some_and(|media_type| {
+            media_type.kind == MediaKind::Audio
+                || media_type.audio_codec().is_some()
+                || media_type.codecs.len() > 1
+        })
+    }
+
+    /// Returns whether the format contains both video and audio.
+    pub fn is_muxed(&self) -> bool {
+        self.has_video() && self.has_audio()
+    }
+
+    /// Returns whether the format is HDR, based on the transfer characteristics of its color info.
+    pub fn is_hdr(&self) -> bool {
+        self.color_info
+            .as_ref()
+            .and_then(|info| info.transfer_characteristics.as_deref())
+            .is_some_and(|transfer| {
+                matches!(
+                    transfer,
+                    "COLOR_TRANSFER_CHARACTERISTICS_SMPTEST2084"
+                        | "COLOR_TRANSFER_CHARACTERISTICS_ARIB_STD_B67"
+                )
+            })
+    }
+
+    /// Returns whether the audio track uses dynamic range compression.
+    pub fn is_drc(&self) -> bool {
+        self.is_drc.unwrap_or(false)
+    }
+
+    /// Returns the audio quality as a typed value, or `None` for formats without audio.
+    pub fn parsed_audio_quality(&self) -> Option<AudioQuality> {
+        match self.audio_quality.as_deref()? {
+            "AUDIO_QUALITY_ULTRALOW" => Some(AudioQuality::UltraLow),
+            "AUDIO_QUALITY_LOW" => Some(AudioQuality::Low),
+            "AUDIO_QUALITY_MEDIUM" => Some(AudioQuality::Medium),
+            "AUDIO_QUALITY_HIGH" => Some(AudioQuality::High),
+            _ => None,
+        }
+    }
+
+    /// Returns the size of the media in bytes.
+    ///
+    /// Returns `None` if `content_length` is missing or not a valid number.
+    pub fn parsed_content_length(&self) -> Option<u64> {
+        self.content_length.as_deref().and_then(parse_count)
+    }
+
+    /// Returns when the URL of the format expires, read from its `expire` parameter.
+    ///
+    /// Protected formats are read from the URL of their signature cipher.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.url
+            .as_deref()
+            .or(self
+                .signature_cipher
+                .as_ref()
+                .map(|cipher| cipher.url.as_str()))
+            .and_then(parse_url_expiry)
+    }
+
+    /// Returns the MIME type parsed into its kind, container and decoded codecs.
+    ///
+    /// Returns `None` if `mime_type` is not an audio or video MIME type.
+    pub fn media_type(&self) -> Option<MediaType> {
+        MediaTypeParser::parse(&self.mime_type)
+    }
+}
+
+/// Top-level type of a streaming format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaKind {
+    /// `video/*`, which includes muxed formats
+    Video,
+    /// `audio/*`
+    Audio,
+}
+
+/// Codec family of a track
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CodecFamily {
+    /// H.264 / AVC (`avc1`)
+    Avc,
+    /// VP9 (`vp9`, `vp09`)
+    Vp9,
+    /// AV1 (`av01`)
+    Av1,
+    /// Opus (`opus`)
+    Opus,
+    /// AAC (`mp4a.40`)
+    Aac,
+    /// Any other codec, such as VP8 or AC-3
+    Other,
+}
+
+impl CodecFamily {
+    /// Returns whether the family is a video codec.
+    pub fn is_video(self) -> bool {
+        matches!(self, CodecFamily::Avc | CodecFamily::Vp9 | CodecFamily::Av1)
+    }
+
+    /// Returns whether the family is an audio codec.
+    pub fn is_audio(self) -> bool {
+        matches!(self, CodecFamily::Opus | CodecFamily::Aac)
+    }
+}
+
+/// Represents a codec of a MIME type, with its profile and level when encoded in its identifier
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Codec {
+    /// Codec family
+    pub family: CodecFamily,
+    /// Codec identifier as written in the MIME type (e.g., "avc1.640028")
+    pub identifier: String,
+    /// Profile name (e.g., "High", "Profile 2", "Main", "LC")
+    pub profile: Option<String>,
+    /// Level (e.g., "4.0", "5.1")
+    pub level: Option<String>,
+    /// Bit depth of the samples (video only)
+    pub bit_depth: Option<u8>,
+}
+
+/// Represents a parsed MIME type such as `video/mp4; codecs="avc1.640028"`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MediaType {
+    /// Top-level type
+    pub kind: MediaKind,
+    /// Container (e.g., "mp4", "webm")
+    pub container: String,
+    /// Codecs of the tracks, in the order of the MIME type
+    pub codecs: Vec<Codec>,
+}
+
+impl MediaType {
+    /// Returns the video codec, if any.
+    pub fn video_codec(&self) -> Option<&Codec> {
+        self.codecs.iter().find(|codec| codec.family.is_video())
+    }
+
+    /// Returns the audio codec, if any.
+    pub fn audio_codec(&self) -> Option<&Codec> {
+        self.codecs.iter().find(|codec| codec.family.is_audio())
+    }
+
+    /// Returns whether one of the codecs belongs to `family`.
+    pub fn has_codec(&self, family: CodecFamily) -> bool {
+        self.codecs.iter().any(|codec| codec.family == family)
+    }
+}
+
+/// Audio quality of a streaming format, ordered from lowest to highest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum AudioQuality {
+    /// `AUDIO_QUALITY_ULTRALOW`
+    UltraLow,
+    /// `AUDIO_QUALITY_LOW`
+    Low,
+    /// `AUDIO_QUALITY_MEDIUM`
+    Medium,
+    /// `AUDIO_QUALITY_HIGH`
+    High,
 }
 
 /// Represents all available streaming data for a YouTube video
@@ -351,6 +762,926 @@ pub struct StreamingData {
     pub adaptive_formats: Vec<StreamingFormat>,
     /// Server ABR streaming URL
     pub server_abr_streaming_url: Option<String>,
+    /// URL of the HLS master playlist, for live streams and their recordings
+    #[serde(default)]
+    pub hls_manifest_url: Option<String>,
+    /// URL of the DASH manifest, for live streams and their recordings
+    #[serde(default)]
+    pub dash_manifest_url: Option<String>,
+    /// When the streaming data was requested, the reference of `expires_in_seconds`.
+    ///
+    /// Set by the fetcher; `None` for streaming data extracted from a stored response.
+    #[serde(default)]
+    pub fetched_at: Option<DateTime<Utc>>,
+    /// Formats of the response that could not be parsed, with the reason
+    #[serde(default)]
+    pub skipped_formats: Vec<SkippedFormat>,
+}
+
+/// Represents a format of the player response that was left out of the streaming data
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct SkippedFormat {
+    /// Whether the format is in the adaptive formats, rather than the muxed formats
+    pub adaptive: bool,
+    /// Position of the format in its list
+    pub index: usize,
+    /// Format identification number, if present
+    pub itag: Option<u32>,
+    /// Why the format was left out (e.g., "missing mimeType")
+    pub reason: String,
+}
+
+impl StreamingData {
+    /// Returns all formats, muxed formats first, then adaptive formats.
+    pub fn all_formats(&self) -> impl Iterator<Item = &StreamingFormat> {
+        self.formats.iter().chain(self.adaptive_formats.iter())
+    }
+
+    /// Returns when the streaming URLs expire.
+    ///
+    /// This is the earliest `expire` parameter of the format and manifest URLs,
+    /// falling back to `fetched_at` plus `expires_in_seconds` when no URL has one.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let mut streaming_data = api.fetch_streaming_data("dQw4w9WgXcQ").await?;
+    /// println!("URLs expire at {:?}", streaming_data.expires_at());
+    ///
+    /// // Later: fetch the streaming data again if the URLs have expired
+    /// api.refresh_streaming_data("dQw4w9WgXcQ", &mut streaming_data).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        let from_urls = self
+            .all_formats()
+            .filter_map(StreamingFormat::expires_at)
+            .chain(
+                [&self.hls_manifest_url, &self.dash_manifest_url]
+                    .into_iter()
+                    .filter_map(|url| url.as_deref().and_then(parse_url_expiry)),
+            )
+            .min();
+
+        from_urls.or_else(|| {
+            let seconds = self.expires_in_seconds.trim().parse::<i64>().ok()?;
+            self.fetched_at?
+                .checked_add_signed(TimeDelta::try_seconds(seconds)?)
+        })
+    }
+
+    /// Returns whether the streaming URLs have expired.
+    ///
+    /// Returns `false` if the expiry is unknown.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at()
+            .is_some_and(|expires_at| expires_at <= Utc::now())
+    }
+
+    /// Starts a query over all formats.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let streaming_data = api.fetch_streaming_data("dQw4w9WgXcQ").await?;
+    ///
+    /// // Best 720p or lower mp4 video at 30 fps or less
+    /// let format = streaming_data
+    ///     .select()
+    ///     .video_only()
+    ///     .container("mp4")
+    ///     .max_height(720)
+    ///     .max_fps(30)
+    ///     .best();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn select(&self) -> FormatSelector<'_> {
+        FormatSelector::new(self.all_formats())
+    }
+
+    /// Returns the best audio-only format, preferring formats without dynamic range compression.
+    pub fn best_audio(&self) -> Option<&StreamingFormat> {
+        self.select().audio_only().best()
+    }
+
+    /// Returns the best video-only format.
+    pub fn best_video(&self) -> Option<&StreamingFormat> {
+        self.select().video_only().best()
+    }
+
+    /// Returns the best video-only format with a height of at most `height` pixels.
+    pub fn best_video_under(&self, height: u32) -> Option<&StreamingFormat> {
+        self.select().video_only().max_height(height).best()
+    }
+
+    /// Returns the best format with both video and audio.
+    pub fn best_muxed(&self) -> Option<&StreamingFormat> {
+        self.select().muxed().best()
+    }
+
+    /// Returns the smallest format with both video and audio.
+    pub fn smallest_muxed(&self) -> Option<&StreamingFormat> {
+        self.select().muxed().smallest()
+    }
+
+    /// Generates a DASH manifest (MPD) of the adaptive formats.
+    ///
+    /// See [`DashManifest`] for the formats included in the manifest.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use yt_transcript_rs::api::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let streaming_data = api.fetch_streaming_data("dQw4w9WgXcQ").await?;
+    ///
+    /// std::fs::write("video.mpd", streaming_data.to_dash_mpd())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_dash_mpd(&self) -> String {
+        DashManifest::generate(self, None)
+    }
+
+    /// Generates a DASH manifest (MPD) of the adaptive formats, with the transcripts
+    /// of the list as WebVTT subtitle tracks.
+    pub fn to_dash_mpd_with_subtitles(&self, transcript_list: &TranscriptList) -> String {
+        DashManifest::generate(self, Some(transcript_list))
+    }
+}
+
+/// Represents the live stream and premiere state of a video
+///
+/// Regular uploads have every flag set to `false` and no timestamps. Premieres are
+/// uploads broadcast like a live stream at a scheduled time, so they share most of
+/// the live stream metadata while they are upcoming or airing.
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct LiveDetails {
+    /// Whether the video is or was a live stream
+    pub is_live_content: bool,
+    /// Whether the video is currently broadcasting live
+    pub is_live_now: bool,
+    /// Whether the live stream or premiere has not started yet
+    pub is_upcoming: bool,
+    /// Whether the video is a premiere rather than a live stream
+    pub is_premiere: bool,
+    /// When an upcoming live stream or premiere is scheduled to start
+    pub scheduled_start_time: Option<DateTime<FixedOffset>>,
+    /// When the broadcast actually started
+    pub start_timestamp: Option<DateTime<FixedOffset>>,
+    /// When the broadcast ended, if it has ended
+    pub end_timestamp: Option<DateTime<FixedOffset>>,
+}
+
+/// Represents the position of one preview frame within a storyboard sprite sheet
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct StoryboardFrame {
+    /// URL of the sprite sheet containing the frame
+    pub url: String,
+    /// Horizontal offset of the frame in the sheet, in pixels
+    pub x: u32,
+    /// Vertical offset of the frame in the sheet, in pixels
+    pub y: u32,
+    /// Width of the frame, in pixels
+    pub width: u32,
+    /// Height of the frame, in pixels
+    pub height: u32,
+    /// Time of the video shown by the frame, in seconds
+    pub start: f64,
+}
+
+/// Represents one resolution of a video's storyboard
+///
+/// Preview frames are packed in sprite sheets of `columns` x `rows` frames, filled
+/// row by row; a level has as many sheets as needed to hold `frame_count` frames.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct StoryboardLevel {
+    /// Index of the level in the spec, from the lowest resolution
+    pub level: u32,
+    /// Width of each frame, in pixels
+    pub width: u32,
+    /// Height of each frame, in pixels
+    pub height: u32,
+    /// Total number of frames of the level
+    pub frame_count: u32,
+    /// Number of frames per row of a sheet
+    pub columns: u32,
+    /// Number of frame rows of a sheet
+    pub rows: u32,
+    /// Time between two frames, in seconds
+    pub interval: f64,
+    /// URL of the sheets, with `$M` standing for the sheet number
+    pub url_template: String,
+}
+
+impl StoryboardLevel {
+    /// Returns the number of frames held by one sheet.
+    pub fn frames_per_sheet(&self) -> u32 {
+        self.columns.saturating_mul(self.rows).max(1)
+    }
+
+    /// Returns the number of sheets of the level.
+    pub fn sheet_count(&self) -> u32 {
+        self.frame_count.div_ceil(self.frames_per_sheet()).max(1)
+    }
+
+    /// Returns the URL of a sheet.
+    pub fn sheet_url(&self, sheet: u32) -> String {
+        self.url_template.replace("$M", &sheet.to_string())
+    }
+
+    /// Returns the URLs of all sheets, in chronological order.
+    pub fn sheet_urls(&self) -> Vec<String> {
+        (0..self.sheet_count())
+            .map(|sheet| self.sheet_url(sheet))
+            .collect()
+    }
+
+    /// Returns the frame showing the video at `time`.
+    ///
+    /// Times past the last frame map to the last frame. Returns `None` for negative
+    /// times or a level without frames.
+    pub fn frame_at(&self, time: f64) -> Option<StoryboardFrame> {
+        if time < 0.0 || self.frame_count == 0 || self.interval <= 0.0 {
+            return None;
+        }
+
+        let index = ((time / self.interval) as u32).min(self.frame_count - 1);
+        let position = index % self.frames_per_sheet();
+
+        Some(StoryboardFrame {
+            url: self.sheet_url(index / self.frames_per_sheet()),
+            x: (position % self.columns.max(1)).saturating_mul(self.width),
+            y: (position / self.columns.max(1)).saturating_mul(self.height),
+            width: self.width,
+            height: self.height,
+            start: index as f64 * self.interval,
+        })
+    }
+}
+
+/// Progress of a format download, reported after each received block of data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct DownloadProgress {
+    /// Bytes written to the file so far, including bytes of a resumed partial file
+    pub downloaded: u64,
+    /// Total size of the stream in bytes, if known
+    pub total: Option<u64>,
+    /// Size of the partial file the download was resumed from
+    pub resumed_from: u64,
+}
+
+impl DownloadProgress {
+    /// Returns the downloaded fraction of the stream, between 0 and 1, if the size is known.
+    pub fn fraction(&self) -> Option<f64> {
+        self.total
+            .filter(|total| *total > 0)
+            .map(|total| (self.downloaded as f64 / total as f64).min(1.0))
+    }
+}
+
+/// Represents an HLS master playlist: the variant streams of a live video
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct HlsMasterPlaylist {
+    /// Variant streams, in playlist order
+    pub variants: Vec<HlsVariant>,
+}
+
+impl HlsMasterPlaylist {
+    /// Returns the variant with the highest bandwidth.
+    pub fn best(&self) -> Option<&HlsVariant> {
+        self.variants.iter().max_by_key(|variant| variant.bandwidth)
+    }
+
+    /// Returns the variant with the highest bandwidth and a height of at most `height` pixels.
+    pub fn best_under(&self, height: u32) -> Option<&HlsVariant> {
+        self.variants
+            .iter()
+            .filter(|variant| variant.height.is_some_and(|h| h <= height))
+            .max_by_key(|variant| variant.bandwidth)
+    }
+
+    /// Returns the variant of a format, by itag.
+    pub fn variant(&self, itag: u32) -> Option<&HlsVariant> {
+        self.variants
+            .iter()
+            .find(|variant| variant.itag == Some(itag))
+    }
+}
+
+/// Represents a variant stream of an HLS master playlist (`#EXT-X-STREAM-INF`)
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct HlsVariant {
+    /// URL of the media playlist of the variant
+    pub url: String,
+    /// Peak bitrate in bits per second (`BANDWIDTH`)
+    pub bandwidth: u64,
+    /// Average bitrate in bits per second (`AVERAGE-BANDWIDTH`)
+    pub average_bandwidth: Option<u64>,
+    /// Codec identifiers (`CODECS`), e.g. `["avc1.4d401f", "mp4a.40.2"]`
+    pub codecs: Vec<String>,
+    /// Width in pixels (`RESOLUTION`)
+    pub width: Option<u32>,
+    /// Height in pixels (`RESOLUTION`)
+    pub height: Option<u32>,
+    /// Frames per second (`FRAME-RATE`)
+    pub frame_rate: Option<f64>,
+    /// YouTube format identifier, read from the `/itag/` path segment of the URL
+    pub itag: Option<u32>,
+}
+
+/// Represents an HLS media playlist: the segments of a variant stream
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct HlsMediaPlaylist {
+    /// Maximum segment duration in seconds (`#EXT-X-TARGETDURATION`)
+    pub target_duration: u32,
+    /// Sequence number of the first segment (`#EXT-X-MEDIA-SEQUENCE`)
+    pub media_sequence: u64,
+    /// Segments, in playback order
+    pub segments: Vec<HlsSegment>,
+    /// Whether the playlist is complete (`#EXT-X-ENDLIST`); `false` while the stream is live
+    pub ended: bool,
+}
+
+impl HlsMediaPlaylist {
+    /// Returns the total duration of the segments in seconds.
+    pub fn duration(&self) -> f64 {
+        self.segments.iter().map(|segment| segment.duration).sum()
+    }
+}
+
+/// Represents a segment of an HLS media playlist
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct HlsSegment {
+    /// Media sequence number of the segment
+    pub sequence: u64,
+    /// Duration in seconds (`#EXTINF`)
+    pub duration: f64,
+    /// URL of the segment
+    pub url: String,
+    /// Wall-clock time of the first sample (`#EXT-X-PROGRAM-DATE-TIME`)
+    pub program_date_time: Option<String>,
+}
+
+/// Represents the storyboard of a video: the preview frames shown when hovering the
+/// progress bar, at several resolutions
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Storyboard {
+    /// Levels of the storyboard, from the lowest to the highest resolution
+    pub levels: Vec<StoryboardLevel>,
+}
+
+impl Storyboard {
+    /// Returns the level with the largest frames.
+    pub fn highest_resolution(&self) -> Option<&StoryboardLevel> {
+        self.levels
+            .iter()
+            .max_by_key(|level| level.width.saturating_mul(level.height))
+    }
+
+    /// Returns the frame showing the video at `time`, from the highest resolution level.
+    pub fn frame_at(&self, time: f64) -> Option<StoryboardFrame> {
+        self.highest_resolution()?.frame_at(time)
+    }
+}
+
+/// Represents a chapter of a video
+///
+/// Chapters cover the video without gaps: each chapter ends where the next one
+/// starts, and the last one ends with the video.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Chapter {
+    /// Title of the chapter
+    pub title: String,
+    /// Start time in seconds
+    pub start: f64,
+    /// End time in seconds
+    pub end: f64,
+}
+
+/// Represents one segment of the "most replayed" heatmap of a video
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct HeatmapSegment {
+    /// Start time in seconds
+    pub start: f64,
+    /// End time in seconds
+    pub end: f64,
+    /// How often the segment is replayed, from 0.0 (least) to 1.0 (most replayed segment)
+    pub intensity: f64,
+}
+
+/// Represents a labelled moment of the heatmap, such as the "Most replayed" peak or
+/// a key moment marked by YouTube on the progress bar
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct KeyMoment {
+    /// Label shown by the player (e.g., "Most replayed" or the title of a key moment)
+    pub label: String,
+    /// Start of the highlighted range, in seconds
+    pub start: f64,
+    /// End of the highlighted range, in seconds
+    pub end: f64,
+    /// Time of the peak within the range, in seconds
+    pub peak: f64,
+}
+
+/// Represents the "most replayed" heatmap shown above the player's progress bar
+///
+/// YouTube only computes heatmaps for videos with enough views. The video is split
+/// into segments of equal length (usually 100), each with an intensity normalized
+/// so that the most replayed segment has an intensity of 1.0.
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Heatmap {
+    /// Segments of the heatmap, in chronological order
+    pub segments: Vec<HeatmapSegment>,
+    /// Labelled moments of the heatmap
+    pub key_moments: Vec<KeyMoment>,
+}
+
+impl Heatmap {
+    /// Returns the `n` most replayed segments, most replayed first.
+    ///
+    /// Segments with the same intensity are returned in chronological order.
+    pub fn most_replayed(&self, n: usize) -> Vec<&HeatmapSegment> {
+        let mut segments: Vec<&HeatmapSegment> = self.segments.iter().collect();
+        segments.sort_by(|a, b| {
+            b.intensity
+                .total_cmp(&a.intensity)
+                .then(a.start.total_cmp(&b.start))
+        });
+        segments.truncate(n);
+        segments
+    }
+
+    /// Returns the intensity of the segment containing `time`, or `None` outside the heatmap.
+    pub fn intensity_at(&self, time: f64) -> Option<f64> {
+        self.segments
+            .iter()
+            .find(|segment| segment.start <= time && time < segment.end)
+            .map(|segment| segment.intensity)
+    }
+}
+
+/// Represents the destination of an endscreen element or a card
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", content = "id", rename_all = "snake_case")]
+pub enum LinkTarget {
+    /// A video, by its ID
+    Video(String),
+    /// A playlist, by its ID
+    Playlist(String),
+    /// A channel, by its ID
+    Channel(String),
+    /// An external website, with YouTube's redirect removed
+    Url(String),
+}
+
+/// Kind of an endscreen element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EndscreenElementKind {
+    /// A video, either chosen by the creator or recommended by YouTube
+    Video,
+    /// A playlist
+    Playlist,
+    /// A channel other than the uploader's
+    Channel,
+    /// The uploader's subscribe button
+    Subscribe,
+    /// An external website
+    Link,
+}
+
+/// Represents an element shown over the last seconds of a video
+///
+/// Positions and sizes are fractions of the player size, so `left: 0.5` is the
+/// horizontal center of the player.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct EndscreenElement {
+    /// Kind of the element
+    pub kind: EndscreenElementKind,
+    /// Title of the linked content
+    pub title: String,
+    /// Destination of the element, if it has one
+    pub target: Option<LinkTarget>,
+    /// Time at which the element appears, in seconds
+    pub start: f64,
+    /// Time at which the element disappears, in seconds
+    pub end: f64,
+    /// Horizontal position of the left edge, as a fraction of the player width
+    pub left: f64,
+    /// Vertical position of the top edge, as a fraction of the player height
+    pub top: f64,
+    /// Width, as a fraction of the player width
+    pub width: f64,
+    /// Width divided by height
+    pub aspect_ratio: f64,
+}
+
+/// Represents an info card, the "i" teaser shown in the top right corner of the player
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Card {
+    /// Title of the linked content
+    pub title: String,
+    /// Teaser message shown when the card pops up
+    pub teaser: Option<String>,
+    /// Destination of the card, if it has one
+    pub target: Option<LinkTarget>,
+    /// Time at which the card pops up, in seconds
+    pub start: f64,
+}
+
+/// Kind of a link found in a video description
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DescriptionLinkKind {
+    /// A web address (e.g., `https://example.com/page`)
+    Url,
+    /// A hashtag (e.g., "#rust")
+    Hashtag,
+    /// A channel handle (e.g., "@YouTube")
+    Mention,
+    /// A timestamp jumping to a time of the video (e.g., "1:02:03")
+    Timestamp {
+        /// Time the timestamp points to, in seconds
+        seconds: f64,
+    },
+}
+
+/// Represents a link found in a video description
+///
+/// Offsets are byte offsets into the description, so
+/// `&description[link.start..link.end] == link.text`.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct DescriptionLink {
+    /// Kind of the link
+    pub kind: DescriptionLinkKind,
+    /// Text of the link as written in the description
+    pub text: String,
+    /// Byte offset of the first character of the link
+    pub start: usize,
+    /// Byte offset just past the last character of the link
+    pub end: usize,
+}
+
+/// Represents the links a creator attached to a video
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct VideoLinks {
+    /// Elements of the endscreen, in order of appearance
+    pub endscreen: Vec<EndscreenElement>,
+    /// Info cards, in order of appearance
+    pub cards: Vec<Card>,
+    /// Links found in the description, in order of appearance
+    pub description: Vec<DescriptionLink>,
+}
+
+impl VideoLinks {
+    /// Returns the IDs of the videos linked from the endscreen and cards, without duplicates.
+    pub fn video_ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = Vec::new();
+        let targets = self
+            .endscreen
+            .iter()
+            .filter_map(|element| element.target.as_ref())
+            .chain(self.cards.iter().filter_map(|card| card.target.as_ref()));
+
+        for target in targets {
+            if let LinkTarget::Video(id) = target {
+                if !ids.contains(&id.as_str()) {
+                    ids.push(id);
+                }
+            }
+        }
+        ids
+    }
+}
+
+/// Represents a video from the "related videos" sidebar of a watch page
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct RelatedVideo {
+    /// The video's unique identifier
+    pub video_id: String,
+    /// Title of the video
+    pub title: String,
+    /// Name of the channel that uploaded the video
+    pub channel_name: Option<String>,
+    /// Display length of the video (e.g., "12:34")
+    pub length_text: Option<String>,
+    /// Display view count (e.g., "1.2M views")
+    pub view_count_text: Option<String>,
+}
+
+/// Represents data extracted from the `ytInitialData` variable of a watch page
+///
+/// `ytInitialData` holds the page layout rather than the player configuration, and
+/// carries information that is missing from the player response, such as the like
+/// count, channel badges and related videos. Every field is optional since YouTube
+/// frequently changes the layout of this data.
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+pub struct InitialData {
+    /// Number of likes, when YouTube exposes the exact count
+    pub like_count: Option<u64>,
+    /// Display like count (e.g., "12K")
+    pub like_count_text: Option<String>,
+    /// Display publication date (e.g., "Oct 25, 2009")
+    pub date_text: Option<String>,
+    /// Display view count (e.g., "1,234,567 views")
+    pub view_count_text: Option<String>,
+    /// Name of the channel that uploaded the video
+    pub channel_name: Option<String>,
+    /// Badges shown next to the channel name (e.g., "Verified")
+    pub channel_badges: Vec<String>,
+    /// Display subscriber count of the channel (e.g., "3.9M subscribers")
+    pub subscriber_count_text: Option<String>,
+    /// Videos listed in the "related videos" sidebar
+    pub related_videos: Vec<RelatedVideo>,
+    /// Parameters of the InnerTube `get_transcript` endpoint, if the video has a transcript panel
+    pub transcript_params: Option<String>,
+    /// Continuation token of the first page of comments, if comments are enabled
+    #[serde(default)]
+    pub comments_continuation: Option<String>,
+}
+
+/// Represents a video listed in a playlist
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct PlaylistEntry {
+    /// The video's unique identifier
+    pub video_id: String,
+    /// Title of the video (e.g., "[Private video]" for unavailable entries)
+    pub title: String,
+    /// Name of the channel that uploaded the video
+    pub author: Option<String>,
+    /// Length of the video in seconds, if known
+    pub length_seconds: Option<u32>,
+    /// Zero-based position of the video in the playlist
+    pub index: usize,
+    /// Whether the video can be played (false for private or deleted videos)
+    pub is_available: bool,
+}
+
+/// Represents a YouTube playlist and its videos
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct Playlist {
+    /// The playlist's unique identifier
+    pub playlist_id: String,
+    /// Title of the playlist
+    pub title: Option<String>,
+    /// Name of the playlist's owner
+    pub author: Option<String>,
+    /// Description of the playlist
+    pub description: Option<String>,
+    /// Videos of the playlist, in playlist order
+    pub entries: Vec<PlaylistEntry>,
+}
+
+impl Playlist {
+    /// Returns the IDs of the videos that can be played, in playlist order.
+    pub fn available_video_ids(&self) -> Vec<&str> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.is_available)
+            .map(|entry| entry.video_id.as_str())
+            .collect()
+    }
+}
+
+/// A tab of a channel page listing videos
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum ChannelTab {
+    /// Regular uploads ("Videos" tab)
+    Videos,
+    /// Short-form vertical videos ("Shorts" tab)
+    Shorts,
+    /// Past, current and upcoming live streams ("Live" tab)
+    Live,
+}
+
+/// Represents a video listed on a channel tab
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ChannelVideo {
+    /// The video's unique identifier
+    pub video_id: String,
+    /// Title of the video
+    pub title: String,
+    /// Length of the video in seconds, if shown (not shown for shorts and live streams)
+    pub length_seconds: Option<u32>,
+    /// Display publication time (e.g., "3 weeks ago")
+    pub published_time_text: Option<String>,
+    /// Display view count (e.g., "1.2M views")
+    pub view_count_text: Option<String>,
+}
+
+/// Represents the videos of one tab of a channel
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ChannelUploads {
+    /// The channel's unique identifier (e.g., "UCuAXFkgsw1L7xaCfnd5JJOw")
+    pub channel_id: String,
+    /// Name of the channel
+    pub title: Option<String>,
+    /// Handle of the channel, including the "@" (e.g., "@RickAstleyYT")
+    pub handle: Option<String>,
+    /// The tab the videos were listed from
+    pub tab: ChannelTab,
+    /// Videos of the tab, newest first
+    pub videos: Vec<ChannelVideo>,
+}
+
+impl ChannelUploads {
+    /// Returns the IDs of the videos, newest first.
+    pub fn video_ids(&self) -> Vec<&str> {
+        self.videos
+            .iter()
+            .map(|video| video.video_id.as_str())
+            .collect()
+    }
+}
+
+/// Upload date filter of a search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum SearchUploadDate {
+    /// Uploaded in the last hour
+    LastHour,
+    /// Uploaded today
+    Today,
+    /// Uploaded this week
+    ThisWeek,
+    /// Uploaded this month
+    ThisMonth,
+    /// Uploaded this year
+    ThisYear,
+}
+
+/// Duration filter of a search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum SearchDuration {
+    /// Under 4 minutes
+    Short,
+    /// Between 4 and 20 minutes
+    Medium,
+    /// Over 20 minutes
+    Long,
+}
+
+/// Result type filter of a search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum SearchResultType {
+    /// Videos, including live streams and shorts
+    Video,
+    /// Channels
+    Channel,
+    /// Playlists and mixes
+    Playlist,
+    /// Movies offered by YouTube
+    Movie,
+}
+
+/// Filters of a search, matching the filters of YouTube's search page
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct SearchFilters {
+    /// Only return results uploaded in this period
+    pub upload_date: Option<SearchUploadDate>,
+    /// Only return videos of this length
+    pub duration: Option<SearchDuration>,
+    /// Only return results of this type
+    pub result_type: Option<SearchResultType>,
+    /// Only return videos with subtitles or closed captions ("Subtitles/CC" filter)
+    pub captions_only: bool,
+}
+
+/// Represents a video found by a search
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SearchVideo {
+    /// The video's unique identifier
+    pub video_id: String,
+    /// Title of the video
+    pub title: String,
+    /// Name of the channel that uploaded the video
+    pub channel_name: Option<String>,
+    /// ID of the channel that uploaded the video
+    pub channel_id: Option<String>,
+    /// Length of the video in seconds (not shown for live streams)
+    pub length_seconds: Option<u32>,
+    /// Display publication time (e.g., "3 weeks ago")
+    pub published_time_text: Option<String>,
+    /// Display view count (e.g., "1,234 views")
+    pub view_count_text: Option<String>,
+    /// Excerpt of the description matching the query
+    pub description_snippet: Option<String>,
+    /// Whether the video is badged as having subtitles or closed captions
+    pub has_captions: bool,
+    /// Whether the video is a live stream currently broadcasting
+    pub is_live: bool,
+}
+
+/// Represents a channel found by a search
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SearchChannel {
+    /// The channel's unique identifier
+    pub channel_id: String,
+    /// Name of the channel
+    pub title: String,
+    /// Display subscriber count (e.g., "3.9M subscribers")
+    pub subscriber_count_text: Option<String>,
+}
+
+/// Represents a playlist found by a search
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SearchPlaylist {
+    /// The playlist's unique identifier
+    pub playlist_id: String,
+    /// Title of the playlist
+    pub title: String,
+    /// Name of the playlist's owner
+    pub channel_name: Option<String>,
+    /// Number of videos in the playlist
+    pub video_count: Option<u64>,
+}
+
+/// Represents one result of a search
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SearchResult {
+    Video(SearchVideo),
+    Channel(SearchChannel),
+    Playlist(SearchPlaylist),
+}
+
+impl SearchResult {
+    /// Returns the video ID if the result is a video.
+    pub fn video_id(&self) -> Option<&str> {
+        match self {
+            SearchResult::Video(video) => Some(&video.video_id),
+            _ => None,
+        }
+    }
+}
+
+/// Represents one page of search results
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SearchPage {
+    /// The searched query
+    pub query: String,
+    /// YouTube's estimate of the total number of results
+    pub estimated_results: Option<u64>,
+    /// Results of the page, in ranking order
+    pub results: Vec<SearchResult>,
+    /// Token of the next page, or `None` on the last page
+    pub continuation: Option<String>,
+}
+
+impl SearchPage {
+    /// Returns the videos of the page, in ranking order.
+    pub fn videos(&self) -> impl Iterator<Item = &SearchVideo> {
+        self.results.iter().filter_map(|result| match result {
+            SearchResult::Video(video) => Some(video),
+            _ => None,
+        })
+    }
+}
+
+/// Sort order of a video's comments
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum CommentSortOrder {
+    /// Most relevant comments first ("Top comments")
+    #[default]
+    Top,
+    /// Most recent comments first ("Newest first")
+    Newest,
+}
+
+/// Represents a comment on a video, or a reply to a comment
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct Comment {
+    /// The comment's unique identifier
+    pub comment_id: String,
+    /// ID of the comment this replies to, `None` for top-level comments
+    pub parent_id: Option<String>,
+    /// Display name of the author (usually their handle)
+    pub author: String,
+    /// Channel ID of the author
+    pub author_channel_id: Option<String>,
+    /// Whether the author is the uploader of the video
+    pub is_author_uploader: bool,
+    /// Text of the comment
+    pub text: String,
+    /// Number of likes; approximate when YouTube abbreviates it (e.g., "1.2K")
+    pub like_count: Option<u64>,
+    /// Display publication time (e.g., "2 years ago")
+    pub published_time_text: Option<String>,
+    /// Number of replies, as reported by YouTube
+    pub reply_count: u64,
+    /// Whether the comment is pinned by the uploader
+    pub is_pinned: bool,
+    /// Replies to the comment, only filled when reply expansion is requested
+    pub replies: Vec<Comment>,
 }
 
 /// # VideoInfos
@@ -375,4 +1706,86 @@ pub struct VideoInfos {
 
     /// List of available transcripts/captions for the video
     pub transcript_list: TranscriptList,
+
+    /// Data from the page's `ytInitialData` variable, if it could be parsed
+    #[serde(default)]
+    pub initial_data: Option<InitialData>,
+
+    /// Chapters of the video, empty if the video has none
+    #[serde(default)]
+    pub chapters: Vec<Chapter>,
+
+    /// Live stream and premiere state of the video
+    #[serde(default)]
+    pub live_details: LiveDetails,
+
+    /// "Most replayed" heatmap of the video, if YouTube shows one
+    #[serde(default)]
+    pub heatmap: Option<Heatmap>,
+
+    /// Preview frame sprite sheets of the video, if available
+    #[serde(default)]
+    pub storyboard: Option<Storyboard>,
+
+    /// Endscreen elements, cards and description links of the video
+    #[serde(default)]
+    pub links: VideoLinks,
+}
+
+impl VideoInfos {
+    /// Returns when the first of the streaming and transcript URLs expires.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        [
+            self.streaming_data.expires_at(),
+            self.transcript_list.expires_at(),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+    }
+
+    /// Returns whether some of the streaming or transcript URLs have expired.
+    ///
+    /// Returns `false` if the expiry is unknown.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at()
+            .is_some_and(|expires_at| expires_at <= Utc::now())
+    }
+}
+
+/// Returns the expiry of a YouTube URL, from its `expire` query parameter or, in
+/// manifest URLs, its `/expire/<timestamp>/` path segment.
+pub(crate) fn parse_url_expiry(url: &str) -> Option<DateTime<Utc>> {
+    let parsed = url::Url::parse(url).ok()?;
+
+    let timestamp = parsed
+        .query_pairs()
+        .find(|(key, _)| key == "expire")
+        .map(|(_, value)| value.into_owned())
+        .or_else(|| {
+            let mut segments = parsed.path_segments()?;
+            segments.find(|segment| *segment == "expire")?;
+            segments.next().map(str::to_string)
+        })?;
+
+    DateTime::from_timestamp(timestamp.parse().ok()?, 0)
+}
+
+/// Parses a date returned by YouTube, accepting RFC 3339 timestamps and plain dates.
+fn parse_typed_date(value: &str) -> Option<DateTime<FixedOffset>> {
+    let value = value.trim();
+
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        return Some(date_time);
+    }
+
+    let naive = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })?;
+
+    Some(naive.and_utc().fixed_offset())
 }
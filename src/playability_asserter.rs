@@ -11,6 +11,8 @@
 /// Playability status is essential for the transcript API to determine whether
 /// it should attempt to fetch transcripts, as many error conditions that affect
 /// video playability also affect transcript availability.
+use chrono::{DateTime, FixedOffset};
+
 use crate::errors::{CouldNotRetrieveTranscript, CouldNotRetrieveTranscriptReason};
 
 /// Responsible for checking if a YouTube video is playable
@@ -23,6 +25,7 @@ use crate::errors::{CouldNotRetrieveTranscript, CouldNotRetrieveTranscriptReason
 /// # Features
 ///
 /// * Detects age-restricted videos
+/// * Detects upcoming and offline live streams and premieres
 /// * Identifies unavailable videos (removed, private, etc.)
 /// * Extracts detailed error messages from YouTube's response
 /// * Converts YouTube playability status to library-specific error types
@@ -78,6 +81,7 @@ impl PlayabilityAsserter {
     /// This method returns different error types based on the playability status:
     ///
     /// * `AgeRestricted` - The video is age-restricted and requires login
+    /// * `LiveStreamOffline` - The video is an upcoming live stream or premiere, or an offline stream
     /// * `VideoUnavailable` - The video doesn't exist or has been removed
     /// * `VideoUnplayable` - Other reasons with detailed information from YouTube
     ///
@@ -124,6 +128,21 @@ impl PlayabilityAsserter {
 
         match status {
             "OK" => Ok(()),
+            "LIVE_STREAM_OFFLINE" => {
+                let reason = player_data
+                    .get("playabilityStatus")
+                    .and_then(|s| s.get("reason"))
+                    .and_then(|s| s.as_str())
+                    .map(str::to_string);
+
+                Err(CouldNotRetrieveTranscript {
+                    video_id: video_id.to_string(),
+                    reason: Some(CouldNotRetrieveTranscriptReason::LiveStreamOffline {
+                        reason,
+                        scheduled_start_time: Self::extract_scheduled_start_time(player_data),
+                    }),
+                })
+            }
             "LOGIN_REQUIRED" => {
                 let reason = player_data
                     .get("playabilityStatus")
@@ -269,6 +288,56 @@ impl PlayabilityAsserter {
 
         sub_reasons
     }
+
+    /// Extracts the scheduled start time of an upcoming live stream or premiere.
+    ///
+    /// YouTube announces the start time in the offline slate shown in place of the
+    /// player, as a Unix timestamp.
+    ///
+    /// # Parameters
+    ///
+    /// * `player_data` - JSON data from YouTube's player response
+    ///
+    /// # Returns
+    ///
+    /// The scheduled start time in UTC, or `None` if the video is not an upcoming
+    /// stream or no start time was announced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use yt_transcript_rs::playability_asserter::PlayabilityAsserter;
+    /// # use serde_json::json;
+    /// let player_data = json!({
+    ///     "playabilityStatus": {
+    ///         "status": "LIVE_STREAM_OFFLINE",
+    ///         "liveStreamability": {"liveStreamabilityRenderer": {"offlineSlate": {
+    ///             "liveStreamOfflineSlateRenderer": {"scheduledStartTime": "1735754400"}
+    ///         }}}
+    ///     }
+    /// });
+    ///
+    /// let start = PlayabilityAsserter::extract_scheduled_start_time(&player_data).unwrap();
+    /// assert_eq!(start.to_rfc3339(), "2025-01-01T18:00:00+00:00");
+    /// ```
+    pub fn extract_scheduled_start_time(
+        player_data: &serde_json::Value,
+    ) -> Option<DateTime<FixedOffset>> {
+        let timestamp = player_data
+            .get("playabilityStatus")?
+            .get("liveStreamability")?
+            .get("liveStreamabilityRenderer")?
+            .get("offlineSlate")?
+            .get("liveStreamOfflineSlateRenderer")?
+            .get("scheduledStartTime")?;
+
+        let seconds = match timestamp {
+            serde_json::Value::String(s) => s.parse().ok()?,
+            other => other.as_i64()?,
+        };
+
+        DateTime::from_timestamp(seconds, 0).map(|date_time| date_time.fixed_offset())
+    }
 }
 
 #[cfg(test)]
@@ -421,6 +490,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_upcoming_live_stream() {
+        let video_id = "upcoming_stream";
+        let player_data = json!({
+            "playabilityStatus": {
+                "status": "LIVE_STREAM_OFFLINE",
+                "reason": "This live event will begin in 3 hours.",
+                "liveStreamability": {
+                    "liveStreamabilityRenderer": {
+                        "offlineSlate": {
+                            "liveStreamOfflineSlateRenderer": {
+                                "scheduledStartTime": "1735754400"
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        let error = PlayabilityAsserter::assert_playability(&player_data, video_id).unwrap_err();
+        assert_eq!(error.video_id, video_id);
+
+        match error.reason {
+            Some(CouldNotRetrieveTranscriptReason::LiveStreamOffline {
+                reason,
+                scheduled_start_time,
+            }) => {
+                assert_eq!(
+                    reason.as_deref(),
+                    Some("This live event will begin in 3 hours.")
+                );
+                assert_eq!(
+                    scheduled_start_time.map(|t| t.timestamp()),
+                    Some(1_735_754_400)
+                );
+            }
+            _ => panic!(
+                "Expected LiveStreamOffline reason but got: {:?}",
+                error.reason
+            ),
+        }
+
+        // An offline stream without a schedule
+        let player_data = json!({"playabilityStatus": {"status": "LIVE_STREAM_OFFLINE"}});
+        let error = PlayabilityAsserter::assert_playability(&player_data, video_id).unwrap_err();
+        assert!(matches!(
+            error.reason,
+            Some(CouldNotRetrieveTranscriptReason::LiveStreamOffline {
+                reason: None,
+                scheduled_start_time: None
+            })
+        ));
+    }
+
     #[test]
     fn test_missing_playability_status() {
         // Test with missing playability status data
@@ -0,0 +1,177 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use reqwest::Client;
+
+use crate::errors::{CouldNotRetrieveTranscript, CouldNotRetrieveTranscriptReason};
+use crate::signature_decipher::SignatureDecipher;
+
+/// Matches the player JavaScript URL in the configuration of a watch page
+static PLAYER_JS_URL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#""(?:jsUrl|PLAYER_JS_URL)":"([^"]+)""#).unwrap());
+
+/// Matches the player version in a player JavaScript URL
+static PLAYER_ID: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"/s/player/([A-Za-z0-9_-]+)/").unwrap());
+
+/// # PlayerJsFetcher
+///
+/// Fetches the player JavaScript (`base.js`) referenced by a watch page.
+///
+/// The player is needed to decipher the signatures of protected streaming formats.
+/// Its URL changes with every player release, and is found in the `jsUrl` field
+/// of the page configuration, e.g. `/s/player/1f8742dc/player_ias.vflset/en_US/base.js`.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// # use reqwest::Client;
+/// # use yt_transcript_rs::player_js_fetcher::PlayerJsFetcher;
+/// # use yt_transcript_rs::youtube_page_fetcher::YoutubePageFetcher;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new();
+/// let html = YoutubePageFetcher::new(client.clone())
+///     .fetch_video_page("dQw4w9WgXcQ")
+///     .await?;
+///
+/// let player_url = PlayerJsFetcher::extract_player_js_url(&html).expect("No player URL");
+/// let decipher = PlayerJsFetcher::new(client)
+///     .fetch_signature_decipher(&player_url, "dQw4w9WgXcQ")
+///     .await?;
+/// println!("{:?}", decipher.operations());
+/// # Ok(())
+/// # }
+/// ```
+pub struct PlayerJsFetcher {
+    /// HTTP client used for making requests to YouTube
+    client: Client,
+}
+
+impl PlayerJsFetcher {
+    /// Creates a new player JavaScript fetcher.
+    ///
+    /// # Parameters
+    ///
+    /// * `client` - The HTTP client, configured with the proxy and cookies of the API
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Extracts the absolute URL of the player JavaScript from a watch page.
+    ///
+    /// # Parameters
+    ///
+    /// * `html` - The HTML of a YouTube watch page
+    ///
+    /// # Returns
+    ///
+    /// * `Option<String>` - The player URL, or `None` if the page does not reference one
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use yt_transcript_rs::player_js_fetcher::PlayerJsFetcher;
+    /// let html = r#"ytcfg.set({"PLAYER_JS_URL":"/s/player/1f8742dc/player_ias.vflset/en_US/base.js"});"#;
+    ///
+    /// let url = PlayerJsFetcher::extract_player_js_url(html).unwrap();
+    /// assert_eq!(url, "https://www.youtube.com/s/player/1f8742dc/player_ias.vflset/en_US/base.js");
+    /// assert_eq!(PlayerJsFetcher::player_id(&url), Some("1f8742dc"));
+    /// ```
+    pub fn extract_player_js_url(html: &str) -> Option<String> {
+        let path = PLAYER_JS_URL
+            .captures(html)?
+            .get(1)?
+            .as_str()
+            .replace("\\/", "/");
+
+        Some(if path.starts_with("//") {
+            format!("https:{}", path)
+        } else if path.starts_with('/') {
+            format!("https://www.youtube.com{}", path)
+        } else {
+            path
+        })
+    }
+
+    /// Returns the player version of a player JavaScript URL (e.g., "1f8742dc").
+    pub fn player_id(player_url: &str) -> Option<&str> {
+        Some(PLAYER_ID.captures(player_url)?.get(1)?.as_str())
+    }
+
+    /// Downloads the player JavaScript.
+    ///
+    /// # Parameters
+    ///
+    /// * `player_url` - The absolute URL of the player JavaScript
+    /// * `video_id` - The YouTube video ID (used for error reporting)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<String, CouldNotRetrieveTranscript>` - The JavaScript source, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YouTubeRequestFailed` error if the request fails or YouTube returns
+    /// an error status.
+    pub async fn fetch_player_js(
+        &self,
+        player_url: &str,
+        video_id: &str,
+    ) -> Result<String, CouldNotRetrieveTranscript> {
+        let request_failed = |message: String| CouldNotRetrieveTranscript {
+            video_id: video_id.to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::YouTubeRequestFailed(
+                message,
+            )),
+        };
+
+        let response = self
+            .client
+            .get(player_url)
+            .send()
+            .await
+            .map_err(|e| request_failed(format!("Player JavaScript request failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(request_failed(format!(
+                "Player JavaScript request returned status: {}",
+                response.status()
+            )));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|e| request_failed(format!("Player JavaScript request failed: {}", e)))
+    }
+
+    /// Downloads the player JavaScript and extracts its signature decipher.
+    ///
+    /// # Parameters
+    ///
+    /// * `player_url` - The absolute URL of the player JavaScript
+    /// * `video_id` - The YouTube video ID (used for error reporting)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SignatureDecipher, CouldNotRetrieveTranscript>` - The decipher, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YouTubeRequestFailed` error if the player cannot be downloaded, and a
+    /// `YouTubeDataUnparsable` error if its deciphering function cannot be found.
+    pub async fn fetch_signature_decipher(
+        &self,
+        player_url: &str,
+        video_id: &str,
+    ) -> Result<SignatureDecipher, CouldNotRetrieveTranscript> {
+        let player_js = self.fetch_player_js(player_url, video_id).await?;
+
+        SignatureDecipher::from_player_js(&player_js).ok_or_else(|| CouldNotRetrieveTranscript {
+            video_id: video_id.to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::YouTubeDataUnparsable(
+                format!("No signature deciphering function in player {}", player_url),
+            )),
+        })
+    }
+}
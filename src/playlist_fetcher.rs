@@ -0,0 +1,417 @@
+use std::collections::HashSet;
+
+use reqwest::Client;
+use serde_json::Value;
+use url::Url;
+
+use crate::errors::{CouldNotRetrieveTranscript, CouldNotRetrieveTranscriptReason};
+use crate::innertube_client::InnerTubeClient;
+use crate::json_utils::{
+    extract_continuation_token, extract_error_alert, extract_text, find_all, find_first,
+    parse_length_text, text_field,
+};
+use crate::models::{Playlist, PlaylistEntry};
+
+/// # PlaylistFetcher
+///
+/// Enumerates the videos of a YouTube playlist through the InnerTube API.
+///
+/// Regular playlists are read from the `browse` endpoint, which returns about 100
+/// videos per page; the remaining pages are requested with the continuation token
+/// found at the end of each page. Mixes ("RD..." playlists) are generated per viewer
+/// and cannot be browsed, so they are read from the `next` endpoint instead, which
+/// returns the queue shown next to the player.
+///
+/// Private and deleted videos stay in the list with `is_available` set to `false`,
+/// so entry indices match the positions shown on YouTube.
+///
+/// ## Usage Example
+///
+/// ```rust,no_run
+/// # use reqwest::Client;
+/// # use yt_transcript_rs::playlist_fetcher::PlaylistFetcher;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let fetcher = PlaylistFetcher::new(Client::new());
+/// let playlist = fetcher
+///     .fetch_playlist("https://www.youtube.com/playlist?list=PLRqwX-V7Uu6ZiZxtDDRCi6uhfTH4FilpH")
+///     .await?;
+///
+/// for entry in &playlist.entries {
+///     println!("{:>3}. {} ({:?}s)", entry.index + 1, entry.title, entry.length_seconds);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct PlaylistFetcher {
+    innertube: InnerTubeClient,
+}
+
+impl PlaylistFetcher {
+    /// Creates a new playlist fetcher.
+    ///
+    /// # Parameters
+    ///
+    /// * `client` - HTTP client used for the InnerTube requests
+    pub fn new(client: Client) -> Self {
+        Self {
+            innertube: InnerTubeClient::new(client),
+        }
+    }
+
+    /// Extracts a playlist ID from a playlist URL, a watch URL with a `list` parameter,
+    /// or a bare playlist ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `url_or_id` - A URL such as `https://www.youtube.com/playlist?list=PL...`, or an ID
+    ///
+    /// # Returns
+    ///
+    /// * `Option<String>` - The playlist ID, or `None` if none could be found
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use yt_transcript_rs::playlist_fetcher::PlaylistFetcher;
+    /// assert_eq!(
+    ///     PlaylistFetcher::extract_playlist_id("https://youtu.be/dQw4w9WgXcQ?list=PL1234abcd").as_deref(),
+    ///     Some("PL1234abcd")
+    /// );
+    /// assert_eq!(PlaylistFetcher::extract_playlist_id("PL1234abcd").as_deref(), Some("PL1234abcd"));
+    /// assert_eq!(PlaylistFetcher::extract_playlist_id("https://www.youtube.com/watch?v=dQw4w9WgXcQ"), None);
+    /// ```
+    pub fn extract_playlist_id(url_or_id: &str) -> Option<String> {
+        let input = url_or_id.trim();
+
+        let is_url = input.contains('/') || input.contains('?');
+        if !is_url {
+            return Self::is_valid_id(input).then(|| input.to_string());
+        }
+
+        let url = if input.contains("://") {
+            Url::parse(input).ok()?
+        } else {
+            Url::parse(&format!("https://{}", input)).ok()?
+        };
+
+        url.query_pairs()
+            .find(|(key, _)| key == "list")
+            .map(|(_, value)| value.into_owned())
+            .filter(|id| Self::is_valid_id(id))
+    }
+
+    /// Fetches a playlist and all of its entries.
+    ///
+    /// # Parameters
+    ///
+    /// * `url_or_id` - The playlist ID or URL (see [`extract_playlist_id`](PlaylistFetcher::extract_playlist_id))
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Playlist, CouldNotRetrieveTranscript>` - The playlist with its entries, or an error
+    ///
+    /// # Errors
+    ///
+    /// - `PlaylistUnavailable` if the input contains no playlist ID, or if the playlist
+    ///   does not exist or is private
+    /// - `YouTubeRequestFailed` or `YouTubeDataUnparsable` if an InnerTube request fails
+    pub async fn fetch_playlist(
+        &self,
+        url_or_id: &str,
+    ) -> Result<Playlist, CouldNotRetrieveTranscript> {
+        let Some(playlist_id) = Self::extract_playlist_id(url_or_id) else {
+            return Err(Self::unavailable(
+                url_or_id,
+                "No playlist ID found in the input".to_string(),
+            ));
+        };
+
+        if !playlist_id.starts_with("RD") {
+            if let Some(playlist) = self.fetch_from_browse(&playlist_id).await? {
+                return Ok(playlist);
+            }
+        }
+
+        let response = self.innertube.next(None, Some(&playlist_id)).await?;
+        Self::parse_next_playlist(&response, &playlist_id).ok_or_else(|| {
+            Self::unavailable(&playlist_id, "The playlist does not exist.".to_string())
+        })
+    }
+
+    /// Fetches a playlist from the `browse` endpoint, following continuations.
+    ///
+    /// Returns `None` if the response describes no playlist, so the `next` endpoint
+    /// can be tried instead.
+    async fn fetch_from_browse(
+        &self,
+        playlist_id: &str,
+    ) -> Result<Option<Playlist>, CouldNotRetrieveTranscript> {
+        let response = self
+            .innertube
+            .browse(&format!("VL{}", playlist_id), None)
+            .await?;
+
+        if let Some(message) = extract_error_alert(&response) {
+            return Err(Self::unavailable(playlist_id, message));
+        }
+
+        let Some(mut playlist) = Self::parse_browse_page(&response, playlist_id) else {
+            return Ok(None);
+        };
+
+        let mut token = extract_continuation_token(&response);
+        let mut seen_tokens = HashSet::new();
+
+        while let Some(current) = token {
+            if !seen_tokens.insert(current.clone()) {
+                break;
+            }
+
+            let page = self
+                .innertube
+                .continuation("browse", &current, playlist_id)
+                .await?;
+            let entries = Self::parse_entries(&page, playlist.entries.len());
+            if entries.is_empty() {
+                break;
+            }
+
+            playlist.entries.extend(entries);
+            token = extract_continuation_token(&page);
+        }
+
+        Ok(Some(playlist))
+    }
+
+    /// Parses the first page of a `browse` response.
+    ///
+    /// Returns `None` if the response has neither playlist metadata nor entries.
+    fn parse_browse_page(response: &Value, playlist_id: &str) -> Option<Playlist> {
+        let metadata = find_first(response, "playlistMetadataRenderer");
+        let entries = Self::parse_entries(response, 0);
+
+        if metadata.is_none() && entries.is_empty() {
+            return None;
+        }
+
+        let author = find_first(response, "playlistHeaderRenderer")
+            .and_then(|header| text_field(header, "ownerText"))
+            .or_else(|| {
+                find_first(response, "videoOwnerRenderer").and_then(|o| text_field(o, "title"))
+            });
+
+        Some(Playlist {
+            playlist_id: playlist_id.to_string(),
+            title: metadata.and_then(|m| text_field(m, "title")),
+            author,
+            description: metadata
+                .and_then(|m| text_field(m, "description"))
+                .filter(|d| !d.is_empty()),
+            entries,
+        })
+    }
+
+    /// Parses the `playlistVideoRenderer` entries of a `browse` page.
+    ///
+    /// # Parameters
+    ///
+    /// * `page` - A `browse` response or continuation response
+    /// * `first_index` - The index of the first entry of the page in the playlist
+    fn parse_entries(page: &Value, first_index: usize) -> Vec<PlaylistEntry> {
+        find_all(page, "playlistVideoRenderer")
+            .into_iter()
+            .filter_map(|renderer| {
+                let video_id = renderer.get("videoId")?.as_str()?.to_string();
+                let length_seconds = renderer
+                    .get("lengthSeconds")
+                    .and_then(|l| l.as_str())
+                    .and_then(|l| l.parse().ok())
+                    .or_else(|| {
+                        text_field(renderer, "lengthText").and_then(|t| parse_length_text(&t))
+                    });
+
+                Some((video_id, renderer, length_seconds))
+            })
+            .enumerate()
+            .map(|(i, (video_id, renderer, length_seconds))| PlaylistEntry {
+                video_id,
+                title: text_field(renderer, "title").unwrap_or_default(),
+                author: text_field(renderer, "shortBylineText"),
+                length_seconds,
+                index: first_index + i,
+                is_available: renderer
+                    .get("isPlayable")
+                    .and_then(|p| p.as_bool())
+                    .unwrap_or(true),
+            })
+            .collect()
+    }
+
+    /// Parses the playlist panel of a `next` response.
+    fn parse_next_playlist(response: &Value, playlist_id: &str) -> Option<Playlist> {
+        let panel = find_first(response, "playlist")
+            .and_then(|p| p.get("playlist"))
+            .or_else(|| find_first(response, "playlistPanelRenderer"))?;
+
+        let entries: Vec<PlaylistEntry> = find_all(panel, "playlistPanelVideoRenderer")
+            .into_iter()
+            .filter_map(|renderer| {
+                let video_id = renderer.get("videoId")?.as_str()?.to_string();
+                Some((video_id, renderer))
+            })
+            .enumerate()
+            .map(|(index, (video_id, renderer))| PlaylistEntry {
+                video_id,
+                title: text_field(renderer, "title").unwrap_or_default(),
+                author: text_field(renderer, "shortBylineText"),
+                length_seconds: text_field(renderer, "lengthText")
+                    .and_then(|t| parse_length_text(&t)),
+                index,
+                is_available: renderer.get("unplayableText").is_none(),
+            })
+            .collect();
+
+        if entries.is_empty() {
+            return None;
+        }
+
+        Some(Playlist {
+            playlist_id: playlist_id.to_string(),
+            title: panel.get("title").and_then(extract_text),
+            author: text_field(panel, "ownerName"),
+            description: None,
+            entries,
+        })
+    }
+
+    fn is_valid_id(id: &str) -> bool {
+        id.len() >= 2
+            && id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
+    fn unavailable(playlist_id: &str, message: String) -> CouldNotRetrieveTranscript {
+        CouldNotRetrieveTranscript {
+            video_id: playlist_id.to_string(),
+            reason: Some(CouldNotRetrieveTranscriptReason::PlaylistUnavailable(
+                message,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn video_renderer(video_id: &str, title: &str, length: &str, playable: bool) -> Value {
+        json!({
+            "playlistVideoRenderer": {
+                "videoId": video_id,
+                "title": {"runs": [{"text": title}]},
+                "shortBylineText": {"runs": [{"text": "Some Channel"}]},
+                "lengthSeconds": length,
+                "isPlayable": playable
+            }
+        })
+    }
+
+    #[test]
+    fn test_extract_playlist_id() {
+        let id = "PLRqwX-V7Uu6ZiZxtDDRCi6uhfTH4FilpH";
+        for input in [
+            id.to_string(),
+            format!("https://www.youtube.com/playlist?list={}", id),
+            format!(
+                "https://www.youtube.com/watch?v=dQw4w9WgXcQ&list={}&index=2",
+                id
+            ),
+            format!("youtube.com/playlist?list={}", id),
+        ] {
+            assert_eq!(
+                PlaylistFetcher::extract_playlist_id(&input).as_deref(),
+                Some(id)
+            );
+        }
+
+        assert!(PlaylistFetcher::extract_playlist_id("https://youtu.be/dQw4w9WgXcQ").is_none());
+        assert!(PlaylistFetcher::extract_playlist_id("not a playlist").is_none());
+    }
+
+    #[test]
+    fn test_parse_browse_page_with_continuation() {
+        let response = json!({
+            "metadata": {
+                "playlistMetadataRenderer": {"title": "My Playlist", "description": ""}
+            },
+            "header": {
+                "playlistHeaderRenderer": {"ownerText": {"runs": [{"text": "Owner"}]}}
+            },
+            "contents": {"playlistVideoListRenderer": {"contents": [
+                video_renderer("video000001", "First", "61", true),
+                video_renderer("video000002", "[Private video]", "0", false),
+                {"continuationItemRenderer": {"continuationEndpoint": {
+                    "continuationCommand": {"token": "TOKEN1", "request": "CONTINUATION_REQUEST_TYPE_BROWSE"}
+                }}}
+            ]}}
+        });
+
+        let playlist = PlaylistFetcher::parse_browse_page(&response, "PL123").unwrap();
+        assert_eq!(playlist.title.as_deref(), Some("My Playlist"));
+        assert_eq!(playlist.author.as_deref(), Some("Owner"));
+        assert!(playlist.description.is_none());
+        assert_eq!(playlist.entries.len(), 2);
+        assert_eq!(playlist.entries[0].length_seconds, Some(61));
+        assert_eq!(playlist.entries[0].author.as_deref(), Some("Some Channel"));
+        assert!(!playlist.entries[1].is_available);
+        assert_eq!(playlist.available_video_ids(), vec!["video000001"]);
+        assert_eq!(
+            extract_continuation_token(&response).as_deref(),
+            Some("TOKEN1")
+        );
+
+        let page = json!({
+            "onResponseReceivedActions": [{"appendContinuationItemsAction": {
+                "continuationItems": [video_renderer("video000003", "Third", "10", true)]
+            }}]
+        });
+        let entries = PlaylistFetcher::parse_entries(&page, 2);
+        assert_eq!(entries[0].index, 2);
+        assert!(extract_continuation_token(&page).is_none());
+    }
+
+    #[test]
+    fn test_parse_next_playlist_and_alerts() {
+        let response = json!({
+            "contents": {"twoColumnWatchNextResults": {"playlist": {"playlist": {
+                "title": "Mix - Some Song",
+                "contents": [
+                    {"playlistPanelVideoRenderer": {
+                        "videoId": "video000001",
+                        "title": {"simpleText": "Some Song"},
+                        "lengthText": {"simpleText": "3:32"}
+                    }}
+                ]
+            }}}}
+        });
+
+        let playlist = PlaylistFetcher::parse_next_playlist(&response, "RDvideo000001").unwrap();
+        assert_eq!(playlist.title.as_deref(), Some("Mix - Some Song"));
+        assert_eq!(playlist.entries[0].length_seconds, Some(212));
+        assert!(playlist.entries[0].is_available);
+
+        let missing = json!({
+            "alerts": [{"alertRenderer": {
+                "type": "ERROR",
+                "text": {"runs": [{"text": "The playlist does not exist."}]}
+            }}]
+        });
+        assert_eq!(
+            extract_error_alert(&missing).as_deref(),
+            Some("The playlist does not exist.")
+        );
+        assert!(PlaylistFetcher::parse_browse_page(&missing, "PL123").is_none());
+    }
+}
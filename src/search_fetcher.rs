@@ -0,0 +1,401 @@
+use base64::Engine;
+use reqwest::Client;
+use serde_json::Value;
+
+use crate::errors::CouldNotRetrieveTranscript;
+use crate::innertube_client::InnerTubeClient;
+use crate::json_utils::{
+    extract_continuation_token, extract_text, find_all, find_first, parse_count, parse_length_text,
+    text_field,
+};
+use crate::models::{
+    SearchChannel, SearchDuration, SearchFilters, SearchPage, SearchPlaylist, SearchResult,
+    SearchResultType, SearchUploadDate, SearchVideo,
+};
+
+/// # SearchFetcher
+///
+/// Searches YouTube through the InnerTube `search` endpoint.
+///
+/// Filters are sent the way YouTube's search page sends them: as a small protobuf
+/// message, base64-encoded into the `params` field of the request. Results are read
+/// from the item sections of the response; shelves ("People also watched") and ads
+/// are skipped so that results keep YouTube's ranking order without duplicates.
+///
+/// The parsing is exposed through [`parse_response`](SearchFetcher::parse_response),
+/// so recorded responses can be replayed without any request.
+///
+/// ## Usage Example
+///
+/// ```rust,no_run
+/// # use reqwest::Client;
+/// # use yt_transcript_rs::search_fetcher::SearchFetcher;
+/// # use yt_transcript_rs::models::{SearchFilters, SearchResultType};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let fetcher = SearchFetcher::new(Client::new());
+/// let filters = SearchFilters {
+///     result_type: Some(SearchResultType::Video),
+///     captions_only: true,
+///     ..Default::default()
+/// };
+///
+/// let videos = fetcher.search_videos("rust ownership", &filters, 50).await?;
+/// for video in &videos {
+///     println!("{} {}", video.video_id, video.title);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct SearchFetcher {
+    innertube: InnerTubeClient,
+}
+
+impl SearchFetcher {
+    /// Creates a new search fetcher.
+    ///
+    /// # Parameters
+    ///
+    /// * `client` - HTTP client used for the InnerTube requests
+    pub fn new(client: Client) -> Self {
+        Self {
+            innertube: InnerTubeClient::new(client),
+        }
+    }
+
+    /// Fetches the first page of results of a search.
+    ///
+    /// # Parameters
+    ///
+    /// * `query` - The search query
+    /// * `filters` - The filters to apply
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SearchPage, CouldNotRetrieveTranscript>` - The first page of results, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns `YouTubeRequestFailed` or `YouTubeDataUnparsable` if the InnerTube request fails.
+    pub async fn search(
+        &self,
+        query: &str,
+        filters: &SearchFilters,
+    ) -> Result<SearchPage, CouldNotRetrieveTranscript> {
+        let params = Self::encode_filters(filters);
+        let response = self.innertube.search(query, params.as_deref()).await?;
+
+        Ok(Self::parse_response(&response, query))
+    }
+
+    /// Fetches the page following `page`.
+    ///
+    /// # Parameters
+    ///
+    /// * `page` - A page returned by [`search`](SearchFetcher::search) or a previous call
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<SearchPage>, CouldNotRetrieveTranscript>` - The next page, `None` if
+    ///   `page` was the last one, or an error
+    pub async fn next_page(
+        &self,
+        page: &SearchPage,
+    ) -> Result<Option<SearchPage>, CouldNotRetrieveTranscript> {
+        let Some(token) = &page.continuation else {
+            return Ok(None);
+        };
+
+        let response = self
+            .innertube
+            .continuation("search", token, &page.query)
+            .await?;
+        let mut next = Self::parse_response(&response, &page.query);
+        next.estimated_results = next.estimated_results.or(page.estimated_results);
+
+        Ok(Some(next))
+    }
+
+    /// Searches videos, following pages until `limit` videos are found or the
+    /// results are exhausted.
+    ///
+    /// # Parameters
+    ///
+    /// * `query` - The search query
+    /// * `filters` - The filters to apply
+    /// * `limit` - Maximum number of videos to return
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<SearchVideo>, CouldNotRetrieveTranscript>` - The videos in ranking order, or an error
+    pub async fn search_videos(
+        &self,
+        query: &str,
+        filters: &SearchFilters,
+        limit: usize,
+    ) -> Result<Vec<SearchVideo>, CouldNotRetrieveTranscript> {
+        let mut page = self.search(query, filters).await?;
+        let mut videos: Vec<SearchVideo> = page.videos().cloned().collect();
+
+        while videos.len() < limit {
+            match self.next_page(&page).await? {
+                Some(next) if !next.results.is_empty() => {
+                    videos.extend(next.videos().cloned());
+                    page = next;
+                }
+                _ => break,
+            }
+        }
+
+        videos.truncate(limit);
+        Ok(videos)
+    }
+
+    /// Parses a `search` response or search continuation response.
+    ///
+    /// # Parameters
+    ///
+    /// * `response` - The JSON returned by the InnerTube `search` endpoint
+    /// * `query` - The searched query, stored in the page for fetching the next one
+    ///
+    /// # Returns
+    ///
+    /// * `SearchPage` - The results of the page; unknown result kinds are skipped
+    pub fn parse_response(response: &Value, query: &str) -> SearchPage {
+        let results = find_all(response, "itemSectionRenderer")
+            .into_iter()
+            .filter_map(|section| section.get("contents").and_then(|c| c.as_array()))
+            .flatten()
+            .filter_map(Self::parse_item)
+            .collect();
+
+        SearchPage {
+            query: query.to_string(),
+            estimated_results: response
+                .get("estimatedResults")
+                .and_then(|e| e.as_str())
+                .and_then(|e| e.parse().ok()),
+            results,
+            continuation: extract_continuation_token(response),
+        }
+    }
+
+    /// Encodes search filters into the `params` of a search request.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<String>` - The encoded parameters, or `None` if no filter is set
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use yt_transcript_rs::search_fetcher::SearchFetcher;
+    /// # use yt_transcript_rs::models::{SearchFilters, SearchResultType};
+    /// let filters = SearchFilters {
+    ///     result_type: Some(SearchResultType::Video),
+    ///     captions_only: true,
+    ///     ..Default::default()
+    /// };
+    /// // Same parameters as YouTube's "Video" + "Subtitles/CC" filters
+    /// assert_eq!(SearchFetcher::encode_filters(&filters).as_deref(), Some("EgQQASgB"));
+    /// assert_eq!(SearchFetcher::encode_filters(&SearchFilters::default()), None);
+    /// ```
+    pub fn encode_filters(filters: &SearchFilters) -> Option<String> {
+        let mut message = Vec::new();
+
+        if let Some(upload_date) = filters.upload_date {
+            let value = match upload_date {
+                SearchUploadDate::LastHour => 1,
+                SearchUploadDate::Today => 2,
+                SearchUploadDate::ThisWeek => 3,
+                SearchUploadDate::ThisMonth => 4,
+                SearchUploadDate::ThisYear => 5,
+            };
+            Self::write_varint_field(&mut message, 1, value);
+        }
+        if let Some(result_type) = filters.result_type {
+            let value = match result_type {
+                SearchResultType::Video => 1,
+                SearchResultType::Channel => 2,
+                SearchResultType::Playlist => 3,
+                SearchResultType::Movie => 4,
+            };
+            Self::write_varint_field(&mut message, 2, value);
+        }
+        if let Some(duration) = filters.duration {
+            let value = match duration {
+                SearchDuration::Short => 1,
+                SearchDuration::Long => 2,
+                SearchDuration::Medium => 3,
+            };
+            Self::write_varint_field(&mut message, 3, value);
+        }
+        if filters.captions_only {
+            Self::write_varint_field(&mut message, 5, 1);
+        }
+
+        if message.is_empty() {
+            return None;
+        }
+
+        // The filters are field 2 of the search parameters message
+        let mut params = vec![(2 << 3) | 2];
+        Self::write_varint(&mut params, message.len() as u64);
+        params.extend(message);
+
+        Some(base64::engine::general_purpose::STANDARD.encode(params))
+    }
+
+    fn write_varint_field(buffer: &mut Vec<u8>, field: u64, value: u64) {
+        Self::write_varint(buffer, field << 3);
+        Self::write_varint(buffer, value);
+    }
+
+    fn write_varint(buffer: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            buffer.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        buffer.push(value as u8);
+    }
+
+    /// Parses one item of an item section.
+    fn parse_item(item: &Value) -> Option<SearchResult> {
+        if let Some(renderer) = item.get("videoRenderer") {
+            return Self::parse_video(renderer).map(SearchResult::Video);
+        }
+        if let Some(renderer) = item.get("channelRenderer") {
+            return Some(SearchResult::Channel(SearchChannel {
+                channel_id: renderer.get("channelId")?.as_str()?.to_string(),
+                title: text_field(renderer, "title").unwrap_or_default(),
+                // Newer layouts show the handle in place of the subscriber count,
+                // and move the count to the video count field
+                subscriber_count_text: ["subscriberCountText", "videoCountText"]
+                    .into_iter()
+                    .filter_map(|key| text_field(renderer, key))
+                    .find(|text| text.contains("subscriber")),
+            }));
+        }
+        if let Some(renderer) = item.get("playlistRenderer") {
+            return Some(SearchResult::Playlist(SearchPlaylist {
+                playlist_id: renderer.get("playlistId")?.as_str()?.to_string(),
+                title: text_field(renderer, "title").unwrap_or_default(),
+                channel_name: text_field(renderer, "shortBylineText"),
+                video_count: renderer
+                    .get("videoCount")
+                    .and_then(|c| c.as_str())
+                    .and_then(parse_count),
+            }));
+        }
+        if let Some(model) = item.get("lockupViewModel") {
+            if model.get("contentType").and_then(|t| t.as_str())
+                == Some("LOCKUP_CONTENT_TYPE_PLAYLIST")
+            {
+                let metadata = find_first(model, "lockupMetadataViewModel");
+                return Some(SearchResult::Playlist(SearchPlaylist {
+                    playlist_id: model.get("contentId")?.as_str()?.to_string(),
+                    title: metadata
+                        .and_then(|m| text_field(m, "title"))
+                        .unwrap_or_default(),
+                    channel_name: None,
+                    video_count: None,
+                }));
+            }
+        }
+
+        None
+    }
+
+    fn parse_video(renderer: &Value) -> Option<SearchVideo> {
+        let owner = renderer
+            .get("ownerText")
+            .or_else(|| renderer.get("longBylineText"));
+        let badge_labels: Vec<(&str, &str)> = find_all(renderer, "metadataBadgeRenderer")
+            .into_iter()
+            .map(|badge| {
+                (
+                    badge.get("label").and_then(|l| l.as_str()).unwrap_or(""),
+                    badge.get("style").and_then(|s| s.as_str()).unwrap_or(""),
+                )
+            })
+            .collect();
+
+        Some(SearchVideo {
+            video_id: renderer.get("videoId")?.as_str()?.to_string(),
+            title: text_field(renderer, "title").unwrap_or_default(),
+            channel_name: owner.and_then(extract_text),
+            channel_id: owner
+                .and_then(|o| find_first(o, "browseId"))
+                .and_then(|id| id.as_str())
+                .map(|id| id.to_string()),
+            length_seconds: text_field(renderer, "lengthText").and_then(|t| parse_length_text(&t)),
+            published_time_text: text_field(renderer, "publishedTimeText"),
+            view_count_text: text_field(renderer, "viewCountText"),
+            description_snippet: find_first(renderer, "snippetText")
+                .and_then(extract_text)
+                .or_else(|| text_field(renderer, "descriptionSnippet")),
+            has_captions: badge_labels
+                .iter()
+                .any(|(label, _)| *label == "CC" || label.contains("Subtitles")),
+            is_live: badge_labels
+                .iter()
+                .any(|(_, style)| *style == "BADGE_STYLE_TYPE_LIVE_NOW"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_encode_filters() {
+        let filters = SearchFilters {
+            result_type: Some(SearchResultType::Video),
+            ..Default::default()
+        };
+        assert_eq!(
+            SearchFetcher::encode_filters(&filters).as_deref(),
+            Some("EgIQAQ==")
+        );
+
+        let filters = SearchFilters {
+            upload_date: Some(SearchUploadDate::ThisWeek),
+            duration: Some(SearchDuration::Long),
+            captions_only: true,
+            ..Default::default()
+        };
+        // upload date (1) = 3, duration (3) = 2, subtitles (5) = 1
+        let encoded = SearchFetcher::encode_filters(&filters).unwrap();
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .unwrap();
+        assert_eq!(bytes, vec![0x12, 0x06, 0x08, 0x03, 0x18, 0x02, 0x28, 0x01]);
+    }
+
+    #[test]
+    fn test_parse_continuation_response() {
+        let response = json!({
+            "onResponseReceivedCommands": [{"appendContinuationItemsAction": {
+                "continuationItems": [
+                    {"itemSectionRenderer": {"contents": [
+                        {"videoRenderer": {
+                            "videoId": "video000004",
+                            "title": {"runs": [{"text": "Page two"}]}
+                        }},
+                        {"adSlotRenderer": {}}
+                    ]}},
+                    {"continuationItemRenderer": {"continuationEndpoint": {
+                        "continuationCommand": {"token": "PAGE3"}
+                    }}}
+                ]
+            }}]
+        });
+
+        let page = SearchFetcher::parse_response(&response, "query");
+        assert_eq!(page.results.len(), 1);
+        assert_eq!(page.results[0].video_id(), Some("video000004"));
+        assert_eq!(page.continuation.as_deref(), Some("PAGE3"));
+        assert!(page.estimated_results.is_none());
+    }
+}
@@ -0,0 +1,323 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use url::Url;
+
+use crate::models::{StreamingData, StreamingFormat};
+
+/// Matches a function scrambling its argument through a helper object:
+/// `Gxa=function(a){a=a.split("");Xy.z4(a,39);Xy.VP(a,20);return a.join("")}`
+static DECIPHER_FUNCTION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"([A-Za-z0-9_$]+)=function\(([A-Za-z0-9_$]+)\)\{([A-Za-z0-9_$]+)=([A-Za-z0-9_$]+)\.split\(""\);([^}]*?)return ([A-Za-z0-9_$]+)\.join\(""\)\}"#,
+    )
+    .unwrap()
+});
+
+/// Matches a call to a helper method, with dotted or bracket access: `Xy.z4(a,39)`, `Xy["z4"](a,39)`
+static HELPER_CALL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"([A-Za-z0-9_$]+)(?:\.([A-Za-z0-9_$]+)|\["([A-Za-z0-9_$]+)"\])\([A-Za-z0-9_$]+(?:,(\d+))?\)"#,
+    )
+    .unwrap()
+});
+
+/// Matches a method of the helper object: `z4:function(a,b){...}`, `"z4":function(a,b){...}`
+static HELPER_METHOD: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?:"([^"]+)"|([A-Za-z0-9_$]+)):function\([^)]*\)\{([^}]*)\}"#).unwrap()
+});
+
+/// An elementary transformation applied to the characters of a signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CipherOperation {
+    /// Reverses the signature (`a.reverse()`)
+    Reverse,
+    /// Removes the first characters of the signature (`a.splice(0,b)`)
+    Splice(usize),
+    /// Swaps the first character with the character at the given index, modulo the length
+    Swap(usize),
+}
+
+/// # SignatureDecipher
+///
+/// Deciphers the scrambled signatures of protected streaming formats.
+///
+/// The player JavaScript (`base.js`) unscrambles a signature with a short function
+/// that splits it into characters, calls methods of a helper object, and joins the
+/// characters back. Every helper method is one of three operations: reversing the
+/// characters, removing the first ones, or swapping the first one with another.
+/// The function and the helper object are renamed with every player release, but
+/// their shape is stable, so the operations can be extracted without running any
+/// JavaScript.
+///
+/// The extracted operations only depend on the player version, so a decipher can be
+/// reused for every video served with the same player.
+///
+/// This does not handle the `n` parameter transform, which YouTube uses to throttle
+/// downloads rather than to protect them.
+///
+/// ## Example
+///
+/// ```rust
+/// # use yt_transcript_rs::signature_decipher::{CipherOperation, SignatureDecipher};
+/// let player_js = r#"var Xy={VP:function(a){a.reverse()},
+/// nF:function(a,b){a.splice(0,b)},
+/// z4:function(a,b){var c=a[0];a[0]=a[b%a.length];a[b%a.length]=c}};
+/// Gxa=function(a){a=a.split("");Xy.z4(a,2);Xy.nF(a,1);Xy.VP(a,5);return a.join("")};"#;
+///
+/// let decipher = SignatureDecipher::from_player_js(player_js).unwrap();
+/// assert_eq!(
+///     decipher.operations(),
+///     &[CipherOperation::Swap(2), CipherOperation::Splice(1), CipherOperation::Reverse]
+/// );
+/// assert_eq!(decipher.decipher("abcdef"), "fedab");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureDecipher {
+    operations: Vec<CipherOperation>,
+}
+
+impl SignatureDecipher {
+    /// Creates a decipher applying the given operations in order.
+    pub fn new(operations: Vec<CipherOperation>) -> Self {
+        Self { operations }
+    }
+
+    /// Extracts the decipher operations from the player JavaScript.
+    ///
+    /// # Parameters
+    ///
+    /// * `player_js` - The source of the player's `base.js`
+    ///
+    /// # Returns
+    ///
+    /// * `Option<SignatureDecipher>` - The decipher, or `None` if the deciphering function
+    ///   or its helper object could not be found
+    pub fn from_player_js(player_js: &str) -> Option<Self> {
+        DECIPHER_FUNCTION
+            .captures_iter(player_js)
+            .filter(|captures| {
+                // The argument is split, scrambled and joined in place
+                let argument = &captures[2];
+                [&captures[3], &captures[4], &captures[6]]
+                    .iter()
+                    .all(|name| *name == argument)
+            })
+            .find_map(|captures| Self::parse_body(player_js, &captures[5]))
+    }
+
+    /// Returns the operations applied by the decipher, in order.
+    pub fn operations(&self) -> &[CipherOperation] {
+        &self.operations
+    }
+
+    /// Deciphers a scrambled signature.
+    pub fn decipher(&self, signature: &str) -> String {
+        let mut characters: Vec<char> = signature.chars().collect();
+
+        for operation in &self.operations {
+            match *operation {
+                CipherOperation::Reverse => characters.reverse(),
+                CipherOperation::Splice(count) => {
+                    characters.drain(..count.min(characters.len()));
+                }
+                CipherOperation::Swap(index) => {
+                    if !characters.is_empty() {
+                        let index = index % characters.len();
+                        characters.swap(0, index);
+                    }
+                }
+            }
+        }
+
+        characters.into_iter().collect()
+    }
+
+    /// Sets the URL of a protected format from its deciphered signature.
+    ///
+    /// Formats that already have a URL or have no cipher are left unchanged.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the URL was set
+    pub fn decipher_format(&self, format: &mut StreamingFormat) -> bool {
+        if format.url.is_some() {
+            return false;
+        }
+        let Some(cipher) = &format.signature_cipher else {
+            return false;
+        };
+        let Ok(mut url) = Url::parse(&cipher.url) else {
+            return false;
+        };
+
+        url.query_pairs_mut()
+            .append_pair(&cipher.signature_param, &self.decipher(&cipher.signature));
+        format.url = Some(url.to_string());
+        true
+    }
+
+    /// Sets the URLs of all protected formats of the streaming data.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of formats whose URL was set
+    pub fn decipher_streaming_data(&self, streaming_data: &mut StreamingData) -> usize {
+        streaming_data
+            .formats
+            .iter_mut()
+            .chain(streaming_data.adaptive_formats.iter_mut())
+            .filter_map(|format| self.decipher_format(format).then_some(()))
+            .count()
+    }
+
+    /// Converts the body of the deciphering function into operations, using the
+    /// definition of the helper object it calls.
+    fn parse_body(player_js: &str, body: &str) -> Option<Self> {
+        let calls: Vec<(&str, &str, usize)> = HELPER_CALL
+            .captures_iter(body)
+            .map(|captures| {
+                let object = captures.get(1).unwrap().as_str();
+                let method = captures.get(2).or(captures.get(3)).unwrap().as_str();
+                let argument = captures
+                    .get(4)
+                    .and_then(|a| a.as_str().parse().ok())
+                    .unwrap_or(0);
+                (object, method, argument)
+            })
+            .collect();
+
+        let object = calls.first()?.0;
+        if calls.iter().any(|(o, _, _)| *o != object) {
+            return None;
+        }
+        let helper = Self::find_object(player_js, object)?;
+
+        let methods: Vec<(&str, &str)> = HELPER_METHOD
+            .captures_iter(helper)
+            .map(|captures| {
+                let name = captures.get(1).or(captures.get(2)).unwrap().as_str();
+                (name, captures.get(3).unwrap().as_str())
+            })
+            .collect();
+
+        let operations = calls
+            .into_iter()
+            .map(|(_, method, argument)| {
+                let (_, body) = methods.iter().find(|(name, _)| *name == method)?;
+                if body.contains("reverse") {
+                    Some(CipherOperation::Reverse)
+                } else if body.contains("splice") {
+                    Some(CipherOperation::Splice(argument))
+                } else if body.contains("var c=") || body.contains('%') {
+                    Some(CipherOperation::Swap(argument))
+                } else {
+                    None
+                }
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self::new(operations))
+    }
+
+    /// Returns the source of an object literal assigned to `name`, braces included.
+    fn find_object<'a>(player_js: &'a str, name: &str) -> Option<&'a str> {
+        let pattern = format!(r"(?:^|[^A-Za-z0-9_$.]){}=\{{", regex::escape(name));
+        let start = Regex::new(&pattern).ok()?.find(player_js)?.end() - 1;
+
+        let mut depth = 0;
+        for (offset, character) in player_js[start..].char_indices() {
+            match character {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(&player_js[start..=start + offset]);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SignatureCipher;
+    use crate::streaming_data_extractor::StreamingDataExtractor;
+    use serde_json::json;
+
+    #[test]
+    fn test_operations() {
+        let decipher = SignatureDecipher::new(vec![
+            CipherOperation::Swap(7),
+            CipherOperation::Splice(2),
+            CipherOperation::Reverse,
+        ]);
+
+        // "abcdef": swap(7 % 6 = 1) -> "bacdef", splice(2) -> "cdef", reverse -> "fedc"
+        assert_eq!(decipher.decipher("abcdef"), "fedc");
+        assert_eq!(decipher.decipher(""), "");
+    }
+
+    #[test]
+    fn test_decipher_streaming_data() {
+        let player_response = json!({"streamingData": {
+            "expiresInSeconds": "21540",
+            "adaptiveFormats": [
+                {
+                    "itag": 251, "mimeType": "audio/webm; codecs=\"opus\"", "bitrate": 140000,
+                    "quality": "tiny", "projectionType": "RECTANGULAR", "approxDurationMs": "212061",
+                    "signatureCipher": "s=cba%3D&sp=sig&url=https%3A%2F%2Frr1---sn-abc.googlevideo.com%2Fvideoplayback%3Fitag%3D251%26mime%3Daudio%252Fwebm"
+                },
+                {
+                    "itag": 140, "mimeType": "audio/mp4; codecs=\"mp4a.40.2\"", "bitrate": 130000,
+                    "quality": "tiny", "projectionType": "RECTANGULAR", "approxDurationMs": "212091",
+                    "url": "https://rr1---sn-abc.googlevideo.com/videoplayback?itag=140"
+                }
+            ]
+        }});
+
+        let mut data =
+            StreamingDataExtractor::extract_streaming_data(&player_response, "test").unwrap();
+        assert_eq!(
+            data.adaptive_formats[0].signature_cipher,
+            Some(SignatureCipher {
+                url:
+                    "https://rr1---sn-abc.googlevideo.com/videoplayback?itag=251&mime=audio%2Fwebm"
+                        .to_string(),
+                signature: "cba=".to_string(),
+                signature_param: "sig".to_string(),
+            })
+        );
+        assert_eq!(data.adaptive_formats[0].url, None);
+
+        let decipher = SignatureDecipher::new(vec![CipherOperation::Reverse]);
+        assert_eq!(decipher.decipher_streaming_data(&mut data), 1);
+        assert_eq!(
+            data.adaptive_formats[0].url.as_deref(),
+            Some("https://rr1---sn-abc.googlevideo.com/videoplayback?itag=251&mime=audio%2Fwebm&sig=%3Dabc")
+        );
+        assert_eq!(
+            data.adaptive_formats[1].url.as_deref(),
+            Some("https://rr1---sn-abc.googlevideo.com/videoplayback?itag=140")
+        );
+    }
+
+    #[test]
+    fn test_unrecognized_player() {
+        assert!(SignatureDecipher::from_player_js("").is_none());
+        // The helper object is missing
+        assert!(SignatureDecipher::from_player_js(
+            r#"Gxa=function(a){a=a.split("");Xy.z4(a,39);return a.join("")};"#
+        )
+        .is_none());
+        // Splitting into another variable is not a deciphering function
+        assert!(SignatureDecipher::from_player_js(
+            r#"var Xy={VP:function(a){a.reverse()}};f=function(a){b=a.split("");Xy.VP(b,1);return b.join("")};"#
+        )
+        .is_none());
+    }
+}
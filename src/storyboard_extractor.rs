@@ -0,0 +1,224 @@
+use serde_json::Value;
+
+use crate::models::{Storyboard, StoryboardLevel};
+
+/// # StoryboardExtractor
+///
+/// Extracts the storyboard of a video from YouTube's player response.
+///
+/// The storyboard is described by a single spec string at
+/// `storyboards.playerStoryboardSpecRenderer.spec`, made of `|`-separated parts:
+/// a URL template followed by one part per level, each with `#`-separated fields:
+///
+/// ```text
+/// https://i.ytimg.com/sb/ID/storyboard3_L$L/$N.jpg?sqp=...|48#27#100#10#10#0#default#rs$...|160#90#101#5#5#2000#M$M#rs$...
+///                                                          w  h  count cols rows interval(ms) name signature
+/// ```
+///
+/// In the template, `$L` stands for the level index and `$N` for the level name,
+/// which itself contains `$M` (the sheet number) for levels split over several
+/// sheets. An interval of 0 means the frames are spread evenly over the video.
+///
+/// Live streams use a different spec (`playerLiveStoryboardSpecRenderer`) whose
+/// frames change as the stream goes on; it is not supported.
+pub struct StoryboardExtractor;
+
+impl StoryboardExtractor {
+    /// Extracts the storyboard from the player response.
+    ///
+    /// # Parameters
+    ///
+    /// * `player_response` - The parsed YouTube player response JSON object
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Storyboard>` - The storyboard, or `None` if the video has none
+    pub fn extract_storyboard(player_response: &Value) -> Option<Storyboard> {
+        let spec = player_response
+            .get("storyboards")?
+            .get("playerStoryboardSpecRenderer")?
+            .get("spec")?
+            .as_str()?;
+
+        let video_length = player_response
+            .get("videoDetails")
+            .and_then(|details| details.get("lengthSeconds"))
+            .and_then(|length| length.as_str())
+            .and_then(|length| length.parse().ok())
+            .unwrap_or(0.0);
+
+        Self::parse_spec(spec, video_length)
+    }
+
+    /// Decodes a storyboard spec string.
+    ///
+    /// Malformed levels are skipped.
+    ///
+    /// # Parameters
+    ///
+    /// * `spec` - The spec string
+    /// * `video_length` - The length of the video in seconds, used for levels without an interval
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Storyboard>` - The storyboard, or `None` if the spec has no valid level
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use yt_transcript_rs::storyboard_extractor::StoryboardExtractor;
+    /// let spec = "https://i.ytimg.com/sb/ID/storyboard3_L$L/$N.jpg?sqp=abc\
+    ///     |48#27#100#10#10#0#default#rs$A|160#90#101#5#5#2000#M$M#rs$B";
+    ///
+    /// let storyboard = StoryboardExtractor::parse_spec(spec, 200.0).unwrap();
+    /// let level = &storyboard.levels[1];
+    ///
+    /// assert_eq!(level.sheet_count(), 5);
+    /// assert_eq!(
+    ///     level.sheet_url(2),
+    ///     "https://i.ytimg.com/sb/ID/storyboard3_L1/M2.jpg?sqp=abc&sigh=rs$B"
+    /// );
+    ///
+    /// // The frame at 1:03 is the 32nd: second sheet, second row, second column
+    /// let frame = storyboard.frame_at(63.0).unwrap();
+    /// assert_eq!(frame.url, level.sheet_url(1));
+    /// assert_eq!((frame.x, frame.y, frame.start), (160, 90, 62.0));
+    /// ```
+    pub fn parse_spec(spec: &str, video_length: f64) -> Option<Storyboard> {
+        let mut parts = spec.split('|');
+        let template = parts.next()?;
+
+        let levels: Vec<StoryboardLevel> = parts
+            .enumerate()
+            .filter_map(|(index, part)| {
+                Self::parse_level(template, index as u32, part, video_length)
+            })
+            .collect();
+
+        if levels.is_empty() {
+            None
+        } else {
+            Some(Storyboard { levels })
+        }
+    }
+
+    fn parse_level(
+        template: &str,
+        level: u32,
+        part: &str,
+        video_length: f64,
+    ) -> Option<StoryboardLevel> {
+        let fields: Vec<&str> = part.split('#').collect();
+        let [width, height, frame_count, columns, rows, interval, name, signature, ..] =
+            fields.as_slice()
+        else {
+            return None;
+        };
+
+        let frame_count: u32 = frame_count.parse().ok()?;
+        let interval_ms: f64 = interval.parse().ok()?;
+        let interval = if interval_ms > 0.0 {
+            interval_ms / 1000.0
+        } else if frame_count > 0 {
+            video_length / frame_count as f64
+        } else {
+            0.0
+        };
+
+        let mut url_template = template
+            .replace("$L", &level.to_string())
+            .replace("$N", name);
+        if !signature.is_empty() {
+            let separator = if url_template.contains('?') { '&' } else { '?' };
+            url_template = format!("{}{}sigh={}", url_template, separator, signature);
+        }
+
+        Some(StoryboardLevel {
+            level,
+            width: width.parse().ok()?,
+            height: height.parse().ok()?,
+            frame_count,
+            columns: columns.parse().ok()?,
+            rows: rows.parse().ok()?,
+            interval,
+            url_template,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const SPEC: &str = "https://i.ytimg.com/sb/dQw4w9WgXcQ/storyboard3_L$L/$N.jpg?sqp=-oaymwENSDfyq4qpAwVwAcABBqLzl_8DBgjlxqOGBg==|48#27#100#10#10#0#default#rs$AOn4CLBs7gdpCkk|80#45#107#10#10#2000#M$M#rs$AOn4CLDM4Eie4xJR|160#90#107#5#5#2000#M$M#rs$AOn4CLDyAiUWcbo";
+
+    #[test]
+    fn test_extract_storyboard() {
+        let player_response = json!({
+            "videoDetails": {"lengthSeconds": "213"},
+            "storyboards": {"playerStoryboardSpecRenderer": {"spec": SPEC}}
+        });
+
+        let storyboard = StoryboardExtractor::extract_storyboard(&player_response).unwrap();
+        assert_eq!(storyboard.levels.len(), 3);
+
+        // Level 0 has no interval: its 100 frames cover the whole video in one sheet
+        let first = &storyboard.levels[0];
+        assert_eq!((first.width, first.height), (48, 27));
+        assert!((first.interval - 2.13).abs() < 1e-9);
+        assert_eq!(first.sheet_count(), 1);
+        assert_eq!(
+            first.sheet_url(0),
+            "https://i.ytimg.com/sb/dQw4w9WgXcQ/storyboard3_L0/default.jpg?sqp=-oaymwENSDfyq4qpAwVwAcABBqLzl_8DBgjlxqOGBg==&sigh=rs$AOn4CLBs7gdpCkk"
+        );
+
+        let best = storyboard.highest_resolution().unwrap();
+        assert_eq!(best.level, 2);
+        assert_eq!(best.sheet_count(), 5);
+        assert_eq!(best.sheet_urls().len(), 5);
+        assert!(best.sheet_urls()[4].contains("storyboard3_L2/M4.jpg"));
+    }
+
+    #[test]
+    fn test_frame_at() {
+        let storyboard = StoryboardExtractor::parse_spec(SPEC, 213.0).unwrap();
+        let best = storyboard.highest_resolution().unwrap();
+
+        let frame = best.frame_at(0.0).unwrap();
+        assert_eq!((frame.x, frame.y), (0, 0));
+        assert!(frame.url.contains("/M0.jpg"));
+
+        // Frame 27: second sheet, first row, third column
+        let frame = best.frame_at(55.0).unwrap();
+        assert!(frame.url.contains("/M1.jpg"));
+        assert_eq!(
+            (frame.x, frame.y, frame.width, frame.height),
+            (320, 0, 160, 90)
+        );
+        assert_eq!(frame.start, 54.0);
+
+        // Past the end: last frame
+        assert_eq!(best.frame_at(10_000.0).unwrap().start, 212.0);
+        assert!(best.frame_at(-1.0).is_none());
+    }
+
+    #[test]
+    fn test_out_of_range_values() {
+        let spec = "https://example.com/$L/$N.jpg|4294967295#4294967295#10#65536#65536#1000#M$M#rs$x|48#27#10#5#5#1000#M$M#rs$y";
+        let storyboard = StoryboardExtractor::parse_spec(spec, 10.0).unwrap();
+
+        let huge = &storyboard.levels[0];
+        assert_eq!(huge.frames_per_sheet(), u32::MAX);
+        assert_eq!(huge.sheet_count(), 1);
+        assert_eq!(storyboard.highest_resolution().unwrap().level, 0);
+        assert!(huge.frame_at(5.0).is_some());
+    }
+
+    #[test]
+    fn test_invalid_spec() {
+        assert!(StoryboardExtractor::parse_spec("https://example.com/$L/$N.jpg", 10.0).is_none());
+        assert!(StoryboardExtractor::parse_spec("https://example.com|48#27", 10.0).is_none());
+        assert!(StoryboardExtractor::extract_storyboard(&json!({})).is_none());
+    }
+}
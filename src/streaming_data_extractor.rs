@@ -1,5 +1,7 @@
 use crate::errors::{CouldNotRetrieveTranscript, CouldNotRetrieveTranscriptReason};
-use crate::models::{ColorInfo, Range, StreamingData, StreamingFormat};
+use crate::models::{
+    ColorInfo, Range, SignatureCipher, SkippedFormat, StreamingData, StreamingFormat,
+};
 use serde_json::Value;
 
 /// # StreamingDataExtractor
@@ -47,11 +49,17 @@ impl StreamingDataExtractor {
             _ => "0".to_string(), // Default to 0 if not found
         };
 
-        // Extract formats
-        let formats = Self::extract_formats(streaming_data.get("formats"));
+        // Extract formats, keeping track of the ones that cannot be parsed
+        let mut skipped_formats = Vec::new();
+        let formats =
+            Self::extract_formats(streaming_data.get("formats"), false, &mut skipped_formats);
 
         // Extract adaptive formats
-        let adaptive_formats = Self::extract_formats(streaming_data.get("adaptiveFormats"));
+        let adaptive_formats = Self::extract_formats(
+            streaming_data.get("adaptiveFormats"),
+            true,
+            &mut skipped_formats,
+        );
 
         // Extract server ABR streaming URL
         let server_abr_streaming_url = match streaming_data.get("serverAbrStreamingUrl") {
@@ -59,11 +67,26 @@ impl StreamingDataExtractor {
             _ => None,
         };
 
+        // Extract the manifests of live streams
+        let hls_manifest_url = streaming_data
+            .get("hlsManifestUrl")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let dash_manifest_url = streaming_data
+            .get("dashManifestUrl")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
         Ok(StreamingData {
             expires_in_seconds,
             formats,
             adaptive_formats,
             server_abr_streaming_url,
+            hls_manifest_url,
+            dash_manifest_url,
+            // Set by the fetcher, which knows when the request was made
+            fetched_at: None,
+            skipped_formats,
         })
     }
 
@@ -72,17 +95,29 @@ impl StreamingDataExtractor {
     /// # Parameters
     ///
     /// * `formats_value` - Optional JSON value containing an array of format objects
+    /// * `adaptive` - Whether the array holds the adaptive formats
+    /// * `skipped` - Receives the formats that cannot be parsed, with the reason
     ///
     /// # Returns
     ///
     /// * `Vec<StreamingFormat>` - Vector of parsed streaming formats
-    fn extract_formats(formats_value: Option<&Value>) -> Vec<StreamingFormat> {
+    fn extract_formats(
+        formats_value: Option<&Value>,
+        adaptive: bool,
+        skipped: &mut Vec<SkippedFormat>,
+    ) -> Vec<StreamingFormat> {
         let mut formats = Vec::new();
 
         if let Some(Value::Array(array)) = formats_value {
-            for item in array {
-                if let Some(format) = Self::parse_format(item) {
-                    formats.push(format);
+            for (index, item) in array.iter().enumerate() {
+                match Self::parse_format(item) {
+                    Ok(format) => formats.push(format),
+                    Err(reason) => skipped.push(SkippedFormat {
+                        adaptive,
+                        index,
+                        itag: item.get("itag").and_then(|v| v.as_u64()).map(|v| v as u32),
+                        reason,
+                    }),
                 }
             }
         }
@@ -90,154 +125,183 @@ impl StreamingDataExtractor {
         formats
     }
 
+    /// Parses the `signatureCipher` field of a format.
+    ///
+    /// The field is a URL-encoded query string with the scrambled signature (`s`),
+    /// the name of the signature parameter (`sp`, "signature" when missing) and the
+    /// unsigned media URL (`url`).
+    ///
+    /// # Parameters
+    ///
+    /// * `cipher` - The value of the `signatureCipher` field
+    ///
+    /// # Returns
+    ///
+    /// * `Option<SignatureCipher>` - The parsed cipher, or `None` if `s` or `url` is missing
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use yt_transcript_rs::streaming_data_extractor::StreamingDataExtractor;
+    /// let cipher = StreamingDataExtractor::parse_signature_cipher(
+    ///     "s=AOq0QJ8w%3D%3D&sp=sig&url=https://rr1---sn-abc.googlevideo.com/videoplayback%3Fitag%3D18",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(cipher.signature, "AOq0QJ8w==");
+    /// assert_eq!(cipher.signature_param, "sig");
+    /// assert_eq!(cipher.url, "https://rr1---sn-abc.googlevideo.com/videoplayback?itag=18");
+    /// ```
+    pub fn parse_signature_cipher(cipher: &str) -> Option<SignatureCipher> {
+        let mut signature = None;
+        let mut signature_param = None;
+        let mut url = None;
+
+        for (key, value) in url::form_urlencoded::parse(cipher.as_bytes()) {
+            match key.as_ref() {
+                "s" => signature = Some(value.into_owned()),
+                "sp" => signature_param = Some(value.into_owned()),
+                "url" => url = Some(value.into_owned()),
+                _ => {}
+            }
+        }
+
+        Some(SignatureCipher {
+            url: url?,
+            signature: signature?,
+            signature_param: signature_param.unwrap_or_else(|| "signature".to_string()),
+        })
+    }
+
     /// Parses a single format from JSON
     ///
+    /// Only the `itag` and `mimeType` fields are required: every other field is
+    /// omitted by YouTube for some formats (e.g., `approxDurationMs` for live streams).
+    /// Fields that are not mapped to a field of `StreamingFormat`, and mapped fields
+    /// whose value has an unexpected type (e.g., `"bitrate": "130000"`), are kept in
+    /// `extra`.
+    ///
     /// # Parameters
     ///
     /// * `format_json` - JSON object containing format data
     ///
     /// # Returns
     ///
-    /// * `Option<StreamingFormat>` - Parsed format or None if parsing failed
-    fn parse_format(format_json: &Value) -> Option<StreamingFormat> {
+    /// * `Result<StreamingFormat, String>` - Parsed format, or the reason it cannot be parsed
+    fn parse_format(format_json: &Value) -> Result<StreamingFormat, String> {
+        let Some(object) = format_json.as_object() else {
+            return Err("not an object".to_string());
+        };
+        let mut fields = FormatFields::new(object);
+
         // Required fields
-        let itag = format_json.get("itag")?.as_u64()? as u32;
-        let mime_type = format_json.get("mimeType")?.as_str()?.to_string();
-        let bitrate = format_json.get("bitrate")?.as_u64()?;
-        let quality = format_json.get("quality")?.as_str()?.to_string();
-        let projection_type = format_json.get("projectionType")?.as_str()?.to_string();
-        let approx_duration_ms = format_json.get("approxDurationMs")?.as_str()?.to_string();
-
-        // Optional fields
-        let url = format_json
-            .get("url")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-
-        let width = format_json
-            .get("width")
-            .and_then(|v| v.as_u64())
-            .map(|v| v as u32);
-        let height = format_json
-            .get("height")
-            .and_then(|v| v.as_u64())
-            .map(|v| v as u32);
-
-        let fps = format_json
-            .get("fps")
-            .and_then(|v| v.as_u64())
-            .map(|v| v as u32);
-
-        let quality_label = format_json
-            .get("qualityLabel")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-
-        let average_bitrate = format_json.get("averageBitrate").and_then(|v| v.as_u64());
-
-        let audio_quality = format_json
-            .get("audioQuality")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-
-        let audio_sample_rate = format_json
-            .get("audioSampleRate")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-
-        let audio_channels = format_json
-            .get("audioChannels")
-            .and_then(|v| v.as_u64())
-            .map(|v| v as u32);
-
-        let quality_ordinal = format_json
-            .get("qualityOrdinal")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-
-        let high_replication = format_json.get("highReplication").and_then(|v| v.as_bool());
-
-        let last_modified = format_json
-            .get("lastModified")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-
-        let content_length = format_json
-            .get("contentLength")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-
-        let loudness_db = format_json.get("loudnessDb").and_then(|v| v.as_f64());
-
-        let is_drc = format_json.get("isDrc").and_then(|v| v.as_bool());
-
-        let xtags = format_json
-            .get("xtags")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-
-        // Extract init_range if present
-        let init_range = format_json.get("initRange").and_then(|range| {
-            let start = range.get("start")?.as_str()?.to_string();
-            let end = range.get("end")?.as_str()?.to_string();
-            Some(Range { start, end })
-        });
-
-        // Extract index_range if present
-        let index_range = format_json.get("indexRange").and_then(|range| {
-            let start = range.get("start")?.as_str()?.to_string();
-            let end = range.get("end")?.as_str()?.to_string();
-            Some(Range { start, end })
-        });
-
-        // Extract color_info if present
-        let color_info = format_json.get("colorInfo").map(|color| {
-            let primaries = color
-                .get("primaries")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
-            let transfer_characteristics = color
-                .get("transferCharacteristics")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
-            let matrix_coefficients = color
-                .get("matrixCoefficients")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
-
-            ColorInfo {
-                primaries,
-                transfer_characteristics,
-                matrix_coefficients,
-            }
-        });
+        let itag = match object.get("itag") {
+            None => return Err("missing itag".to_string()),
+            Some(v) => fields
+                .read("itag", Self::as_u32)
+                .ok_or_else(|| format!("invalid itag: {}", v))?,
+        };
+        let mime_type = match object.get("mimeType") {
+            None => return Err("missing mimeType".to_string()),
+            Some(v) => fields
+                .read("mimeType", Self::as_string)
+                .ok_or_else(|| format!("invalid mimeType: {}", v))?,
+        };
 
-        Some(StreamingFormat {
+        // Protected formats carry their URL in a cipher instead ("cipher" in older responses)
+        let parse_cipher = |v: &Value| v.as_str().and_then(Self::parse_signature_cipher);
+        let signature_cipher = fields
+            .read("signatureCipher", parse_cipher)
+            .or_else(|| fields.read("cipher", parse_cipher));
+
+        Ok(StreamingFormat {
             itag,
-            url,
+            url: fields.read("url", Self::as_string),
+            signature_cipher,
             mime_type,
-            bitrate,
-            width,
-            height,
-            init_range,
-            index_range,
-            last_modified,
-            content_length,
-            quality,
-            fps,
-            quality_label,
-            projection_type,
-            average_bitrate,
-            audio_quality,
-            approx_duration_ms,
-            audio_sample_rate,
-            audio_channels,
-            quality_ordinal,
-            high_replication,
-            color_info,
-            loudness_db,
-            is_drc,
-            xtags,
+            bitrate: fields.read("bitrate", Value::as_u64),
+            width: fields.read("width", Self::as_u32),
+            height: fields.read("height", Self::as_u32),
+            init_range: fields.read("initRange", Self::as_range),
+            index_range: fields.read("indexRange", Self::as_range),
+            last_modified: fields.read("lastModified", Self::as_string),
+            content_length: fields.read("contentLength", Self::as_string),
+            quality: fields.read("quality", Self::as_string),
+            fps: fields.read("fps", Self::as_u32),
+            quality_label: fields.read("qualityLabel", Self::as_string),
+            projection_type: fields.read("projectionType", Self::as_string),
+            average_bitrate: fields.read("averageBitrate", Value::as_u64),
+            audio_quality: fields.read("audioQuality", Self::as_string),
+            approx_duration_ms: fields.read("approxDurationMs", Self::as_string),
+            audio_sample_rate: fields.read("audioSampleRate", Self::as_string),
+            audio_channels: fields.read("audioChannels", Self::as_u32),
+            quality_ordinal: fields.read("qualityOrdinal", Self::as_string),
+            high_replication: fields.read("highReplication", Value::as_bool),
+            color_info: fields.read("colorInfo", Self::as_color_info),
+            loudness_db: fields.read("loudnessDb", Value::as_f64),
+            is_drc: fields.read("isDrc", Value::as_bool),
+            xtags: fields.read("xtags", Self::as_string),
+            extra: fields.unread(),
+        })
+    }
+
+    fn as_string(value: &Value) -> Option<String> {
+        value.as_str().map(str::to_string)
+    }
+
+    fn as_u32(value: &Value) -> Option<u32> {
+        value.as_u64().and_then(|v| u32::try_from(v).ok())
+    }
+
+    fn as_range(range: &Value) -> Option<Range> {
+        let start = range.get("start")?.as_str()?.to_string();
+        let end = range.get("end")?.as_str()?.to_string();
+        Some(Range { start, end })
+    }
+
+    fn as_color_info(color: &Value) -> Option<ColorInfo> {
+        let field = |key: &str| color.get(key).and_then(Self::as_string);
+
+        color.is_object().then(|| ColorInfo {
+            primaries: field("primaries"),
+            transfer_characteristics: field("transferCharacteristics"),
+            matrix_coefficients: field("matrixCoefficients"),
         })
     }
 }
+
+/// The fields of a format JSON object, tracking which ones were parsed.
+///
+/// Every field that is not read successfully ends up in `StreamingFormat::extra`,
+/// so a field added to `StreamingFormat` cannot silently drop unexpected values.
+struct FormatFields<'a> {
+    object: &'a serde_json::Map<String, Value>,
+    parsed: Vec<&'static str>,
+}
+
+impl<'a> FormatFields<'a> {
+    fn new(object: &'a serde_json::Map<String, Value>) -> Self {
+        Self {
+            object,
+            parsed: Vec::new(),
+        }
+    }
+
+    /// Parses a field, marking it as consumed if the parse succeeds.
+    fn read<T>(&mut self, key: &'static str, parse: impl Fn(&Value) -> Option<T>) -> Option<T> {
+        let value = self.object.get(key).and_then(parse);
+        if value.is_some() {
+            self.parsed.push(key);
+        }
+        value
+    }
+
+    /// Returns the fields that were not parsed.
+    fn unread(&self) -> serde_json::Map<String, Value> {
+        self.object
+            .iter()
+            .filter(|(key, _)| !self.parsed.contains(&key.as_str()))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+}
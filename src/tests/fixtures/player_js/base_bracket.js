@@ -0,0 +1,19 @@
+var _yt_player={};(function(g){var window=this;/*
+
+ Copyright Google LLC
+ SPDX-License-Identifier: Apache-2.0
+*/
+'use strict';
+var aa,ca,fa,ha,ja,ma,Zoa;aa=function(a){return a};
+ca=function(a,b){return a.length>b?a.substring(0,b):a};
+fa=function(a){var b=[];for(var c in a)b.push(c);return b};
+ha=function(a,b){a=a.split("");a.splice(0,b);return a.join("")};
+var $q={"Xt":function(a,b){var c=a[0];a[0]=a[b%a.length];a[b]=c},
+Q$:function(a){a.reverse()},
+"kw":function(a,b){a.splice(0,b)}};
+ja=function(a){return typeof a==="string"&&a.length>0};
+Zoa=function(a){var b=a.split(""),c=[-1830146402,function(d,e){d.push(e)},"ZFLD4ei"];c[1](b,c[2]);return b.join("")};
+V$b=function(a){a=a.split("");$q["Q$"](a,41);$q.kw(a,1);$q["Xt"](a,66);$q.Q$(a,18);$q["kw"](a,3);$q.Xt(a,29);return a.join("")};
+ma=function(a,b){return a===b};
+g.Ud=function(a,b){b=b.sp||"sig";a.set(b,V$b(a.get("s")));return a};
+})(_yt_player);
@@ -0,0 +1,22 @@
+var _yt_player={};(function(g){var window=this;/*
+
+ Copyright The Closure Library Authors.
+ SPDX-License-Identifier: Apache-2.0
+*/
+'use strict';
+var ba,da,ea,ia,ka,la,oaa,paa,qaa;ba=function(a){var b=0;return function(){return b<a.length?{done:!1,value:a[b++]}:{done:!0}}};
+da=typeof Object.defineProperties=="function"?Object.defineProperty:function(a,b,c){if(a==Array.prototype||a==Object.prototype)return a;a[b]=c.value;return a};
+ea=function(a){a=["object"==typeof globalThis&&globalThis,a,"object"==typeof window&&window,"object"==typeof self&&self,"object"==typeof global&&global];for(var b=0;b<a.length;++b){var c=a[b];if(c&&c.Math==Math)return c}throw Error("Cannot find global object");};
+ia=ea(this);ka=function(a,b){if(b)a:{var c=ia;a=a.split(".");for(var d=0;d<a.length-1;d++){var e=a[d];if(!(e in c))break a;c=c[e]}a=a[a.length-1];d=c[a];b=b(d);b!=d&&b!=null&&da(c,a,{configurable:!0,writable:!0,value:b})}};
+g.Ax=function(a,b){a.splice(0,b)};
+var Xy={VP:function(a){a.reverse()},
+nF:function(a,b){a.splice(0,b)},
+z4:function(a,b){var c=a[0];a[0]=a[b%a.length];a[b%a.length]=c}};
+g.Tz=function(a){return a.split("").reverse().join("")};
+oaa=function(a){var b=a.split(""),c=[function(d,e){e=(e%d.length+d.length)%d.length;d.splice(-e).reverse().forEach(function(f){d.unshift(f)})},
+-1460785423,function(d){d.reverse()},"pop"];c[0](b,c[1]);c[2](b);return b.join("")};
+Gxa=function(a){a=a.split("");Xy.z4(a,39);Xy.nF(a,3);Xy.VP(a,20);Xy.z4(a,57);Xy.nF(a,2);Xy.VP(a,69);Xy.z4(a,8);return a.join("")};
+paa=function(a,b){var c=a.length;b=b===void 0?0:b;for(var d=0;d<c;d++)if(a[d]==b)return d;return-1};
+g.Hb=function(a,b){this.j=a;this.sp=b};g.k=g.Hb.prototype;g.k.Mn=function(a){var b=this.j.get("signature");b&&this.set(this.sp||"signature",Gxa(b));return this};
+qaa=function(a){a=a.split(".");return a.length>1?a[1]:""};
+})(_yt_player);
@@ -307,7 +307,7 @@ async fn test_fetch_streaming_data() {
     assert_eq!(format.itag, 18);
     assert_eq!(format.width, Some(640));
     assert_eq!(format.height, Some(360));
-    assert_eq!(format.quality, "medium");
+    assert_eq!(format.quality.as_deref(), Some("medium"));
     assert_eq!(format.quality_label, Some("360p".to_string()));
 
     // Check adaptive format properties
@@ -4,9 +4,13 @@ use std::collections::HashMap;
 use serde_json::json;
 
 use crate::fetched_transcript::FetchedTranscript;
+use crate::hls_playlist_parser::HlsPlaylistParser;
 use crate::models::{
-    FetchedTranscriptSnippet, MicroformatData, MicroformatEmbed, MicroformatThumbnail, Range,
-    StreamingData, StreamingFormat, TranslationLanguage, VideoThumbnail,
+    Card, ChannelTab, ChannelUploads, ChannelVideo, Chapter, Comment, EndscreenElement,
+    EndscreenElementKind, FetchedTranscriptSnippet, HlsMasterPlaylist, HlsMediaPlaylist,
+    LinkTarget, LiveDetails, MicroformatData, MicroformatEmbed, MicroformatThumbnail, Playlist,
+    PlaylistEntry, Range, SearchPage, SearchResult, SearchVideo, Storyboard, StreamingData,
+    StreamingFormat, TranslationLanguage, VideoLinks, VideoThumbnail,
 };
 use crate::transcript::Transcript;
 use crate::transcript_list::TranscriptList;
@@ -184,6 +188,189 @@ pub fn create_mock_video_details() -> VideoDetails {
     }
 }
 
+/// Creates mock chapters for testing
+pub fn create_mock_chapters() -> Vec<Chapter> {
+    vec![
+        Chapter {
+            title: "Intro".to_string(),
+            start: 0.0,
+            end: 30.0,
+        },
+        Chapter {
+            title: "Main part".to_string(),
+            start: 30.0,
+            end: 90.0,
+        },
+        Chapter {
+            title: "Outro".to_string(),
+            start: 90.0,
+            end: 100.0,
+        },
+    ]
+}
+
+/// Creates mock live details for testing, describing a past live stream
+pub fn create_mock_live_details() -> LiveDetails {
+    LiveDetails {
+        is_live_content: true,
+        start_timestamp: chrono::DateTime::parse_from_rfc3339("2023-01-01T12:00:00+00:00").ok(),
+        end_timestamp: chrono::DateTime::parse_from_rfc3339("2023-01-01T13:40:00+00:00").ok(),
+        ..Default::default()
+    }
+}
+
+/// Creates a mock playlist for testing, with one available and one deleted video
+pub fn create_mock_playlist(playlist_id: &str) -> Playlist {
+    Playlist {
+        playlist_id: playlist_id.to_string(),
+        title: Some("Mock Playlist".to_string()),
+        author: Some("Mock Channel".to_string()),
+        description: None,
+        entries: vec![
+            PlaylistEntry {
+                video_id: MOCK_MULTILANG_VIDEO_ID.to_string(),
+                title: "Mock Video".to_string(),
+                author: Some("Mock Channel".to_string()),
+                length_seconds: Some(100),
+                index: 0,
+                is_available: true,
+            },
+            PlaylistEntry {
+                video_id: MOCK_NON_EXISTENT_VIDEO_ID.to_string(),
+                title: "[Deleted video]".to_string(),
+                author: None,
+                length_seconds: None,
+                index: 1,
+                is_available: false,
+            },
+        ],
+    }
+}
+
+/// Creates mock channel uploads for testing, with three videos on the requested tab
+pub fn create_mock_channel_uploads(tab: ChannelTab) -> ChannelUploads {
+    let videos = (1..=3)
+        .map(|i| ChannelVideo {
+            video_id: format!("mockvideo0{}", i),
+            title: format!("Mock Video {}", i),
+            length_seconds: (tab == ChannelTab::Videos).then_some(60 * i),
+            published_time_text: Some(format!("{} days ago", i)),
+            view_count_text: Some("1,000 views".to_string()),
+        })
+        .collect();
+
+    ChannelUploads {
+        channel_id: "UCmockchannel0000000000x".to_string(),
+        title: Some("Mock Channel".to_string()),
+        handle: Some("@MockChannel".to_string()),
+        tab,
+        videos,
+    }
+}
+
+/// Creates a mock search page for testing, with two videos and no next page
+pub fn create_mock_search_page(query: &str) -> SearchPage {
+    let results = (1..=2)
+        .map(|i| {
+            SearchResult::Video(SearchVideo {
+                video_id: format!("searchvid0{}", i),
+                title: format!("{} result {}", query, i),
+                channel_name: Some("Mock Channel".to_string()),
+                channel_id: Some("UCmockchannel0000000000x".to_string()),
+                length_seconds: Some(120 * i),
+                published_time_text: Some("1 year ago".to_string()),
+                view_count_text: Some("1,000 views".to_string()),
+                description_snippet: None,
+                has_captions: true,
+                is_live: false,
+            })
+        })
+        .collect();
+
+    SearchPage {
+        query: query.to_string(),
+        estimated_results: Some(2),
+        results,
+        continuation: None,
+    }
+}
+
+/// Creates mock comments for testing: a pinned comment with one reply, and a plain comment
+pub fn create_mock_comments() -> Vec<Comment> {
+    let comment = |id: &str, parent_id: Option<&str>, text: &str| Comment {
+        comment_id: id.to_string(),
+        parent_id: parent_id.map(str::to_string),
+        author: "@mockuser".to_string(),
+        author_channel_id: Some("UCmockuser00000000000000".to_string()),
+        is_author_uploader: false,
+        text: text.to_string(),
+        like_count: Some(10),
+        published_time_text: Some("1 day ago".to_string()),
+        reply_count: 0,
+        is_pinned: false,
+        replies: Vec::new(),
+    };
+
+    let mut pinned = comment("UgzMock1", None, "First mock comment");
+    pinned.is_pinned = true;
+    pinned.reply_count = 1;
+    pinned.replies = vec![comment("UgzMock1.reply1", Some("UgzMock1"), "Mock reply")];
+
+    vec![pinned, comment("UgzMock2", None, "Second mock comment")]
+}
+
+/// Creates a mock storyboard for testing, matching the 100 second mock transcript
+pub fn create_mock_storyboard() -> Storyboard {
+    crate::storyboard_extractor::StoryboardExtractor::parse_spec(
+        "https://i.ytimg.com/sb/arj7oStGLkU/storyboard3_L$L/$N.jpg?sqp=mock\
+         |48#27#100#10#10#0#default#rs$mock0|160#90#50#5#5#2000#M$M#rs$mock1",
+        100.0,
+    )
+    .unwrap()
+}
+
+/// Creates mock video links for testing: a subscribe button and a video on the
+/// endscreen, a card and the links of a timestamped description
+pub fn create_mock_video_links() -> VideoLinks {
+    let endscreen_element = |kind, title: &str, target, left| EndscreenElement {
+        kind,
+        title: title.to_string(),
+        target: Some(target),
+        start: 80.0,
+        end: 100.0,
+        left,
+        top: 0.3,
+        width: 0.4,
+        aspect_ratio: 1.0,
+    };
+
+    VideoLinks {
+        endscreen: vec![
+            endscreen_element(
+                EndscreenElementKind::Subscribe,
+                "Test Author",
+                LinkTarget::Channel("test-channel".to_string()),
+                0.05,
+            ),
+            endscreen_element(
+                EndscreenElementKind::Video,
+                "Next Test Video",
+                LinkTarget::Video(crate::tests::test_utils::NON_EXISTENT_VIDEO_ID.to_string()),
+                0.55,
+            ),
+        ],
+        cards: vec![Card {
+            title: "Test Playlist".to_string(),
+            teaser: Some("More tests".to_string()),
+            target: Some(LinkTarget::Playlist("PLtest".to_string())),
+            start: 30.0,
+        }],
+        description: crate::links_extractor::LinksExtractor::parse_description(
+            "0:00 Intro\n0:30 Main part\n1:30 Outro\nMore at https://example.com #test @TestAuthor",
+        ),
+    }
+}
+
 // Create a mock HTTP client for tests
 pub fn create_mock_client() -> Client {
     // In real implementation, we'd use a more sophisticated HTTP mocking library
@@ -253,21 +440,22 @@ pub fn create_mock_streaming_data() -> StreamingData {
         formats: vec![StreamingFormat {
             itag: 18,
             url: Some("https://example.com/video.mp4".to_string()),
+            signature_cipher: None,
             mime_type: "video/mp4; codecs=\"avc1.42001E, mp4a.40.2\"".to_string(),
-            bitrate: 347177,
+            bitrate: Some(347177),
             width: Some(640),
             height: Some(360),
             init_range: None,
             index_range: None,
             last_modified: Some("1739036631573310".to_string()),
             content_length: Some("36612752".to_string()),
-            quality: "medium".to_string(),
+            quality: Some("medium".to_string()),
             fps: Some(24),
             quality_label: Some("360p".to_string()),
-            projection_type: "RECTANGULAR".to_string(),
+            projection_type: Some("RECTANGULAR".to_string()),
             average_bitrate: Some(347136),
             audio_quality: Some("AUDIO_QUALITY_LOW".to_string()),
-            approx_duration_ms: "843766".to_string(),
+            approx_duration_ms: Some("843766".to_string()),
             audio_sample_rate: Some("44100".to_string()),
             audio_channels: Some(2),
             quality_ordinal: Some("QUALITY_ORDINAL_360P".to_string()),
@@ -276,14 +464,16 @@ pub fn create_mock_streaming_data() -> StreamingData {
             loudness_db: None,
             is_drc: None,
             xtags: None,
+            extra: Default::default(),
         }],
         adaptive_formats: vec![
             // High quality video format
             StreamingFormat {
                 itag: 136,
                 url: Some("https://example.com/video_720p.mp4".to_string()),
+                signature_cipher: None,
                 mime_type: "video/mp4; codecs=\"avc1.4d401f\"".to_string(),
-                bitrate: 582678,
+                bitrate: Some(582678),
                 width: Some(1280),
                 height: Some(720),
                 init_range: Some(Range {
@@ -296,13 +486,13 @@ pub fn create_mock_streaming_data() -> StreamingData {
                 }),
                 last_modified: Some("1739044586331610".to_string()),
                 content_length: Some("26758604".to_string()),
-                quality: "hd720".to_string(),
+                quality: Some("hd720".to_string()),
                 fps: Some(24),
                 quality_label: Some("720p".to_string()),
-                projection_type: "RECTANGULAR".to_string(),
+                projection_type: Some("RECTANGULAR".to_string()),
                 average_bitrate: Some(253736),
                 audio_quality: None,
-                approx_duration_ms: "843666".to_string(),
+                approx_duration_ms: Some("843666".to_string()),
                 audio_sample_rate: None,
                 audio_channels: None,
                 quality_ordinal: Some("QUALITY_ORDINAL_720P".to_string()),
@@ -311,13 +501,15 @@ pub fn create_mock_streaming_data() -> StreamingData {
                 loudness_db: None,
                 is_drc: None,
                 xtags: None,
+                extra: Default::default(),
             },
             // High quality audio format
             StreamingFormat {
                 itag: 140,
                 url: Some("https://example.com/audio.mp4".to_string()),
+                signature_cipher: None,
                 mime_type: "audio/mp4; codecs=\"mp4a.40.2\"".to_string(),
-                bitrate: 130904,
+                bitrate: Some(130904),
                 width: None,
                 height: None,
                 init_range: Some(Range {
@@ -330,13 +522,13 @@ pub fn create_mock_streaming_data() -> StreamingData {
                 }),
                 last_modified: Some("1739026344625664".to_string()),
                 content_length: Some("13656269".to_string()),
-                quality: "tiny".to_string(),
+                quality: Some("tiny".to_string()),
                 fps: None,
                 quality_label: None,
-                projection_type: "RECTANGULAR".to_string(),
+                projection_type: Some("RECTANGULAR".to_string()),
                 average_bitrate: Some(129479),
                 audio_quality: Some("AUDIO_QUALITY_MEDIUM".to_string()),
-                approx_duration_ms: "843766".to_string(),
+                approx_duration_ms: Some("843766".to_string()),
                 audio_sample_rate: Some("44100".to_string()),
                 audio_channels: Some(2),
                 quality_ordinal: Some("QUALITY_ORDINAL_UNKNOWN".to_string()),
@@ -345,8 +537,25 @@ pub fn create_mock_streaming_data() -> StreamingData {
                 loudness_db: Some(-2.6200008),
                 is_drc: None,
                 xtags: None,
+                extra: Default::default(),
             },
         ],
         server_abr_streaming_url: Some("https://example.com/streaming.mp4".to_string()),
+        hls_manifest_url: None,
+        dash_manifest_url: None,
+        fetched_at: None,
+        skipped_formats: Vec::new(),
     }
 }
+
+/// Creates a mock HLS master playlist from the saved live stream playlist
+pub fn create_mock_hls_master_playlist() -> HlsMasterPlaylist {
+    HlsPlaylistParser::parse_master(include_str!("fixtures/hls/master.m3u8"), None)
+        .expect("Invalid master playlist fixture")
+}
+
+/// Creates a mock HLS media playlist from the saved live stream playlist
+pub fn create_mock_hls_media_playlist() -> HlsMediaPlaylist {
+    HlsPlaylistParser::parse_media(include_str!("fixtures/hls/media.m3u8"), None)
+        .expect("Invalid media playlist fixture")
+}
@@ -378,16 +378,22 @@ async fn test_fetch_streaming_data() {
     for format in &streaming_data.formats {
         assert!(format.itag > 0, "Invalid itag value");
         assert!(!format.mime_type.is_empty(), "MIME type is empty");
-        assert!(format.bitrate > 0, "Bitrate is zero or negative");
-        assert!(!format.approx_duration_ms.is_empty(), "Duration is empty");
+        assert!(
+            format.bitrate.is_some_and(|b| b > 0),
+            "Bitrate is missing or zero"
+        );
+        assert!(format.approx_duration_ms.is_some(), "Duration is missing");
     }
 
     // Check some adaptive format details
     for format in &streaming_data.adaptive_formats {
         assert!(format.itag > 0, "Invalid itag value");
         assert!(!format.mime_type.is_empty(), "MIME type is empty");
-        assert!(format.bitrate > 0, "Bitrate is zero or negative");
-        assert!(!format.approx_duration_ms.is_empty(), "Duration is empty");
+        assert!(
+            format.bitrate.is_some_and(|b| b > 0),
+            "Bitrate is missing or zero"
+        );
+        assert!(format.approx_duration_ms.is_some(), "Duration is missing");
 
         // Check for video-specific properties
         if format.mime_type.starts_with("video/") {
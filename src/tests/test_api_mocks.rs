@@ -4,6 +4,10 @@ use super::mocks::{
 };
 #[allow(unused_imports)]
 use super::test_utils::{create_api, setup, MULTILANG_VIDEO_ID, NON_EXISTENT_VIDEO_ID};
+#[allow(unused_imports)]
+use crate::models::{
+    ChannelTab, CodecFamily, CommentSortOrder, DescriptionLinkKind, MediaKind, SearchFilters,
+};
 
 // #[cfg(feature = "ci")]
 #[tokio::test]
@@ -58,6 +62,32 @@ async fn test_find_transcript() {
     assert!(transcript.is_err(), "Found a non-existent transcript");
 }
 
+#[cfg(feature = "ci")]
+#[tokio::test]
+async fn test_fetch_transcript_with_dedup() {
+    setup();
+    let api = create_api();
+
+    let transcript = api
+        .fetch_transcript(MULTILANG_VIDEO_ID, &["en"], false)
+        .await
+        .unwrap();
+    let deduplicated = api
+        .fetch_transcript_with_dedup(MULTILANG_VIDEO_ID, &["en"], false, true)
+        .await
+        .unwrap();
+
+    // The mock lines do not repeat each other
+    assert_eq!(deduplicated.snippets.len(), transcript.snippets.len());
+    assert_eq!(deduplicated.text(), transcript.text());
+
+    // Test non-existent video
+    let result = api
+        .fetch_transcript_with_dedup(NON_EXISTENT_VIDEO_ID, &["en"], false, true)
+        .await;
+    assert!(result.is_err(), "Successfully fetched non-existent video");
+}
+
 // Implement the other tests similarly...
 
 #[cfg(feature = "ci")]
@@ -120,7 +150,8 @@ async fn test_fetch_streaming_data() {
     let format = &streaming_data.formats[0];
     assert_eq!(format.itag, 18, "Format itag doesn't match mock data");
     assert_eq!(
-        format.quality, "medium",
+        format.quality.as_deref(),
+        Some("medium"),
         "Format quality doesn't match mock data"
     );
     assert_eq!(
@@ -168,7 +199,343 @@ async fn test_fetch_streaming_data() {
         "Expiration time is empty"
     );
 
+    // Check format selection
+    assert_eq!(streaming_data.best_muxed().map(|f| f.itag), Some(18));
+    assert_eq!(
+        streaming_data.best_video_under(720).map(|f| f.itag),
+        Some(136)
+    );
+    assert_eq!(streaming_data.best_audio().map(|f| f.itag), Some(140));
+    assert!(streaming_data.select().codec("vp9").is_empty());
+
+    // Check media types
+    let media_type = format.media_type().expect("Muxed format has no media type");
+    assert_eq!(media_type.kind, MediaKind::Video);
+    assert_eq!(media_type.container, "mp4");
+    assert_eq!(
+        media_type.audio_codec().map(|codec| codec.family),
+        Some(CodecFamily::Aac)
+    );
+
+    // Check the DASH manifest, with the transcripts as subtitle tracks
+    let transcript_list = create_mock_transcript_list(reqwest::Client::new());
+    let mpd = streaming_data.to_dash_mpd_with_subtitles(&transcript_list);
+    assert!(mpd.contains("mediaPresentationDuration=\"PT843.766S\""));
+    assert!(mpd.contains("<Representation id=\"136\" codecs=\"avc1.4d401f\""));
+    assert!(mpd.contains("<Representation id=\"140\" codecs=\"mp4a.40.2\""));
+    assert!(!mpd.contains("<Representation id=\"18\""));
+    assert!(mpd.contains("<SegmentBase indexRange=\"740-2703\">"));
+    assert!(mpd.contains("mimeType=\"text/vtt\" lang=\"en\""));
+    assert!(mpd.contains("<BaseURL>https://mock.url/es?fmt=vtt</BaseURL>"));
+    assert!(!streaming_data.to_dash_mpd().contains("text/vtt"));
+
     // Test non-existent video
     let result = api.fetch_streaming_data(NON_EXISTENT_VIDEO_ID).await;
     assert!(result.is_err(), "Successfully fetched non-existent video");
 }
+
+#[cfg(feature = "ci")]
+#[tokio::test]
+async fn test_fetch_chapters() {
+    setup();
+    let api = create_api();
+
+    let chapters = api
+        .fetch_chapters(MULTILANG_VIDEO_ID)
+        .await
+        .expect("Failed to fetch chapters");
+
+    assert_eq!(chapters.len(), 3, "Chapter count doesn't match mock data");
+    assert_eq!(chapters[0].start, 0.0, "First chapter doesn't start at 0");
+    for pair in chapters.windows(2) {
+        assert_eq!(pair[0].end, pair[1].start, "Chapters are not contiguous");
+    }
+
+    // Test non-existent video
+    let result = api.fetch_chapters(NON_EXISTENT_VIDEO_ID).await;
+    assert!(result.is_err(), "Successfully fetched non-existent video");
+}
+
+#[cfg(feature = "ci")]
+#[tokio::test]
+async fn test_fetch_playlist_transcripts() {
+    setup();
+    let api = create_api();
+
+    let playlist = api
+        .fetch_playlist("https://www.youtube.com/playlist?list=PLmock1234")
+        .await
+        .expect("Failed to fetch playlist");
+    assert_eq!(playlist.playlist_id, "PLmock1234");
+    assert_eq!(playlist.available_video_ids(), vec![MULTILANG_VIDEO_ID]);
+
+    // Unavailable entries are skipped
+    let results = api
+        .fetch_playlist_transcripts("PLmock1234", &["en"], false)
+        .await
+        .expect("Failed to fetch playlist transcripts");
+    assert_eq!(results.len(), 1);
+    assert!(
+        results[0].1.is_ok(),
+        "Failed to fetch playlist entry transcript"
+    );
+
+    // Batch results keep the input order, failures included
+    let results = api
+        .fetch_transcripts(&[MULTILANG_VIDEO_ID, NON_EXISTENT_VIDEO_ID], &["en"], false)
+        .await;
+    assert_eq!(results[0].0, MULTILANG_VIDEO_ID);
+    assert!(results[0].1.is_ok());
+    assert!(results[1].1.is_err());
+
+    // Input without a playlist ID
+    let result = api
+        .fetch_playlist("https://www.youtube.com/watch?v=arj7oStGLkU")
+        .await;
+    assert!(result.is_err(), "Fetched a playlist from a URL without one");
+}
+
+#[cfg(feature = "ci")]
+#[tokio::test]
+async fn test_fetch_channel_videos() {
+    setup();
+    let api = create_api();
+
+    let uploads = api
+        .fetch_channel_videos("@MockChannel", ChannelTab::Shorts, Some(2))
+        .await
+        .expect("Failed to fetch channel videos");
+    assert_eq!(uploads.tab, ChannelTab::Shorts);
+    assert_eq!(uploads.video_ids().len(), 2, "Limit was not applied");
+
+    // Test non-existent channel
+    let result = api
+        .fetch_channel_videos(NON_EXISTENT_VIDEO_ID, ChannelTab::Videos, None)
+        .await;
+    assert!(result.is_err(), "Successfully fetched non-existent channel");
+}
+
+#[cfg(feature = "ci")]
+#[tokio::test]
+async fn test_search() {
+    setup();
+    let api = create_api();
+
+    let filters = SearchFilters {
+        captions_only: true,
+        ..Default::default()
+    };
+    let page = api
+        .search("rust ownership", &filters)
+        .await
+        .expect("Failed to search");
+    assert_eq!(page.query, "rust ownership");
+    assert_eq!(page.videos().count(), 2);
+    assert!(page.videos().all(|video| video.has_captions));
+
+    let next = api
+        .search_next_page(&page)
+        .await
+        .expect("Failed to fetch next page");
+    assert!(next.is_none(), "Mock search has a single page");
+}
+
+#[cfg(feature = "ci")]
+#[tokio::test]
+async fn test_fetch_comments() {
+    setup();
+    let api = create_api();
+
+    let comments = api
+        .fetch_comments(MULTILANG_VIDEO_ID, CommentSortOrder::Top, true, None)
+        .await
+        .expect("Failed to fetch comments");
+    assert_eq!(comments.len(), 2);
+    assert!(comments[0].is_pinned);
+    assert_eq!(comments[0].replies.len() as u64, comments[0].reply_count);
+    assert_eq!(
+        comments[0].replies[0].parent_id.as_deref(),
+        Some(comments[0].comment_id.as_str())
+    );
+
+    // Replies are only fetched on request
+    let comments = api
+        .fetch_comments(MULTILANG_VIDEO_ID, CommentSortOrder::Newest, false, Some(1))
+        .await
+        .expect("Failed to fetch comments");
+    assert_eq!(comments.len(), 1);
+    assert!(comments[0].replies.is_empty());
+
+    // Test non-existent video
+    let result = api
+        .fetch_comments(NON_EXISTENT_VIDEO_ID, CommentSortOrder::Top, false, None)
+        .await;
+    assert!(result.is_err(), "Successfully fetched non-existent video");
+}
+
+#[cfg(feature = "ci")]
+#[tokio::test]
+async fn test_fetch_video_links() {
+    setup();
+    let api = create_api();
+
+    let links = api
+        .fetch_video_links(MULTILANG_VIDEO_ID)
+        .await
+        .expect("Failed to fetch video links");
+    assert_eq!(links.endscreen.len(), 2);
+    assert_eq!(links.cards.len(), 1);
+    assert_eq!(links.video_ids(), vec![NON_EXISTENT_VIDEO_ID]);
+
+    // Description timestamps match the chapters
+    let chapters = api
+        .fetch_chapters(MULTILANG_VIDEO_ID)
+        .await
+        .expect("Failed to fetch chapters");
+    let timestamps: Vec<f64> = links
+        .description
+        .iter()
+        .filter_map(|link| match link.kind {
+            DescriptionLinkKind::Timestamp { seconds } => Some(seconds),
+            _ => None,
+        })
+        .collect();
+    let starts: Vec<f64> = chapters.iter().map(|c| c.start).collect();
+    assert_eq!(timestamps, starts);
+
+    // Test non-existent video
+    let result = api.fetch_video_links(NON_EXISTENT_VIDEO_ID).await;
+    assert!(result.is_err(), "Successfully fetched non-existent video");
+}
+
+#[cfg(feature = "ci")]
+#[tokio::test]
+async fn test_fetch_storyboard() {
+    setup();
+    let api = create_api();
+
+    let storyboard = api
+        .fetch_storyboard(MULTILANG_VIDEO_ID)
+        .await
+        .expect("Failed to fetch storyboard")
+        .expect("Mock video has no storyboard");
+    let transcript = api
+        .fetch_transcript(MULTILANG_VIDEO_ID, &["en"], false)
+        .await
+        .expect("Failed to fetch transcript");
+
+    // Every transcript line has a preview frame
+    for snippet in transcript.parts() {
+        let frame = storyboard
+            .frame_at(snippet.start)
+            .expect("No frame for transcript line");
+        assert!(frame.start <= snippet.start);
+    }
+
+    // Test non-existent video
+    let result = api.fetch_storyboard(NON_EXISTENT_VIDEO_ID).await;
+    assert!(result.is_err(), "Successfully fetched non-existent video");
+}
+
+#[cfg(feature = "ci")]
+#[tokio::test]
+async fn test_fetch_live_details() {
+    setup();
+    let api = create_api();
+
+    let live = api
+        .fetch_live_details(MULTILANG_VIDEO_ID)
+        .await
+        .expect("Failed to fetch live details");
+
+    assert!(live.is_live_content, "Mock video should be live content");
+    assert!(!live.is_live_now, "Mock stream should have ended");
+    assert!(
+        live.end_timestamp > live.start_timestamp,
+        "Stream should end after it starts"
+    );
+
+    // Test non-existent video
+    let result = api.fetch_live_details(NON_EXISTENT_VIDEO_ID).await;
+    assert!(result.is_err(), "Successfully fetched non-existent video");
+}
+
+#[cfg(feature = "ci")]
+#[tokio::test]
+async fn test_fetch_hls_playlist() {
+    setup();
+    let api = create_api();
+
+    let master = api
+        .fetch_hls_playlist(MULTILANG_VIDEO_ID)
+        .await
+        .expect("Failed to fetch HLS playlist")
+        .expect("No HLS playlist");
+    assert!(!master.variants.is_empty());
+
+    let variant = master.best_under(720).expect("No variant under 720p");
+    assert_eq!(variant.height, Some(720));
+    assert_eq!(variant.itag, Some(95));
+
+    let media = api
+        .fetch_hls_media_playlist(MULTILANG_VIDEO_ID, variant)
+        .await
+        .expect("Failed to fetch HLS media playlist");
+    assert!(!media.segments.is_empty());
+    assert!(media.segments.iter().all(|s| s.duration > 0.0));
+
+    // Test non-existent video
+    let result = api.fetch_hls_playlist(NON_EXISTENT_VIDEO_ID).await;
+    assert!(result.is_err(), "Successfully fetched non-existent video");
+}
+
+#[cfg(feature = "ci")]
+#[tokio::test]
+async fn test_refresh_streaming_data() {
+    setup();
+    let api = create_api();
+
+    // Data extracted long ago, whose URLs have expired
+    let mut streaming_data = super::mocks::create_mock_streaming_data();
+    streaming_data.fetched_at = chrono::DateTime::from_timestamp(1_600_000_000, 0);
+    assert!(streaming_data.is_expired());
+    streaming_data.formats.clear();
+
+    let refreshed = api
+        .refresh_streaming_data(MULTILANG_VIDEO_ID, &mut streaming_data)
+        .await
+        .expect("Failed to refresh streaming data");
+    assert!(refreshed);
+    assert!(!streaming_data.formats.is_empty());
+
+    // The mock data has no expiry and is left unchanged
+    let refreshed = api
+        .refresh_streaming_data(MULTILANG_VIDEO_ID, &mut streaming_data)
+        .await
+        .expect("Failed to refresh streaming data");
+    assert!(!refreshed);
+}
+
+#[cfg(feature = "ci")]
+#[tokio::test]
+async fn test_fetch_player_response() {
+    setup();
+    let api = create_api();
+
+    let player_response = api
+        .fetch_player_response(MULTILANG_VIDEO_ID)
+        .await
+        .expect("Failed to fetch player response");
+    assert_eq!(player_response["playabilityStatus"]["status"], "OK");
+
+    // The raw captions are those the transcript list is built from
+    let caption_tracks = player_response["captions"]["playerCaptionsTracklistRenderer"]
+        ["captionTracks"]
+        .as_array()
+        .expect("No caption tracks");
+    assert!(!caption_tracks.is_empty());
+
+    // Test non-existent video
+    let result = api.fetch_player_response(NON_EXISTENT_VIDEO_ID).await;
+    assert!(result.is_err(), "Successfully fetched non-existent video");
+}
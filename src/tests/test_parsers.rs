@@ -90,6 +90,29 @@ fn test_js_var_parser_with_alternate_format() {
     assert_eq!(result["simple"], json!("value"));
 }
 
+#[test]
+fn test_js_var_parser_with_window_assignment() {
+    let parser = JsVarParser::new("ytInitialData");
+
+    // YouTube resets the variable to null before assigning the page data
+    let html = r#"
+    <script>
+    window["ytInitialData"] = null;
+    var other = {"ytInitialData": "not this one"};
+    window["ytInitialData"] = {"contents": {"items": ["a", "b;c"]}};</script>
+    <script>window.other = {};</script>
+    "#;
+
+    let result = parser.parse(html, "test").unwrap();
+    assert_eq!(result["contents"]["items"][1], json!("b;c"));
+
+    let parser = JsVarParser::new("dotVar");
+    let html = r#"<script>window.dotVar={"key": {"inner": 1}};</script>"#;
+
+    let result = parser.parse(html, "test").unwrap();
+    assert_eq!(result["key"]["inner"], json!(1));
+}
+
 /// Test the extraction of microformat data from a simplified JSON
 #[tokio::test]
 async fn test_microformat_extraction_simple() {
@@ -277,6 +300,18 @@ async fn test_microformat_extraction_comprehensive() {
 
     let microformat = result.unwrap();
 
+    // Check typed accessors
+    assert_eq!(
+        microformat.parsed_length(),
+        Some(std::time::Duration::from_secs(844))
+    );
+    assert_eq!(microformat.parsed_like_count(), Some(2_009_473));
+    assert_eq!(microformat.parsed_view_count(), Some(58_968_839));
+    let upload_date = microformat.parsed_upload_date().unwrap();
+    assert_eq!(upload_date.to_rfc3339(), "2016-04-06T09:59:35-07:00");
+    assert_eq!(upload_date.offset().local_minus_utc(), -7 * 3600);
+    assert_eq!(microformat.parsed_publish_date(), Some(upload_date));
+
     // Verify specific fields from the known data
     assert_eq!(
         microformat.external_video_id,
@@ -564,3 +599,348 @@ async fn test_streaming_data_extraction_comprehensive() {
         Some("https://example.com/streaming.mp4".to_string())
     );
 }
+
+/// Test the tolerant parsing of typed numeric and date fields
+#[cfg(feature = "ci")]
+#[test]
+fn test_typed_field_parsing() {
+    use crate::tests::mocks::{create_mock_microformat_data, create_mock_video_details};
+    use std::time::Duration;
+
+    let mut details = create_mock_video_details();
+    assert_eq!(details.parsed_view_count(), Some(100));
+    assert_eq!(details.length(), Duration::from_secs(100));
+
+    details.view_count = " 1,234,567 ".to_string();
+    assert_eq!(details.parsed_view_count(), Some(1_234_567));
+    details.view_count = "No views".to_string();
+    assert_eq!(details.parsed_view_count(), None);
+
+    let mut microformat = create_mock_microformat_data();
+    assert_eq!(microformat.parsed_length(), Some(Duration::from_secs(300)));
+    assert_eq!(microformat.parsed_like_count(), Some(1000));
+    assert_eq!(microformat.parsed_view_count(), Some(10000));
+    assert_eq!(
+        microformat.parsed_publish_date().map(|d| d.to_rfc3339()),
+        Some("2023-01-01T12:00:00+00:00".to_string())
+    );
+
+    // Older videos only have a date, interpreted as midnight UTC
+    microformat.upload_date = Some("2009-10-24".to_string());
+    assert_eq!(
+        microformat.parsed_upload_date().map(|d| d.to_rfc3339()),
+        Some("2009-10-24T00:00:00+00:00".to_string())
+    );
+
+    // Missing and invalid values
+    microformat.like_count = None;
+    microformat.length_seconds = Some("unknown".to_string());
+    microformat.publish_date = Some("yesterday".to_string());
+    assert_eq!(microformat.parsed_like_count(), None);
+    assert_eq!(microformat.parsed_length(), None);
+    assert_eq!(microformat.parsed_publish_date(), None);
+}
+
+#[test]
+fn test_search_response_fixture() {
+    use crate::models::SearchResult;
+    use crate::search_fetcher::SearchFetcher;
+
+    let response: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/search_response.json")).unwrap();
+    let page = SearchFetcher::parse_response(&response, "rust ownership");
+
+    assert_eq!(page.query, "rust ownership");
+    assert_eq!(page.estimated_results, Some(2_184_731));
+    assert!(page
+        .continuation
+        .as_deref()
+        .unwrap()
+        .starts_with("EoIDEg5y"));
+
+    // Shelf videos are not part of the ranked results
+    assert_eq!(page.results.len(), 4);
+    assert!(matches!(&page.results[0], SearchResult::Channel(c)
+        if c.subscriber_count_text.as_deref() == Some("41.2K subscribers")));
+    assert!(matches!(&page.results[2], SearchResult::Playlist(p) if p.video_count == Some(56)));
+
+    let videos: Vec<_> = page.videos().collect();
+    assert_eq!(videos.len(), 2);
+
+    let video = videos[0];
+    assert_eq!(video.video_id, "VFIOSWy93H0");
+    assert_eq!(video.channel_name.as_deref(), Some("Let's Get Rusty"));
+    assert_eq!(
+        video.channel_id.as_deref(),
+        Some("UCSp-OaMpsO8K0KkOqyBl7_w")
+    );
+    assert_eq!(video.length_seconds, Some(1492));
+    assert_eq!(
+        video.description_snippet.as_deref(),
+        Some("In this video we go over ownership in Rust.")
+    );
+    assert!(video.has_captions);
+    assert!(!video.is_live);
+
+    let live = videos[1];
+    assert!(live.is_live);
+    assert_eq!(live.length_seconds, None);
+    assert_eq!(live.view_count_text.as_deref(), Some("1,024 watching"));
+}
+
+#[test]
+fn test_signature_decipher_player_fixtures() {
+    use crate::signature_decipher::{CipherOperation, SignatureDecipher};
+
+    const SIGNATURE: &str = "AOq0QJ8wRQIhAJ1kGmmqPqDkCpeSuOvVUaWCuwHyrWpOHoYK2VHl0Wg9AiBp3Ue9x4hvbyYT0zMFCkoOpHahDFsGxaeGWBpKhb2NsQ==";
+
+    // Expected values were produced by running each player's own function on SIGNATURE
+    let dotted =
+        SignatureDecipher::from_player_js(include_str!("fixtures/player_js/base_dotted.js"))
+            .expect("No decipher in dotted player");
+    assert_eq!(
+        dotted.operations(),
+        &[
+            CipherOperation::Swap(39),
+            CipherOperation::Splice(3),
+            CipherOperation::Reverse,
+            CipherOperation::Swap(57),
+            CipherOperation::Splice(2),
+            CipherOperation::Reverse,
+            CipherOperation::Swap(8),
+        ]
+    );
+    assert_eq!(
+        dotted.decipher(SIGNATURE),
+        "hQJ8wRQI0AJ1kGmmqPqDkCpeSuOvVUaWCuwHArWpOHo=K2VHl0Wg9AiBp3Ue9x4hvbyYT0zMFCkoOpHahDFsGxaeGWBpKhb2NsQ"
+    );
+
+    // Quoted method names, bracket calls and names containing "$"
+    let bracket =
+        SignatureDecipher::from_player_js(include_str!("fixtures/player_js/base_bracket.js"))
+            .expect("No decipher in bracket player");
+    assert_eq!(bracket.operations().len(), 6);
+    assert_eq!(
+        bracket.decipher(SIGNATURE),
+        "UQJ8wRQIhAJ1kGmmqPqDkCpeSuOvV0aWC=wHyrWpOHoYK2VHl0Wg9AiBp3Ue9x4hvbyYT0zMFCkoOpHahDFsGxaeGWBpKhb2NsQu"
+    );
+}
+
+#[test]
+fn test_hls_playlist_fixtures() {
+    use crate::hls_playlist_parser::HlsPlaylistParser;
+    use crate::streaming_data_extractor::StreamingDataExtractor;
+
+    const MASTER_URL: &str =
+        "https://manifest.googlevideo.com/api/manifest/hls_variant/id/jfKfPfyJRdk.1/file/index.m3u8";
+
+    // The manifest URLs of a live stream
+    let player_response = json!({"streamingData": {
+        "expiresInSeconds": "21540",
+        "hlsManifestUrl": MASTER_URL,
+        "dashManifestUrl": "https://manifest.googlevideo.com/api/manifest/dash/id/jfKfPfyJRdk.1"
+    }});
+    let streaming_data =
+        StreamingDataExtractor::extract_streaming_data(&player_response, "jfKfPfyJRdk").unwrap();
+    assert_eq!(streaming_data.hls_manifest_url.as_deref(), Some(MASTER_URL));
+    assert!(streaming_data.dash_manifest_url.is_some());
+    assert!(streaming_data.adaptive_formats.is_empty());
+
+    let master =
+        HlsPlaylistParser::parse_master(include_str!("fixtures/hls/master.m3u8"), Some(MASTER_URL))
+            .expect("Invalid master playlist");
+    assert_eq!(master.variants.len(), 6);
+    assert_eq!(
+        master.variants.iter().map(|v| v.itag).collect::<Vec<_>>(),
+        [91, 92, 93, 94, 95, 96].map(Some)
+    );
+
+    let variant = master.variant(95).unwrap();
+    assert_eq!(variant.bandwidth, 2969452);
+    assert_eq!(variant.average_bandwidth, None);
+    assert_eq!(variant.codecs, ["avc1.4d401f", "mp4a.40.2"]);
+    assert_eq!((variant.width, variant.height), (Some(1280), Some(720)));
+    assert_eq!(variant.frame_rate, Some(30.0));
+    // Absolute URLs are kept as they are
+    assert!(variant
+        .url
+        .starts_with("https://manifest.googlevideo.com/api/manifest/hls_playlist/"));
+    assert!(variant.url.ends_with("/file/index.m3u8"));
+
+    assert_eq!(master.best().and_then(|v| v.itag), Some(96));
+    assert_eq!(master.best_under(480).and_then(|v| v.itag), Some(94));
+    assert_eq!(master.best_under(100), None);
+
+    let media = HlsPlaylistParser::parse_media(include_str!("fixtures/hls/media.m3u8"), None)
+        .expect("Invalid media playlist");
+    assert_eq!(media.target_duration, 5);
+    assert_eq!(media.media_sequence, 8412);
+    assert!(!media.ended);
+    assert_eq!(media.segments.len(), 6);
+    assert_eq!(media.duration(), 30.0);
+
+    let last = media.segments.last().unwrap();
+    assert_eq!(last.sequence, 8417);
+    assert_eq!(last.duration, 5.0);
+    assert!(last.url.contains("/itag/95/") && last.url.contains("/sq/8417/"));
+    assert_eq!(
+        last.program_date_time.as_deref(),
+        Some("2025-10-18T14:30:35.000+00:00")
+    );
+}
+
+#[test]
+fn test_url_expiry() {
+    use crate::streaming_data_extractor::StreamingDataExtractor;
+    use crate::transcript::Transcript;
+    use chrono::{DateTime, TimeDelta, Utc};
+
+    let format = |itag: u32, url_field: &str, url: &str| {
+        json!({
+            "itag": itag, url_field: url, "mimeType": "audio/mp4; codecs=\"mp4a.40.2\"",
+            "bitrate": 130000, "quality": "tiny", "projectionType": "RECTANGULAR",
+            "approxDurationMs": "212091"
+        })
+    };
+    let player_response = json!({"streamingData": {
+        "expiresInSeconds": "21540",
+        "formats": [format(18, "url", "https://rr1---sn-abc.googlevideo.com/videoplayback?expire=1760848000&itag=18")],
+        "adaptiveFormats": [
+            format(140, "url", "https://rr1---sn-abc.googlevideo.com/videoplayback?expire=1760847000&itag=140"),
+            format(251, "signatureCipher", "s=abc&sp=sig&url=https%3A%2F%2Frr1---sn-abc.googlevideo.com%2Fvideoplayback%3Fexpire%3D1760846000%26itag%3D251")
+        ],
+        "hlsManifestUrl": "https://manifest.googlevideo.com/api/manifest/hls_variant/expire/1760849000/id/abc.1/file/index.m3u8"
+    }});
+    let streaming_data =
+        StreamingDataExtractor::extract_streaming_data(&player_response, "test").unwrap();
+
+    let expire = |timestamp: i64| DateTime::from_timestamp(timestamp, 0);
+    assert_eq!(streaming_data.formats[0].expires_at(), expire(1760848000));
+    // Protected formats expire with their cipher URL
+    assert_eq!(
+        streaming_data.adaptive_formats[1].expires_at(),
+        expire(1760846000)
+    );
+    // The earliest URL expiry wins over expiresInSeconds
+    assert_eq!(streaming_data.expires_at(), expire(1760846000));
+    assert!(streaming_data.is_expired());
+
+    // Without URL expiry, the expiry is computed from the request time, which is
+    // set by the fetcher
+    let player_response = json!({"streamingData": {"expiresInSeconds": "21540"}});
+    let mut streaming_data =
+        StreamingDataExtractor::extract_streaming_data(&player_response, "test").unwrap();
+    assert_eq!(streaming_data.fetched_at, None);
+    assert_eq!(streaming_data.expires_at(), None);
+
+    let fetched_at = Utc::now();
+    streaming_data.fetched_at = Some(fetched_at);
+    assert_eq!(
+        streaming_data.expires_at(),
+        Some(fetched_at + TimeDelta::seconds(21540))
+    );
+    assert!(!streaming_data.is_expired());
+
+    let transcript = Transcript::new(
+        "test".to_string(),
+        "https://www.youtube.com/api/timedtext?v=test&expire=1760850000&lang=en".to_string(),
+        "English".to_string(),
+        "en".to_string(),
+        false,
+        vec![],
+    );
+    assert_eq!(transcript.expires_at(), expire(1760850000));
+    assert!(transcript.is_expired());
+
+    let transcript = Transcript::new(
+        "test".to_string(),
+        "https://www.youtube.com/api/timedtext?v=test&lang=en".to_string(),
+        "English".to_string(),
+        "en".to_string(),
+        false,
+        vec![],
+    );
+    assert_eq!(transcript.expires_at(), None);
+    assert!(!transcript.is_expired());
+}
+
+#[test]
+fn test_tolerant_format_parsing() {
+    use crate::models::SkippedFormat;
+    use crate::streaming_data_extractor::StreamingDataExtractor;
+
+    let player_response = json!({"streamingData": {
+        "expiresInSeconds": "21540",
+        "formats": [
+            {"itag": 18, "mimeType": "video/mp4; codecs=\"avc1.42001E, mp4a.40.2\""},
+            {"mimeType": "video/mp4; codecs=\"avc1.42001E, mp4a.40.2\"", "bitrate": 500000}
+        ],
+        "adaptiveFormats": [
+            // A live stream format, without duration, projection type or quality
+            {
+                "itag": 136, "url": "https://example.com/136", "mimeType": "video/mp4; codecs=\"avc1.4d401f\"",
+                "bitrate": 2000000, "width": 1280, "height": 720, "fps": 30,
+                "targetDurationSec": 5.0, "maxDvrDurationSec": 43200
+            },
+            {"itag": 140, "bitrate": 130000},
+            // Known fields with an unexpected type
+            {
+                "itag": 137, "mimeType": "video/mp4; codecs=\"avc1.640028\"",
+                "bitrate": "4000000", "width": "640", "height": 360, "fps": 4294967296u64, "colorInfo": "BT709"
+            },
+            {"itag": "251", "mimeType": "audio/webm; codecs=\"opus\""},
+            "not a format"
+        ]
+    }});
+    let streaming_data =
+        StreamingDataExtractor::extract_streaming_data(&player_response, "test").unwrap();
+
+    // Only itag and mimeType are required
+    assert_eq!(streaming_data.formats.len(), 1);
+    let muxed = &streaming_data.formats[0];
+    assert_eq!(muxed.itag, 18);
+    assert_eq!(muxed.bitrate, None);
+    assert_eq!(muxed.quality, None);
+    assert!(muxed.extra.is_empty());
+
+    assert_eq!(streaming_data.adaptive_formats.len(), 2);
+    let live = &streaming_data.adaptive_formats[0];
+    assert_eq!(live.itag, 136);
+    assert_eq!(live.approx_duration_ms, None);
+    assert_eq!(live.projection_type, None);
+    assert_eq!(live.height, Some(720));
+    // Unknown fields are kept
+    assert_eq!(live.extra.len(), 2);
+    assert_eq!(live.extra["targetDurationSec"], json!(5.0));
+    assert_eq!(live.extra["maxDvrDurationSec"], json!(43200));
+    assert_eq!(streaming_data.best_video().map(|f| f.itag), Some(136));
+
+    // Mistyped known fields are kept in `extra` instead of being dropped
+    let mistyped = &streaming_data.adaptive_formats[1];
+    assert_eq!(mistyped.bitrate, None);
+    assert_eq!(mistyped.width, None);
+    assert_eq!(mistyped.height, Some(360));
+    assert_eq!(mistyped.fps, None);
+    assert!(mistyped.color_info.is_none());
+    assert_eq!(mistyped.extra.len(), 4);
+    assert_eq!(mistyped.extra["colorInfo"], json!("BT709"));
+    assert_eq!(mistyped.extra["bitrate"], json!("4000000"));
+    assert_eq!(mistyped.extra["width"], json!("640"));
+    assert_eq!(mistyped.extra["fps"], json!(4294967296u64));
+
+    let skipped = |adaptive: bool, index: usize, itag: Option<u32>, reason: &str| SkippedFormat {
+        adaptive,
+        index,
+        itag,
+        reason: reason.to_string(),
+    };
+    assert_eq!(
+        streaming_data.skipped_formats,
+        vec![
+            skipped(false, 1, None, "missing itag"),
+            skipped(true, 1, Some(140), "missing mimeType"),
+            skipped(true, 3, None, "invalid itag: \"251\""),
+            skipped(true, 4, None, "not an object"),
+        ]
+    );
+}
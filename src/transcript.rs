@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use reqwest::Client;
 use std::collections::HashMap;
 use std::fmt;
@@ -5,7 +6,8 @@ use std::fmt;
 use crate::errors::{CouldNotRetrieveTranscript, CouldNotRetrieveTranscriptReason};
 use crate::fetched_transcript::FetchedTranscript;
 use crate::innertube_client::InnerTubeClient;
-use crate::models::TranslationLanguage;
+use crate::models::{parse_url_expiry, TranslationLanguage};
+use crate::transcript_deduplicator::TranscriptDeduplicator;
 use crate::transcript_parser::TranscriptParser;
 
 /// # Transcript
@@ -195,6 +197,49 @@ impl Transcript {
         &self,
         client: &Client,
         preserve_formatting: bool,
+    ) -> Result<FetchedTranscript, CouldNotRetrieveTranscript> {
+        self.fetch_with_dedup(client, preserve_formatting, false)
+            .await
+    }
+
+    /// Fetches the transcript, optionally removing rolling caption repeats.
+    ///
+    /// This behaves exactly like [`fetch()`](Self::fetch), but when `deduplicate` is `true`
+    /// the snippets are passed through a [`TranscriptDeduplicator`] before being returned.
+    /// This is mostly useful for auto-generated and live-stream captions, where the end
+    /// of each line is repeated at the beginning of the next one.
+    ///
+    /// # Parameters
+    ///
+    /// * `client` - The HTTP client to use for the request
+    /// * `preserve_formatting` - Whether to preserve HTML formatting in the transcript
+    /// * `deduplicate` - Whether to remove text repeated between consecutive snippets
+    ///
+    /// # Returns
+    ///
+    /// * `Result<FetchedTranscript, CouldNotRetrieveTranscript>` - The fetched transcript or an error
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use reqwest::Client;
+    /// # use yt_transcript_rs::YouTubeTranscriptApi;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new();
+    /// let api = YouTubeTranscriptApi::new(None, None, None)?;
+    /// let transcript_list = api.list_transcripts("dQw4w9WgXcQ").await?;
+    /// let transcript = transcript_list.find_generated_transcript(&["en"])?;
+    ///
+    /// let fetched = transcript.fetch_with_dedup(&client, false, true).await?;
+    /// println!("Transcript: {}", fetched.text());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_with_dedup(
+        &self,
+        client: &Client,
+        preserve_formatting: bool,
+        deduplicate: bool,
     ) -> Result<FetchedTranscript, CouldNotRetrieveTranscript> {
         // Use InnerTube API directly - this is now the only reliable method
         let innertube_client = InnerTubeClient::new(client.clone());
@@ -316,6 +361,12 @@ impl Transcript {
                 )),
             })?;
 
+        let snippets = if deduplicate {
+            TranscriptDeduplicator::new().deduplicate(&snippets)
+        } else {
+            snippets
+        };
+
         Ok(FetchedTranscript {
             snippets,
             video_id: self.video_id.clone(),
@@ -360,6 +411,21 @@ impl Transcript {
         !self.translation_languages.is_empty()
     }
 
+    /// Returns when the URL of the transcript expires, read from its `expire` parameter.
+    ///
+    /// Returns `None` if the URL has no expiry.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        parse_url_expiry(&self.url)
+    }
+
+    /// Returns whether the URL of the transcript has expired.
+    ///
+    /// Returns `false` if the expiry is unknown.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at()
+            .is_some_and(|expires_at| expires_at <= Utc::now())
+    }
+
     /// Creates a translated version of this transcript in the specified language.
     ///
     /// This method creates a new `Transcript` instance representing the same content
@@ -0,0 +1,261 @@
+use crate::models::FetchedTranscriptSnippet;
+
+/// # TranscriptDeduplicator
+///
+/// Removes text repeated between consecutive snippets of rolling captions.
+///
+/// Auto-generated tracks and live-stream captions are often displayed as "rolling"
+/// lines: the end of one caption re-appears at the beginning of the next one while
+/// the new words scroll in. Joined together, those snippets repeat every line twice.
+///
+/// The deduplicator compares each snippet with the previous one, finds the longest
+/// run of words that is both a suffix of the previous snippet and a prefix of the
+/// current one, and removes it from the current snippet. Words are compared
+/// case-insensitively and ignoring surrounding punctuation.
+///
+/// Timings are kept consistent:
+/// - A snippet whose text is entirely repeated is dropped, and the previous snippet
+///   is extended to cover its time span
+/// - When an overlap is removed, the previous snippet is shortened so that it ends
+///   when the de-duplicated snippet starts
+///
+/// ## Usage Example
+///
+/// ```rust
+/// use yt_transcript_rs::models::FetchedTranscriptSnippet;
+/// use yt_transcript_rs::transcript_deduplicator::TranscriptDeduplicator;
+///
+/// let snippets = vec![
+///     FetchedTranscriptSnippet { text: "so today we".to_string(), start: 0.0, duration: 4.0 },
+///     FetchedTranscriptSnippet { text: "so today we are going to".to_string(), start: 2.0, duration: 4.0 },
+///     FetchedTranscriptSnippet { text: "are going to talk about".to_string(), start: 4.0, duration: 4.0 },
+/// ];
+///
+/// let deduplicated = TranscriptDeduplicator::new().deduplicate(&snippets);
+///
+/// assert_eq!(deduplicated[0].text, "so today we");
+/// assert_eq!(deduplicated[1].text, "are going to");
+/// assert_eq!(deduplicated[2].text, "talk about");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TranscriptDeduplicator {
+    /// Minimum number of repeated words for an overlap to be removed
+    min_overlap_words: usize,
+}
+
+impl TranscriptDeduplicator {
+    /// Creates a deduplicator that removes overlaps of at least two words.
+    ///
+    /// Single repeated words are left untouched, since they are frequently genuine
+    /// speech ("very very", "no no"). Snippets that are exact repeats of the previous
+    /// snippet are always removed, whatever their length.
+    pub fn new() -> Self {
+        Self {
+            min_overlap_words: 2,
+        }
+    }
+
+    /// Creates a deduplicator with a custom minimum overlap length.
+    ///
+    /// # Parameters
+    ///
+    /// * `min_overlap_words` - Minimum number of repeated words for an overlap to be removed.
+    ///   Values below 1 are treated as 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use yt_transcript_rs::transcript_deduplicator::TranscriptDeduplicator;
+    /// // Also remove single-word overlaps
+    /// let deduplicator = TranscriptDeduplicator::with_config(1);
+    /// ```
+    pub fn with_config(min_overlap_words: usize) -> Self {
+        Self {
+            min_overlap_words: min_overlap_words.max(1),
+        }
+    }
+
+    /// Removes text repeated between consecutive snippets.
+    ///
+    /// # Parameters
+    ///
+    /// * `snippets` - The snippets to process, sorted by start time
+    ///
+    /// # Returns
+    ///
+    /// A new list of snippets with repeated words removed. Snippets without any
+    /// overlap with their predecessor are returned unchanged.
+    pub fn deduplicate(
+        &self,
+        snippets: &[FetchedTranscriptSnippet],
+    ) -> Vec<FetchedTranscriptSnippet> {
+        let mut result: Vec<FetchedTranscriptSnippet> = Vec::with_capacity(snippets.len());
+
+        // Words of the previous *original* snippet, since rolling lines repeat the
+        // text as displayed, not as de-duplicated
+        let mut previous_words: Vec<&str> = Vec::new();
+
+        for snippet in snippets {
+            let words: Vec<&str> = snippet.text.split_whitespace().collect();
+            if words.is_empty() {
+                result.push(snippet.clone());
+                continue;
+            }
+
+            let overlap = self.overlap_len(&previous_words, &words);
+
+            if overlap == 0 {
+                result.push(snippet.clone());
+            } else if overlap == words.len() {
+                // The whole snippet is a repeat: extend the last kept snippet instead
+                if let Some(last) = result.last_mut() {
+                    let end = (last.start + last.duration).max(snippet.start + snippet.duration);
+                    last.duration = end - last.start;
+                }
+            } else {
+                if let Some(last) = result.last_mut() {
+                    if snippet.start > last.start && last.start + last.duration > snippet.start {
+                        last.duration = snippet.start - last.start;
+                    }
+                }
+
+                result.push(FetchedTranscriptSnippet {
+                    text: words[overlap..].join(" "),
+                    start: snippet.start,
+                    duration: snippet.duration,
+                });
+            }
+
+            previous_words = words;
+        }
+
+        result
+    }
+
+    /// Returns the number of words at the start of `current` repeating the end of `previous`.
+    fn overlap_len(&self, previous: &[&str], current: &[&str]) -> usize {
+        let max_overlap = previous.len().min(current.len());
+
+        for len in (1..=max_overlap).rev() {
+            let suffix = &previous[previous.len() - len..];
+            let prefix = &current[..len];
+
+            let is_match = suffix
+                .iter()
+                .zip(prefix)
+                .all(|(a, b)| Self::normalize(a) == Self::normalize(b));
+
+            if is_match {
+                let exact_repeat = len == previous.len() && len == current.len();
+                return if len >= self.min_overlap_words || exact_repeat {
+                    len
+                } else {
+                    0
+                };
+            }
+        }
+
+        0
+    }
+
+    /// Normalizes a word for comparison by lowercasing it and trimming punctuation.
+    fn normalize(word: &str) -> String {
+        word.trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase()
+    }
+}
+
+impl Default for TranscriptDeduplicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snippet(text: &str, start: f64, duration: f64) -> FetchedTranscriptSnippet {
+        FetchedTranscriptSnippet {
+            text: text.to_string(),
+            start,
+            duration,
+        }
+    }
+
+    #[test]
+    fn test_rolling_captions() {
+        let snippets = vec![
+            snippet("welcome back to the", 0.0, 4.0),
+            snippet("welcome back to the channel today", 2.0, 4.0),
+            snippet("channel today we talk about", 4.0, 4.0),
+            snippet("we talk about gradient descent", 6.0, 4.0),
+        ];
+
+        let result = TranscriptDeduplicator::new().deduplicate(&snippets);
+
+        let texts: Vec<&str> = result.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "welcome back to the",
+                "channel today",
+                "we talk about",
+                "gradient descent"
+            ]
+        );
+
+        // Previous snippets end when the next one starts
+        assert_eq!(result[0].duration, 2.0);
+        assert_eq!(result[1].duration, 2.0);
+        assert_eq!(result[2].duration, 2.0);
+        assert_eq!(result[3].duration, 4.0);
+    }
+
+    #[test]
+    fn test_exact_repeat_extends_previous() {
+        let snippets = vec![
+            snippet("Hello.", 0.0, 2.0),
+            snippet("hello", 1.0, 3.0),
+            snippet("How are you?", 4.0, 2.0),
+        ];
+
+        let result = TranscriptDeduplicator::new().deduplicate(&snippets);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].text, "Hello.");
+        assert_eq!(result[0].duration, 4.0);
+        assert_eq!(result[1].text, "How are you?");
+    }
+
+    #[test]
+    fn test_min_overlap_words() {
+        let snippets = vec![
+            snippet("it was very", 0.0, 2.0),
+            snippet("very good", 2.0, 2.0),
+        ];
+
+        // Single-word overlaps are kept by default
+        let result = TranscriptDeduplicator::new().deduplicate(&snippets);
+        assert_eq!(result[1].text, "very good");
+
+        let result = TranscriptDeduplicator::with_config(1).deduplicate(&snippets);
+        assert_eq!(result[1].text, "good");
+    }
+
+    #[test]
+    fn test_no_overlap_is_unchanged() {
+        let snippets = vec![
+            snippet("first line", 0.0, 2.0),
+            snippet("", 2.0, 0.5),
+            snippet("second line", 2.5, 2.0),
+        ];
+
+        let result = TranscriptDeduplicator::new().deduplicate(&snippets);
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].text, "first line");
+        assert_eq!(result[0].duration, 2.0);
+        assert_eq!(result[2].text, "second line");
+    }
+}
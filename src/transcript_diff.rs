@@ -0,0 +1,533 @@
+/// Transcript diffing.
+///
+/// This module compares two `FetchedTranscript`s, for example two archived versions
+/// of the same track (to detect corrected re-uploads) or the manual and
+/// auto-generated tracks of a video.
+///
+/// Snippets are matched by text using a longest-common-subsequence diff. Unmatched
+/// snippets that overlap in time are reported as changed, with a word-level diff of
+/// their text; the remaining ones are reported as inserted or deleted.
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::fetched_transcript::FetchedTranscript;
+use crate::models::FetchedTranscriptSnippet;
+
+/// A single word-level difference inside a changed snippet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "text", rename_all = "snake_case")]
+pub enum WordChange {
+    /// Words present in both versions
+    Equal(String),
+
+    /// Words only present in the new version
+    Inserted(String),
+
+    /// Words only present in the old version
+    Deleted(String),
+}
+
+/// A difference between two transcripts at the snippet level.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SnippetChange {
+    /// A snippet only present in the new transcript
+    Inserted {
+        /// Index of the snippet in the new transcript
+        new_index: usize,
+        /// The inserted snippet
+        snippet: FetchedTranscriptSnippet,
+    },
+
+    /// A snippet only present in the old transcript
+    Deleted {
+        /// Index of the snippet in the old transcript
+        old_index: usize,
+        /// The deleted snippet
+        snippet: FetchedTranscriptSnippet,
+    },
+
+    /// A snippet whose text differs between the two transcripts
+    Changed {
+        /// Index of the snippet in the old transcript
+        old_index: usize,
+        /// Index of the snippet in the new transcript
+        new_index: usize,
+        /// The snippet in the old transcript
+        old: FetchedTranscriptSnippet,
+        /// The snippet in the new transcript
+        new: FetchedTranscriptSnippet,
+        /// Word-level differences between the old and new text
+        words: Vec<WordChange>,
+    },
+}
+
+impl SnippetChange {
+    /// Returns the start time of the change, in seconds.
+    ///
+    /// For changed snippets this is the start time in the new transcript.
+    pub fn start(&self) -> f64 {
+        match self {
+            SnippetChange::Inserted { snippet, .. } | SnippetChange::Deleted { snippet, .. } => {
+                snippet.start
+            }
+            SnippetChange::Changed { new, .. } => new.start,
+        }
+    }
+}
+
+/// The differences between two transcripts.
+///
+/// The `Display` implementation renders a human-readable report, one line per change:
+///
+/// ```text
+/// - [12.50s] deleted snippet text
+/// + [15.00s] inserted snippet text
+/// ~ [17.20s -> 17.30s] a [-colour-] {+color+} correction
+/// ```
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use yt_transcript_rs::YouTubeTranscriptApi;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let api = YouTubeTranscriptApi::new(None, None, None)?;
+/// let transcript_list = api.list_transcripts("dQw4w9WgXcQ").await?;
+///
+/// let client = reqwest::Client::new();
+/// let manual = transcript_list.find_manually_created_transcript(&["en"])?;
+/// let generated = transcript_list.find_generated_transcript(&["en"])?;
+/// let manual = manual.fetch(&client, false).await?;
+/// let generated = generated.fetch(&client, false).await?;
+///
+/// let diff = generated.diff(&manual);
+/// println!("{} differences", diff.changes.len());
+/// println!("{}", diff);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptDiff {
+    /// All snippet-level changes, in transcript order
+    pub changes: Vec<SnippetChange>,
+}
+
+impl TranscriptDiff {
+    /// Computes the differences between an old and a new transcript.
+    ///
+    /// Snippet texts are compared after collapsing whitespace, so formatting-only
+    /// changes such as line breaks are ignored. Timing changes of snippets whose
+    /// text is unchanged are not reported.
+    ///
+    /// # Parameters
+    ///
+    /// * `old` - The reference transcript
+    /// * `new` - The transcript compared against the reference
+    pub fn between(old: &FetchedTranscript, new: &FetchedTranscript) -> Self {
+        let old_texts: Vec<String> = old.snippets.iter().map(|s| normalize(&s.text)).collect();
+        let new_texts: Vec<String> = new.snippets.iter().map(|s| normalize(&s.text)).collect();
+
+        let mut changes = Vec::new();
+        let mut deleted: Vec<usize> = Vec::new();
+        let mut inserted: Vec<usize> = Vec::new();
+
+        for op in diff_sequences(&old_texts, &new_texts) {
+            match op {
+                DiffOp::Equal(..) => {
+                    Self::flush_hunk(old, new, &mut deleted, &mut inserted, &mut changes);
+                }
+                DiffOp::Delete(i) => deleted.push(i),
+                DiffOp::Insert(j) => inserted.push(j),
+            }
+        }
+        Self::flush_hunk(old, new, &mut deleted, &mut inserted, &mut changes);
+
+        Self { changes }
+    }
+
+    /// Returns `true` if the two transcripts have the same text.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Serializes the diff to a pretty-printed JSON document.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Turns a run of deleted and inserted snippets into changes.
+    ///
+    /// Deleted and inserted snippets overlapping in time are paired as changed
+    /// snippets; the others are emitted in time order as deletions or insertions.
+    fn flush_hunk(
+        old: &FetchedTranscript,
+        new: &FetchedTranscript,
+        deleted: &mut Vec<usize>,
+        inserted: &mut Vec<usize>,
+        changes: &mut Vec<SnippetChange>,
+    ) {
+        let (mut d, mut i) = (0, 0);
+
+        while d < deleted.len() || i < inserted.len() {
+            let old_snippet = deleted.get(d).map(|&index| &old.snippets[index]);
+            let new_snippet = inserted.get(i).map(|&index| &new.snippets[index]);
+
+            match (old_snippet, new_snippet) {
+                (Some(o), Some(n)) if overlaps(o, n) => {
+                    changes.push(SnippetChange::Changed {
+                        old_index: deleted[d],
+                        new_index: inserted[i],
+                        old: o.clone(),
+                        new: n.clone(),
+                        words: diff_words(&o.text, &n.text),
+                    });
+                    d += 1;
+                    i += 1;
+                }
+                (Some(o), Some(n)) if n.start < o.start => {
+                    changes.push(SnippetChange::Inserted {
+                        new_index: inserted[i],
+                        snippet: n.clone(),
+                    });
+                    i += 1;
+                }
+                (Some(o), _) => {
+                    changes.push(SnippetChange::Deleted {
+                        old_index: deleted[d],
+                        snippet: o.clone(),
+                    });
+                    d += 1;
+                }
+                (None, Some(n)) => {
+                    changes.push(SnippetChange::Inserted {
+                        new_index: inserted[i],
+                        snippet: n.clone(),
+                    });
+                    i += 1;
+                }
+                (None, None) => unreachable!(),
+            }
+        }
+
+        deleted.clear();
+        inserted.clear();
+    }
+}
+
+impl fmt::Display for TranscriptDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            match change {
+                SnippetChange::Deleted { snippet, .. } => {
+                    writeln!(f, "- [{:.2}s] {}", snippet.start, normalize(&snippet.text))?;
+                }
+                SnippetChange::Inserted { snippet, .. } => {
+                    writeln!(f, "+ [{:.2}s] {}", snippet.start, normalize(&snippet.text))?;
+                }
+                SnippetChange::Changed {
+                    old, new, words, ..
+                } => {
+                    let text = words
+                        .iter()
+                        .map(|word| match word {
+                            WordChange::Equal(text) => text.clone(),
+                            WordChange::Inserted(text) => format!("{{+{}+}}", text),
+                            WordChange::Deleted(text) => format!("[-{}-]", text),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    writeln!(f, "~ [{:.2}s -> {:.2}s] {}", old.start, new.start, text)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An operation of a sequence diff, with indices into the old and new sequences.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DiffOp {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Computes a longest-common-subsequence diff between two sequences.
+///
+/// Uses Hirschberg's algorithm, so memory stays linear in the length of the
+/// sequences even for transcripts with tens of thousands of lines.
+fn diff_sequences<T: PartialEq>(old: &[T], new: &[T]) -> Vec<DiffOp> {
+    // Common prefix and suffix are trimmed to keep the search space small
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let mut ops: Vec<DiffOp> = (0..prefix).map(|k| DiffOp::Equal(k, k)).collect();
+    hirschberg(old_mid, new_mid, prefix, prefix, &mut ops);
+    ops.extend(
+        (0..suffix).map(|k| DiffOp::Equal(prefix + old_mid.len() + k, prefix + new_mid.len() + k)),
+    );
+    ops
+}
+
+/// Appends the diff of `old` and `new` to `ops`, offsetting indices by `old_offset`
+/// and `new_offset`.
+fn hirschberg<T: PartialEq>(
+    old: &[T],
+    new: &[T],
+    old_offset: usize,
+    new_offset: usize,
+    ops: &mut Vec<DiffOp>,
+) {
+    if old.is_empty() {
+        ops.extend((0..new.len()).map(|j| DiffOp::Insert(new_offset + j)));
+        return;
+    }
+    if new.is_empty() {
+        ops.extend((0..old.len()).map(|i| DiffOp::Delete(old_offset + i)));
+        return;
+    }
+
+    if old.len() == 1 {
+        match new.iter().position(|item| *item == old[0]) {
+            Some(j) => {
+                ops.extend((0..j).map(|k| DiffOp::Insert(new_offset + k)));
+                ops.push(DiffOp::Equal(old_offset, new_offset + j));
+                ops.extend((j + 1..new.len()).map(|k| DiffOp::Insert(new_offset + k)));
+            }
+            None => {
+                ops.push(DiffOp::Delete(old_offset));
+                ops.extend((0..new.len()).map(|k| DiffOp::Insert(new_offset + k)));
+            }
+        }
+        return;
+    }
+
+    // Split `old` in half and find where the optimal path crosses the middle
+    let mid = old.len() / 2;
+    let forward = lcs_row(old[..mid].iter(), new.iter());
+    let backward = lcs_row(old[mid..].iter().rev(), new.iter().rev());
+    let split = (0..=new.len())
+        .max_by_key(|&k| (forward[k] + backward[new.len() - k], std::cmp::Reverse(k)))
+        .unwrap_or(0);
+
+    hirschberg(&old[..mid], &new[..split], old_offset, new_offset, ops);
+    hirschberg(
+        &old[mid..],
+        &new[split..],
+        old_offset + mid,
+        new_offset + split,
+        ops,
+    );
+}
+
+/// Returns the LCS lengths of `old` with every prefix of `new`, using a single row.
+fn lcs_row<'a, T: PartialEq + 'a>(
+    old: impl Iterator<Item = &'a T>,
+    new: impl Iterator<Item = &'a T> + Clone,
+) -> Vec<usize> {
+    let mut row = vec![0; new.clone().count() + 1];
+    for a in old {
+        // `diagonal` holds the previous row's value at `j - 1`
+        let mut diagonal = 0;
+        for (j, b) in new.clone().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if a == b {
+                diagonal + 1
+            } else {
+                above.max(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row
+}
+
+/// Computes a word-level diff between two texts, merging consecutive words of the same kind.
+fn diff_words(old: &str, new: &str) -> Vec<WordChange> {
+    let old_words: Vec<&str> = old.split_whitespace().collect();
+    let new_words: Vec<&str> = new.split_whitespace().collect();
+
+    let mut changes: Vec<WordChange> = Vec::new();
+    for op in diff_sequences(&old_words, &new_words) {
+        let change = match op {
+            DiffOp::Equal(i, _) => WordChange::Equal(old_words[i].to_string()),
+            DiffOp::Delete(i) => WordChange::Deleted(old_words[i].to_string()),
+            DiffOp::Insert(j) => WordChange::Inserted(new_words[j].to_string()),
+        };
+
+        let merged = match (changes.last_mut(), &change) {
+            (Some(WordChange::Equal(text)), WordChange::Equal(word))
+            | (Some(WordChange::Inserted(text)), WordChange::Inserted(word))
+            | (Some(WordChange::Deleted(text)), WordChange::Deleted(word)) => {
+                text.push(' ');
+                text.push_str(word);
+                true
+            }
+            _ => false,
+        };
+
+        if !merged {
+            changes.push(change);
+        }
+    }
+
+    changes
+}
+
+/// Returns `true` if two snippets are on screen at the same time.
+fn overlaps(a: &FetchedTranscriptSnippet, b: &FetchedTranscriptSnippet) -> bool {
+    let start = a.start.max(b.start);
+    let end = (a.start + a.duration).min(b.start + b.duration);
+    end > start || a.start == b.start
+}
+
+/// Collapses all whitespace runs into single spaces.
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_transcript(texts: &[(&str, f64, f64)]) -> FetchedTranscript {
+        FetchedTranscript {
+            snippets: texts
+                .iter()
+                .map(|(text, start, duration)| FetchedTranscriptSnippet {
+                    text: text.to_string(),
+                    start: *start,
+                    duration: *duration,
+                })
+                .collect(),
+            video_id: "test123".to_string(),
+            language: "English".to_string(),
+            language_code: "en".to_string(),
+            is_generated: false,
+        }
+    }
+
+    #[test]
+    fn test_identical_transcripts() {
+        let old = create_transcript(&[("Hello world", 0.0, 2.0), ("Bye", 2.0, 1.0)]);
+        let new = create_transcript(&[("Hello\nworld", 0.0, 2.0), ("Bye", 2.5, 1.0)]);
+
+        let diff = old.diff(&new);
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "");
+    }
+
+    #[test]
+    fn test_inserted_deleted_and_changed() {
+        let old = create_transcript(&[
+            ("Hello world", 0.0, 2.0),
+            ("the colour of money", 2.0, 2.0),
+            ("removed line", 4.0, 2.0),
+            ("Bye", 6.0, 1.0),
+        ]);
+        let new = create_transcript(&[
+            ("Hello world", 0.0, 2.0),
+            ("the color of money", 2.0, 2.0),
+            ("Bye", 6.0, 1.0),
+            ("added line", 7.0, 1.0),
+        ]);
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.changes.len(), 3);
+
+        match &diff.changes[0] {
+            SnippetChange::Changed {
+                old_index,
+                new_index,
+                words,
+                ..
+            } => {
+                assert_eq!((*old_index, *new_index), (1, 1));
+                assert_eq!(
+                    words,
+                    &vec![
+                        WordChange::Equal("the".to_string()),
+                        WordChange::Deleted("colour".to_string()),
+                        WordChange::Inserted("color".to_string()),
+                        WordChange::Equal("of money".to_string()),
+                    ]
+                );
+            }
+            other => panic!("expected a changed snippet, got {:?}", other),
+        }
+        assert!(matches!(
+            diff.changes[1],
+            SnippetChange::Deleted { old_index: 2, .. }
+        ));
+        assert!(matches!(
+            diff.changes[2],
+            SnippetChange::Inserted { new_index: 3, .. }
+        ));
+        assert_eq!(diff.changes[2].start(), 7.0);
+
+        assert_eq!(
+            diff.to_string(),
+            "~ [2.00s -> 2.00s] the [-colour-] {+color+} of money\n\
+             - [4.00s] removed line\n\
+             + [7.00s] added line\n"
+        );
+    }
+
+    #[test]
+    fn test_diff_sequences() {
+        let old: Vec<char> = "ABCABBA".chars().collect();
+        let new: Vec<char> = "CBABAC".chars().collect();
+        let ops = diff_sequences(&old, &new);
+
+        // The LCS of both sequences has 4 items
+        let equal = ops
+            .iter()
+            .filter(|op| matches!(op, DiffOp::Equal(..)))
+            .count();
+        assert_eq!(equal, 4);
+        assert_eq!(ops.len(), old.len() + new.len() - equal);
+
+        // Applying the operations to `old` gives `new`
+        let rebuilt: Vec<char> = ops
+            .iter()
+            .filter_map(|op| match op {
+                DiffOp::Equal(i, j) => {
+                    assert_eq!(old[*i], new[*j]);
+                    Some(old[*i])
+                }
+                DiffOp::Insert(j) => Some(new[*j]),
+                DiffOp::Delete(_) => None,
+            })
+            .collect();
+        assert_eq!(rebuilt, new);
+
+        // Long sequences without a common prefix or suffix
+        let old: Vec<usize> = (0..2_000).collect();
+        let new: Vec<usize> = (0..2_000).map(|k| k * 3 % 2_001).collect();
+        let ops = diff_sequences(&old, &new);
+        assert!(ops.len() >= old.len());
+    }
+
+    #[test]
+    fn test_json_output() {
+        let old = create_transcript(&[("a b", 0.0, 1.0)]);
+        let new = create_transcript(&[("a c", 0.0, 1.0)]);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&old.diff(&new).to_json().unwrap()).unwrap();
+        assert_eq!(json["changes"][0]["type"], "changed");
+        assert_eq!(json["changes"][0]["words"][0]["type"], "equal");
+        assert_eq!(json["changes"][0]["words"][0]["text"], "a");
+        assert_eq!(json["changes"][0]["words"][1]["type"], "deleted");
+        assert_eq!(json["changes"][0]["new"]["text"], "a c");
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
@@ -411,6 +412,13 @@ impl TranscriptList {
     pub fn transcripts(&self) -> impl Iterator<Item = &Transcript> {
         self.into_iter()
     }
+
+    /// Returns when the first transcript URL expires.
+    ///
+    /// Returns `None` if no transcript URL has an expiry.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.transcripts().filter_map(Transcript::expires_at).min()
+    }
 }
 
 impl fmt::Display for TranscriptList {
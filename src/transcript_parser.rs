@@ -8,12 +8,14 @@ use scraper::{Html, Selector};
 use serde::{Deserialize, Serialize};
 use std::io::Cursor;
 
+#[allow(dead_code)]
 #[derive(Debug, Serialize, Deserialize)]
 struct Transcript {
     #[serde(rename = "text")]
     texts: Vec<Text>,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Serialize, Deserialize)]
 struct Text {
     #[serde(rename = "@start")]
@@ -253,19 +255,15 @@ impl TranscriptParser {
                         content.push_str(&tag_with_attrs);
                     }
                 }
-                Ok(Event::Text(e)) => {
-                    if in_text {
-                        // Handle XML entities by using unescape
-                        match e.unescape() {
-                            Ok(text) => content.push_str(&text),
-                            Err(_) => content.push_str(&String::from_utf8_lossy(e.as_ref())),
-                        }
+                Ok(Event::Text(e)) if in_text => {
+                    // Handle XML entities by using unescape
+                    match e.unescape() {
+                        Ok(text) => content.push_str(&text),
+                        Err(_) => content.push_str(&String::from_utf8_lossy(e.as_ref())),
                     }
                 }
-                Ok(Event::CData(e)) => {
-                    if in_text {
-                        content.push_str(&String::from_utf8_lossy(e.as_ref()));
-                    }
+                Ok(Event::CData(e)) if in_text => {
+                    content.push_str(&String::from_utf8_lossy(e.as_ref()));
                 }
                 Ok(Event::End(e)) => {
                     let tag_name = String::from_utf8_lossy(e.name().as_ref()).to_string();
@@ -1,11 +1,27 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::Utc;
 use reqwest::Client;
 
 use crate::captions_extractor::CaptionsExtractor;
+use crate::chapters_extractor::ChaptersExtractor;
 use crate::errors::CouldNotRetrieveTranscript;
+use crate::heatmap_extractor::HeatmapExtractor;
+use crate::hls_playlist_fetcher::HlsPlaylistFetcher;
+use crate::initial_data_extractor::InitialDataExtractor;
 use crate::js_var_parser::JsVarParser;
+use crate::links_extractor::LinksExtractor;
+use crate::live_details_extractor::LiveDetailsExtractor;
 use crate::microformat_extractor::MicroformatExtractor;
-use crate::models::{MicroformatData, StreamingData, VideoDetails, VideoInfos};
+use crate::models::{
+    Chapter, HlsMasterPlaylist, LiveDetails, MicroformatData, Storyboard, StreamingData,
+    StreamingFormat, VideoDetails, VideoInfos, VideoLinks,
+};
 use crate::playability_asserter::PlayabilityAsserter;
+use crate::player_js_fetcher::PlayerJsFetcher;
+use crate::signature_decipher::SignatureDecipher;
+use crate::storyboard_extractor::StoryboardExtractor;
 use crate::streaming_data_extractor::StreamingDataExtractor;
 use crate::transcript_list::TranscriptList;
 use crate::video_details_extractor::VideoDetailsExtractor;
@@ -35,6 +51,8 @@ pub struct VideoDataFetcher {
     pub client: Client,
     /// Specialized fetcher for YouTube pages
     page_fetcher: YoutubePageFetcher,
+    /// Signature deciphers by player JavaScript URL, so each player is only downloaded once
+    signature_deciphers: Mutex<HashMap<String, SignatureDecipher>>,
 }
 
 impl VideoDataFetcher {
@@ -70,6 +88,7 @@ impl VideoDataFetcher {
         Self {
             client,
             page_fetcher,
+            signature_deciphers: Mutex::new(HashMap::new()),
         }
     }
 
@@ -235,6 +254,9 @@ impl VideoDataFetcher {
     /// - Both combined formats (with audio and video) and separate adaptive formats
     /// - Information about format expiration
     ///
+    /// The URLs of protected formats are deciphered with the player JavaScript, which
+    /// costs one more request the first time a player version is seen.
+    ///
     /// # Parameters
     ///
     /// * `video_id` - The YouTube video ID
@@ -280,11 +302,169 @@ impl VideoDataFetcher {
         &self,
         video_id: &str,
     ) -> Result<StreamingData, CouldNotRetrieveTranscript> {
-        // Get player response with playability check
-        let player_response = self.fetch_player_response(video_id, true).await?;
+        // `expires_in_seconds` counts from the request
+        let fetched_at = Utc::now();
+        let html = self.page_fetcher.fetch_video_page(video_id).await?;
+        let player_response = self.extract_yt_initial_player_response(&html, video_id)?;
+        PlayabilityAsserter::assert_playability(&player_response, video_id)?;
 
         // Extract streaming data from player response
-        StreamingDataExtractor::extract_streaming_data(&player_response, video_id)
+        let mut streaming_data =
+            StreamingDataExtractor::extract_streaming_data(&player_response, video_id)?;
+        streaming_data.fetched_at = Some(fetched_at);
+        self.decipher_formats(&html, &mut streaming_data, video_id)
+            .await;
+
+        Ok(streaming_data)
+    }
+
+    /// Fetches the storyboard (preview frame sprite sheets) of a YouTube video.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<Storyboard>, CouldNotRetrieveTranscript>` - The storyboard, `None` if the video has none, or an error
+    ///
+    /// # Errors
+    ///
+    /// This method can fail if:
+    /// - The video page cannot be fetched
+    /// - The video is not playable
+    /// - The player response cannot be parsed
+    pub async fn fetch_storyboard(
+        &self,
+        video_id: &str,
+    ) -> Result<Option<Storyboard>, CouldNotRetrieveTranscript> {
+        let player_response = self.fetch_player_response(video_id, true).await?;
+
+        Ok(StoryboardExtractor::extract_storyboard(&player_response))
+    }
+
+    /// Fetches the HLS master playlist of a live stream.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<HlsMasterPlaylist>, CouldNotRetrieveTranscript>` - The playlist, `None` if the video has no HLS manifest, or an error
+    ///
+    /// # Errors
+    ///
+    /// This method can fail if:
+    /// - The video page cannot be fetched
+    /// - The video is not playable
+    /// - The playlist cannot be fetched or parsed
+    pub async fn fetch_hls_playlist(
+        &self,
+        video_id: &str,
+    ) -> Result<Option<HlsMasterPlaylist>, CouldNotRetrieveTranscript> {
+        let player_response = self.fetch_player_response(video_id, true).await?;
+        let streaming_data =
+            StreamingDataExtractor::extract_streaming_data(&player_response, video_id)?;
+
+        let Some(manifest_url) = streaming_data.hls_manifest_url else {
+            return Ok(None);
+        };
+        HlsPlaylistFetcher::new(self.client.clone())
+            .fetch_master_playlist(&manifest_url, video_id)
+            .await
+            .map(Some)
+    }
+
+    /// Fetches the endscreen elements, cards and description links of a YouTube video.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID
+    ///
+    /// # Returns
+    ///
+    /// * `Result<VideoLinks, CouldNotRetrieveTranscript>` - The links on success, or an error
+    ///
+    /// # Errors
+    ///
+    /// This method can fail if:
+    /// - The video page cannot be fetched
+    /// - The video is not playable
+    /// - The player response cannot be parsed
+    pub async fn fetch_video_links(
+        &self,
+        video_id: &str,
+    ) -> Result<VideoLinks, CouldNotRetrieveTranscript> {
+        let player_response = self.fetch_player_response(video_id, true).await?;
+
+        Ok(LinksExtractor::extract_links(&player_response))
+    }
+
+    /// Fetches the live stream and premiere details of a YouTube video.
+    ///
+    /// Unlike the other fetch methods this does not check playability, so it also
+    /// works for upcoming live streams and premieres, which YouTube reports as not
+    /// playable until they start.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID
+    ///
+    /// # Returns
+    ///
+    /// * `Result<LiveDetails, CouldNotRetrieveTranscript>` - The live details on success, or an error
+    ///
+    /// # Errors
+    ///
+    /// This method can fail if:
+    /// - The video page cannot be fetched
+    /// - The player response cannot be parsed
+    pub async fn fetch_live_details(
+        &self,
+        video_id: &str,
+    ) -> Result<LiveDetails, CouldNotRetrieveTranscript> {
+        let player_response = self.fetch_player_response(video_id, false).await?;
+
+        Ok(LiveDetailsExtractor::extract_live_details(&player_response))
+    }
+
+    /// Fetches the chapters of a YouTube video.
+    ///
+    /// Chapters are taken from the player bar markers of the page's `ytInitialData`
+    /// when available, and otherwise parsed from timestamp lines in the video description.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<Chapter>, CouldNotRetrieveTranscript>` - The chapters (empty if the video has none), or an error
+    ///
+    /// # Errors
+    ///
+    /// This method can fail if:
+    /// - The video doesn't exist or is private
+    /// - The player response cannot be parsed
+    /// - Network errors occur during the request
+    pub async fn fetch_chapters(
+        &self,
+        video_id: &str,
+    ) -> Result<Vec<Chapter>, CouldNotRetrieveTranscript> {
+        let html = self.page_fetcher.fetch_video_page(video_id).await?;
+        let player_response = self.extract_yt_initial_player_response(&html, video_id)?;
+        PlayabilityAsserter::assert_playability(&player_response, video_id)?;
+
+        let video_details =
+            VideoDetailsExtractor::extract_video_details(&player_response, video_id)?;
+        let initial_data = self.extract_yt_initial_data(&html, video_id).ok();
+
+        Ok(ChaptersExtractor::extract_chapters(
+            initial_data.as_ref(),
+            &video_details.short_description,
+            video_details.length_seconds as f64,
+        ))
     }
 
     /// Fetches all available information about a YouTube video in a single request.
@@ -294,6 +474,11 @@ impl VideoDataFetcher {
     /// - Microformat data (category, available countries, etc.)
     /// - Streaming data (available formats, qualities, etc.)
     /// - Transcript list (available caption languages)
+    /// - Watch page data from `ytInitialData` (likes, channel badges, related videos),
+    ///   when it can be parsed
+    /// - Chapters
+    /// - Live stream and premiere details
+    /// - Endscreen elements, cards and description links
     ///
     /// This is more efficient than calling the individual fetch methods separately
     /// when multiple types of information are needed, as it avoids multiple HTTP requests.
@@ -334,28 +519,114 @@ impl VideoDataFetcher {
         &self,
         video_id: &str,
     ) -> Result<VideoInfos, CouldNotRetrieveTranscript> {
-        // Get player response with playability check (single network request)
-        let player_response = self.fetch_player_response(video_id, true).await?;
+        self.fetch_video_infos_with_raw(video_id)
+            .await
+            .map(|(infos, _)| infos)
+    }
+
+    /// Fetches all available information about a video, along with the raw player
+    /// response it was extracted from.
+    ///
+    /// The player response holds data that `VideoInfos` does not model, such as new
+    /// fields added by YouTube, without fetching the page a second time.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(VideoInfos, serde_json::Value), CouldNotRetrieveTranscript>` - The video information and the `ytInitialPlayerResponse` JSON, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`fetch_video_infos`](VideoDataFetcher::fetch_video_infos).
+    pub async fn fetch_video_infos_with_raw(
+        &self,
+        video_id: &str,
+    ) -> Result<(VideoInfos, serde_json::Value), CouldNotRetrieveTranscript> {
+        // Fetch the page once and parse both page variables (single network request)
+        // `expires_in_seconds` counts from the request
+        let fetched_at = Utc::now();
+        let html = self.page_fetcher.fetch_video_page(video_id).await?;
+        let player_response = self.extract_yt_initial_player_response(&html, video_id)?;
+        PlayabilityAsserter::assert_playability(&player_response, video_id)?;
 
         // Extract all data in parallel using the various extractors
         let video_details =
             VideoDetailsExtractor::extract_video_details(&player_response, video_id)?;
         let microformat =
             MicroformatExtractor::extract_microformat_data(&player_response, video_id)?;
-        let streaming_data =
+        let mut streaming_data =
             StreamingDataExtractor::extract_streaming_data(&player_response, video_id)?;
+        streaming_data.fetched_at = Some(fetched_at);
+        self.decipher_formats(&html, &mut streaming_data, video_id)
+            .await;
 
         // Extract captions data and build transcript list
         let captions_data = CaptionsExtractor::extract_captions_data(&player_response, video_id)?;
         let transcript_list = TranscriptList::build(video_id.to_string(), &captions_data)?;
 
+        // The page data is optional: a missing or changed ytInitialData must not
+        // prevent returning the player response data
+        let initial_data_json = self.extract_yt_initial_data(&html, video_id).ok();
+        let initial_data = initial_data_json
+            .as_ref()
+            .and_then(|data| InitialDataExtractor::extract_initial_data(data, video_id).ok());
+
+        let live_details = LiveDetailsExtractor::extract_live_details(&player_response);
+        let heatmap = initial_data_json
+            .as_ref()
+            .and_then(HeatmapExtractor::extract_heatmap);
+        let storyboard = StoryboardExtractor::extract_storyboard(&player_response);
+        let links = LinksExtractor::extract_links(&player_response);
+
+        let chapters = ChaptersExtractor::extract_chapters(
+            initial_data_json.as_ref(),
+            &video_details.short_description,
+            video_details.length_seconds as f64,
+        );
+
         // Combine all data into the VideoInfos struct
-        Ok(VideoInfos {
+        let infos = VideoInfos {
             video_details,
             microformat,
             streaming_data,
             transcript_list,
-        })
+            initial_data,
+            chapters,
+            live_details,
+            heatmap,
+            storyboard,
+            links,
+        };
+
+        Ok((infos, player_response))
+    }
+
+    /// Fetches the raw player response of a video (`ytInitialPlayerResponse`).
+    ///
+    /// The playability of the video is not checked, so the `playabilityStatus` of
+    /// unplayable videos can be inspected.
+    ///
+    /// # Parameters
+    ///
+    /// * `video_id` - The YouTube video ID
+    ///
+    /// # Returns
+    ///
+    /// * `Result<serde_json::Value, CouldNotRetrieveTranscript>` - The player response JSON or an error
+    ///
+    /// # Errors
+    ///
+    /// This method can fail if:
+    /// - The video page cannot be fetched
+    /// - The page does not contain a player response
+    pub async fn fetch_raw_player_response(
+        &self,
+        video_id: &str,
+    ) -> Result<serde_json::Value, CouldNotRetrieveTranscript> {
+        self.fetch_player_response(video_id, false).await
     }
 
     /// Extracts the ytInitialPlayerResponse JavaScript variable from YouTube's HTML.
@@ -381,6 +652,82 @@ impl VideoDataFetcher {
         Ok(player_response)
     }
 
+    /// Extracts the ytInitialData JavaScript variable from YouTube's HTML.
+    ///
+    /// This variable describes the watch page layout (like button, channel owner,
+    /// related videos, engagement panels).
+    ///
+    /// # Parameters
+    ///
+    /// * `html` - The HTML content of the YouTube video page
+    /// * `video_id` - The YouTube video ID (used for error reporting)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<serde_json::Value, CouldNotRetrieveTranscript>` - The parsed JavaScript object or an error
+    fn extract_yt_initial_data(
+        &self,
+        html: &str,
+        video_id: &str,
+    ) -> Result<serde_json::Value, CouldNotRetrieveTranscript> {
+        JsVarParser::new("ytInitialData").parse(html, video_id)
+    }
+
+    /// Sets the URLs of the protected formats from their deciphered signatures.
+    ///
+    /// Deciphering is best effort: if the player JavaScript referenced by the page
+    /// cannot be downloaded or parsed, protected formats keep `url: None` and their
+    /// `signature_cipher`.
+    ///
+    /// # Parameters
+    ///
+    /// * `html` - The HTML of the watch page, which references the player
+    /// * `streaming_data` - The streaming data to update
+    /// * `video_id` - The YouTube video ID (used for error reporting)
+    async fn decipher_formats(
+        &self,
+        html: &str,
+        streaming_data: &mut StreamingData,
+        video_id: &str,
+    ) {
+        let is_protected =
+            |format: &&StreamingFormat| format.url.is_none() && format.signature_cipher.is_some();
+        if !streaming_data
+            .all_formats()
+            .any(|format| is_protected(&format))
+        {
+            return;
+        }
+        let Some(player_url) = PlayerJsFetcher::extract_player_js_url(html) else {
+            return;
+        };
+
+        let cached = self
+            .signature_deciphers
+            .lock()
+            .unwrap()
+            .get(&player_url)
+            .cloned();
+        let decipher = match cached {
+            Some(decipher) => decipher,
+            None => {
+                let Ok(decipher) = PlayerJsFetcher::new(self.client.clone())
+                    .fetch_signature_decipher(&player_url, video_id)
+                    .await
+                else {
+                    return;
+                };
+                self.signature_deciphers
+                    .lock()
+                    .unwrap()
+                    .insert(player_url, decipher.clone());
+                decipher
+            }
+        };
+
+        decipher.decipher_streaming_data(streaming_data);
+    }
+
     /// Helper method that fetches a video page and extracts the player response.
     ///
     /// This private method centralizes the common functionality used across multiple